@@ -0,0 +1,51 @@
+//! Idle autosave of editor content to sidecar files.
+
+use ::std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+/// Extension appended to the path of autosaved files.
+pub const AUTOSAVE_EXTENSION: &str = "autosave";
+
+/// Get the path of the autosave sidecar of a file.
+pub fn sidecar_path(path: &Path) -> PathBuf {
+    let mut sidecar = OsString::from(path.as_os_str());
+    sidecar.push(".");
+    sidecar.push(AUTOSAVE_EXTENSION);
+    PathBuf::from(sidecar)
+}
+
+/// Get autosaved content that should be offered for restoring.
+///
+/// Nothing is offered if there is no autosave or if it matches the saved content.
+pub fn restorable(saved: &str, autosave: Option<&str>) -> Option<String> {
+    autosave
+        .filter(|autosave| *autosave != saved)
+        .map(str::to_owned)
+}
+
+/// Debounce for edits, only the save scheduled by the latest edit triggers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Debounce {
+    /// Generation of latest edit.
+    generation: u64,
+}
+
+impl Debounce {
+    /// Register an edit, returning the generation to schedule a save for.
+    pub const fn edit(&mut self) -> u64 {
+        self.generation = self.generation.wrapping_add(1);
+        self.generation
+    }
+
+    /// Check if a save scheduled for the given generation should trigger.
+    pub const fn should_save(&self, generation: u64) -> bool {
+        self.generation == generation
+    }
+
+    /// Cancel any scheduled saves.
+    pub const fn cancel(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+}
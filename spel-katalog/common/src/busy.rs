@@ -0,0 +1,82 @@
+//! [Busy] impl.
+
+use ::std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// An active operation, with progress if known.
+type Operation = (&'static str, Option<(usize, usize)>);
+
+/// Tracker of active long running operations.
+#[derive(Debug, Clone, Default)]
+pub struct Busy {
+    /// Labels and progress of active operations, in the order they were started.
+    active: Arc<Mutex<Vec<Operation>>>,
+}
+
+/// Guard keeping an operation marked as active until dropped.
+#[derive(Debug)]
+#[must_use = "operation is no longer considered active once guard is dropped"]
+pub struct BusyGuard {
+    /// Tracker operation is active in.
+    busy: Busy,
+    /// Label of operation.
+    label: &'static str,
+}
+
+impl Busy {
+    /// Lock active operations, ignoring poisoning since the list is always valid.
+    fn active(&self) -> MutexGuard<'_, Vec<Operation>> {
+        self.active.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Mark an operation as active until the returned guard is dropped.
+    pub fn start(&self, label: &'static str) -> BusyGuard {
+        self.active().push((label, None));
+        BusyGuard {
+            busy: self.clone(),
+            label,
+        }
+    }
+
+    /// Amount of active operations.
+    pub fn count(&self) -> usize {
+        self.active().len()
+    }
+
+    /// Check if any operation is active.
+    pub fn is_busy(&self) -> bool {
+        self.count() != 0
+    }
+
+    /// Label of the most recently started active operation.
+    pub fn label(&self) -> Option<&'static str> {
+        self.active().last().map(|(label, _)| *label)
+    }
+
+    /// Progress, as done and total, of the most recently started active operation.
+    pub fn progress(&self) -> Option<(usize, usize)> {
+        self.active().last().and_then(|(_, progress)| *progress)
+    }
+}
+
+impl BusyGuard {
+    /// Set progress of operation as amount done out of a total.
+    pub fn set_progress(&self, done: usize, total: usize) {
+        let mut active = self.busy.active();
+        if let Some((_, progress)) = active
+            .iter_mut()
+            .rev()
+            .find(|(label, _)| *label == self.label)
+        {
+            *progress = Some((done, total));
+        }
+    }
+}
+
+impl Drop for BusyGuard {
+    fn drop(&mut self) {
+        let mut active = self.busy.active();
+        if let Some(idx) = active.iter().rposition(|(label, _)| *label == self.label) {
+            active.remove(idx);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Grouping of ordered items.
+
+use ::std::collections::BTreeMap;
+
+/// Group items by a key.
+///
+/// Groups are ordered by key, descending if `reverse` is set, items within a
+/// group keep their relative order.
+pub fn group_by_key<T, K, F>(
+    items: impl IntoIterator<Item = T>,
+    mut key: F,
+    reverse: bool,
+) -> Vec<(K, Vec<T>)>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut groups = items
+        .into_iter()
+        .fold(BTreeMap::<K, Vec<T>>::new(), |mut groups, item| {
+            groups.entry(key(&item)).or_default().push(item);
+            groups
+        })
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    if reverse {
+        groups.reverse();
+    }
+
+    groups
+}
@@ -1,6 +1,9 @@
 //! Common types for communication across crates.
 
+mod busy;
+mod group;
 mod or_request;
+mod proc_tree;
 mod status_sender;
 mod display_bytes {
     //! Display wrapper for bytes.
@@ -63,13 +66,18 @@ mod display_bytes {
 }
 
 pub use self::{
+    busy::{Busy, BusyGuard},
     display_bytes::display_bytes,
+    group::group_by_key,
     or_request::{IntoOrRequest, OrRequest},
+    proc_tree::{process_children, process_tree},
     status_sender::StatusSender,
 };
 
+pub mod autosave;
 pub mod in_place;
 pub mod styling;
+pub mod units;
 pub mod w;
 
 /// Create a status message.
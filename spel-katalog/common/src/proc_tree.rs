@@ -0,0 +1,56 @@
+//! Traversal of process trees using a `/proc` like directory.
+
+use ::std::{collections::HashSet, path::Path};
+
+/// Read the direct children of `pid` from `<proc_root>/<pid>/task/*/children`.
+///
+/// Unreadable entries are logged and skipped.
+pub fn process_children(proc_root: &Path, pid: i64) -> Vec<i64> {
+    let tasks = proc_root.join(pid.to_string()).join("task");
+    let entries = match ::std::fs::read_dir(&tasks) {
+        Ok(entries) => entries,
+        Err(err) => {
+            ::log::warn!("reading directory {tasks:?}\n{err}");
+            return Vec::new();
+        }
+    };
+
+    let mut children = Vec::new();
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let path = entry.path().join("children");
+        let content = match ::std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) => {
+                ::log::warn!("reading path {path:?}\n{err}");
+                continue;
+            }
+        };
+        children.extend(
+            content
+                .split_ascii_whitespace()
+                .filter_map(|child| child.parse::<i64>().ok()),
+        );
+    }
+    children.sort_unstable();
+    children
+}
+
+/// Collect `pid` and all of its descendants, parents before children.
+///
+/// Each pid is only visited once, should the tree contain a cycle.
+pub fn process_tree(proc_root: &Path, pid: i64) -> Vec<i64> {
+    let mut visited = HashSet::from([pid]);
+    let mut tree = vec![pid];
+    let mut idx = 0;
+
+    while let Some(&current) = tree.get(idx) {
+        idx += 1;
+        for child in process_children(proc_root, current) {
+            if visited.insert(child) {
+                tree.push(child);
+            }
+        }
+    }
+
+    tree
+}
@@ -10,3 +10,29 @@ pub fn box_border(theme: &::iced_core::Theme) -> widget::container::Style {
 
     base
 }
+
+/// Get a color derived from a name, the same name always gives the same color.
+pub fn name_color(name: &str) -> ::iced_core::Color {
+    // FNV-1a, since the std hashers are not guaranteed to be stable.
+    let hash = name.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+
+    let hue = (hash % 360) as f32 / 60.0;
+    let (saturation, lightness) = (0.45, 0.45);
+
+    let chroma = (1.0 - (2.0 * lightness - 1.0_f32).abs()) * saturation;
+    let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let m = lightness - chroma / 2.0;
+
+    let (r, g, b) = match hue as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    ::iced_core::Color::from_rgb(r + m, g + m, b + m)
+}
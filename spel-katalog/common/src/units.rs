@@ -0,0 +1,66 @@
+//! Human readable formatting of sizes and counts.
+
+/// Base used when formatting sizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum UnitBase {
+    /// Powers of 1024, `KiB`, `MiB`, ...
+    #[default]
+    Binary,
+    /// Powers of 1000, `kB`, `MB`, ...
+    Decimal,
+}
+
+impl UnitBase {
+    /// Size of a step between units.
+    const fn step(self) -> u64 {
+        match self {
+            UnitBase::Binary => 1024,
+            UnitBase::Decimal => 1000,
+        }
+    }
+
+    /// Unit suffixes in increasing order.
+    const fn units(self) -> [&'static str; 7] {
+        match self {
+            UnitBase::Binary => ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"],
+            UnitBase::Decimal => ["B", "kB", "MB", "GB", "TB", "PB", "EB"],
+        }
+    }
+}
+
+/// Format a byte size, such as `512 B` or `1.2 MiB`.
+///
+/// Sizes below one step are shown as whole bytes, larger sizes with one decimal
+/// using the largest unit the rounded value is at least one of.
+pub fn format_size(bytes: u64, base: UnitBase) -> String {
+    let step = base.step() as f64;
+    let units = base.units();
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while unit + 1 < units.len() && (value * 10.0).round() >= step * 10.0 {
+        value /= step;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} {}", units[unit])
+    } else {
+        format!("{value:.1} {}", units[unit])
+    }
+}
+
+/// Format a count with thousands separated by commas, such as `1,024`.
+pub fn format_count(count: u64) -> String {
+    let digits = count.to_string();
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i != 0 && (digits.len() - i).is_multiple_of(3) {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    }
+
+    formatted
+}
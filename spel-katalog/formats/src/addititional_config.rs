@@ -1,8 +1,15 @@
 //! [AdditionalConfig] impl.
 
+use ::std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
 use ::rustc_hash::FxHashMap;
 use ::serde::{Deserialize, Serialize};
 
+use crate::{GameId, write_atomic};
+
 /// Additional config values not used by lutris.
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct AdditionalConfig {
@@ -13,4 +20,168 @@ pub struct AdditionalConfig {
     /// Custom attributes for game.
     #[serde(skip_serializing_if = "FxHashMap::is_empty", default)]
     pub attrs: FxHashMap<String, String>,
+
+    /// Is game a favorite.
+    #[serde(skip_serializing_if = "::core::ops::Not::not", default)]
+    pub favorite: bool,
+
+    /// Is game pinned to the top.
+    #[serde(skip_serializing_if = "::core::ops::Not::not", default)]
+    pub pinned: bool,
+
+    /// Custom command used to launch game instead of the default.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub launch_override: Option<String>,
+}
+
+/// Error returned when additional config cannot be read or written.
+#[derive(Debug, ::thiserror::Error)]
+pub enum AdditionalConfigError {
+    /// Additional config could not be read.
+    #[error("could not read {path:?}\n{source}")]
+    Read {
+        /// Error that occurred.
+        #[source]
+        source: ::std::io::Error,
+        /// Path to additional config.
+        path: PathBuf,
+    },
+    /// Additional config could not be parsed.
+    #[error("could not parse {path:?}\n{source}")]
+    Parse {
+        /// Error that occurred.
+        #[source]
+        source: ::toml::de::Error,
+        /// Path to additional config.
+        path: PathBuf,
+    },
+    /// Additional config could not be serialized.
+    #[error("could not serialize additional config for {path:?}\n{source}")]
+    Serialize {
+        /// Error that occurred.
+        #[source]
+        source: ::toml::ser::Error,
+        /// Path to additional config.
+        path: PathBuf,
+    },
+    /// Directory of additional config could not be created.
+    #[error("could not create {path:?}\n{source}")]
+    CreateDir {
+        /// Error that occurred.
+        #[source]
+        source: ::std::io::Error,
+        /// Path to directory.
+        path: PathBuf,
+    },
+    /// Additional config could not be written.
+    #[error("could not write {path:?}\n{source}")]
+    Write {
+        /// Error that occurred.
+        #[source]
+        source: ::std::io::Error,
+        /// Path to additional config.
+        path: PathBuf,
+    },
+}
+
+/// Placeholder replaced by game executable in launch override.
+const EXE_PLACEHOLDER: &str = "{exe}";
+
+/// Placeholder replaced by wine prefix in launch override.
+const PREFIX_PLACEHOLDER: &str = "{prefix}";
+
+impl AdditionalConfig {
+    /// Get path of additional config for game with given id in config directory.
+    pub fn path(config_dir: &Path, id: GameId) -> PathBuf {
+        config_dir.join("games").join(format!("{id}.toml"))
+    }
+
+    /// Read additional config from given path, [None] if there is no file at path.
+    ///
+    /// # Errors
+    /// If the file at path cannot be read or parsed.
+    pub async fn read(path: &Path) -> Result<Option<Self>, AdditionalConfigError> {
+        let content = match ::smol::fs::read_to_string(path).await {
+            Ok(content) => content,
+            Err(err) if err.kind() == ::std::io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => {
+                return Err(AdditionalConfigError::Read {
+                    source,
+                    path: path.to_path_buf(),
+                });
+            }
+        };
+
+        ::toml::from_str(&content)
+            .map(Some)
+            .map_err(|source| AdditionalConfigError::Parse {
+                source,
+                path: path.to_path_buf(),
+            })
+    }
+
+    /// Write additional config to given path using [write_atomic], creating the
+    /// directory containing it if needed.
+    ///
+    /// # Errors
+    /// If the additional config cannot be serialized or written.
+    pub async fn write(&self, path: &Path) -> Result<(), AdditionalConfigError> {
+        let content =
+            ::toml::to_string(self).map_err(|source| AdditionalConfigError::Serialize {
+                source,
+                path: path.to_path_buf(),
+            })?;
+
+        if let Some(dir) = path.parent() {
+            ::smol::fs::create_dir_all(dir).await.map_err(|source| {
+                AdditionalConfigError::CreateDir {
+                    source,
+                    path: dir.to_path_buf(),
+                }
+            })?;
+        }
+
+        write_atomic(path, content.as_bytes())
+            .await
+            .map_err(|source| AdditionalConfigError::Write {
+                source,
+                path: path.to_path_buf(),
+            })
+    }
+
+    /// Get command line of launch override, [None] if the default command should be used.
+    ///
+    /// Occurrences of `{exe}` and `{prefix}` are replaced by the executable and
+    /// prefix, a missing prefix is replaced by nothing.
+    ///
+    /// # Errors
+    /// If the launch override cannot be split into arguments.
+    pub fn launch_command(
+        &self,
+        exe: &Path,
+        prefix: Option<&Path>,
+    ) -> Result<Option<Vec<OsString>>, ::shell_words::ParseError> {
+        let Some(launch_override) = &self.launch_override else {
+            return Ok(None);
+        };
+        let words = ::shell_words::split(launch_override)?;
+        if words.is_empty() {
+            return Ok(None);
+        }
+
+        let exe = exe.to_string_lossy();
+        let prefix = prefix.map(Path::to_string_lossy).unwrap_or_default();
+
+        let command_line = words
+            .into_iter()
+            .map(|word| {
+                OsString::from(
+                    word.replace(EXE_PLACEHOLDER, &exe)
+                        .replace(PREFIX_PLACEHOLDER, &prefix),
+                )
+            })
+            .collect();
+
+        Ok(Some(command_line))
+    }
 }
@@ -0,0 +1,40 @@
+//! Atomic writing of files.
+
+use ::std::path::{Path, PathBuf};
+
+use ::smol::io::AsyncWriteExt as _;
+
+/// Get path of the temporary file used when writing a file with [write_atomic].
+///
+/// The temporary file is placed next to path, keeping it on the same filesystem.
+pub fn temp_path(path: &Path) -> PathBuf {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    PathBuf::from(temp)
+}
+
+/// Write contents to path by writing them to the [temp_path] of path which is then
+/// renamed to path, such that an interrupted write leaves any existing file intact.
+///
+/// # Errors
+/// If the temporary file cannot be written or renamed.
+pub async fn write_atomic(path: &Path, contents: &[u8]) -> ::std::io::Result<()> {
+    let temp = temp_path(path);
+    let result = async {
+        let mut file = ::smol::fs::File::create(&temp).await?;
+        file.write_all(contents).await?;
+        file.sync_all().await?;
+        drop(file);
+        ::smol::fs::rename(&temp, path).await
+    }
+    .await;
+
+    if result.is_err()
+        && let Err(err) = ::smol::fs::remove_file(&temp).await
+        && err.kind() != ::std::io::ErrorKind::NotFound
+    {
+        ::log::warn!("could not remove temporary file {temp:?}\n{err}");
+    }
+
+    result
+}
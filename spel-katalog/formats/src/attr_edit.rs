@@ -0,0 +1,56 @@
+//! Editing of attributes across multiple [AdditionalConfig] values.
+
+use crate::AdditionalConfig;
+
+/// Edit of a single custom attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttrEdit {
+    /// Set attribute to a value.
+    Set {
+        /// Key of attribute.
+        key: String,
+        /// Value to set.
+        value: String,
+    },
+    /// Remove attribute.
+    Remove {
+        /// Key of attribute.
+        key: String,
+    },
+}
+
+impl AttrEdit {
+    /// Get key of edited attribute.
+    pub fn key(&self) -> &str {
+        match self {
+            AttrEdit::Set { key, .. } | AttrEdit::Remove { key } => key,
+        }
+    }
+
+    /// Apply edit to config, returns true if the config was changed.
+    pub fn apply(&self, config: &mut AdditionalConfig) -> bool {
+        match self {
+            AttrEdit::Set { key, value } => {
+                if config.attrs.get(key) == Some(value) {
+                    false
+                } else {
+                    config.attrs.insert(key.clone(), value.clone());
+                    true
+                }
+            }
+            AttrEdit::Remove { key } => config.attrs.remove(key).is_some(),
+        }
+    }
+
+    /// Apply edit to configs of a selection of games, returning the changed configs
+    /// which need to be written.
+    pub fn apply_to<I>(
+        &self,
+        configs: impl IntoIterator<Item = (I, AdditionalConfig)>,
+    ) -> Vec<(I, AdditionalConfig)> {
+        configs
+            .into_iter()
+            .filter_map(|(id, mut config)| self.apply(&mut config).then_some((id, config)))
+            .collect()
+    }
+}
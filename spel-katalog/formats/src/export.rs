@@ -0,0 +1,132 @@
+//! Export of games to json or csv.
+
+use ::std::path::Path;
+
+use ::serde::{Deserialize, Serialize};
+
+use crate::{Game, GameId};
+
+/// Header of csv exports.
+const CSV_HEADER: &str = "id,name,runner,slug,hidden,installed_at";
+
+/// Format games can be exported as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// A json array of games.
+    Json,
+    /// Comma separated values with a header row.
+    Csv,
+}
+
+impl ExportFormat {
+    /// Get export format from extension of path.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?;
+        if ext.eq_ignore_ascii_case("json") {
+            Some(Self::Json)
+        } else if ext.eq_ignore_ascii_case("csv") {
+            Some(Self::Csv)
+        } else {
+            None
+        }
+    }
+}
+
+/// Exported game data.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedGame {
+    /// Id of game.
+    pub id: GameId,
+    /// Name of game.
+    pub name: String,
+    /// Name of runner used by game.
+    pub runner: String,
+    /// Slug of game, if available.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub slug: Option<String>,
+    /// Is the game hidden.
+    pub hidden: bool,
+    /// When was the game installed.
+    pub installed_at: i64,
+}
+
+impl From<&Game> for ExportedGame {
+    fn from(game: &Game) -> Self {
+        Self {
+            id: game.id(),
+            name: game.name().to_owned(),
+            runner: game.runner_name(),
+            slug: game.slug().map(String::from),
+            hidden: game.hidden(),
+            installed_at: game.installed_at(),
+        }
+    }
+}
+
+/// Quote a csv field if needed.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Export games as csv, one row per game after a header row.
+pub fn export_csv<'a>(games: impl IntoIterator<Item = &'a Game>) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+    for game in games {
+        let ExportedGame {
+            id,
+            name,
+            runner,
+            slug,
+            hidden,
+            installed_at,
+        } = ExportedGame::from(game);
+        let row = [
+            id.to_string(),
+            name,
+            runner,
+            slug.unwrap_or_default(),
+            hidden.to_string(),
+            installed_at.to_string(),
+        ]
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",");
+        csv.push_str(&row);
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Export games as a pretty printed json array.
+///
+/// # Errors
+/// If the games cannot be serialized.
+pub fn export_json<'a>(
+    games: impl IntoIterator<Item = &'a Game>,
+) -> Result<String, ::serde_json::Error> {
+    let games = games
+        .into_iter()
+        .map(ExportedGame::from)
+        .collect::<Vec<_>>();
+    ::serde_json::to_string_pretty(&games)
+}
+
+/// Export games using given format.
+///
+/// # Errors
+/// If the games cannot be serialized.
+pub fn export_games<'a>(
+    games: impl IntoIterator<Item = &'a Game>,
+    format: ExportFormat,
+) -> Result<String, ::serde_json::Error> {
+    match format {
+        ExportFormat::Json => export_json(games),
+        ExportFormat::Csv => Ok(export_csv(games)),
+    }
+}
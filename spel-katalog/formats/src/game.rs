@@ -69,6 +69,39 @@ impl Game {
         }
     }
 
+    /// Initial of game name used when grouping by letter, uppercase
+    /// for letters and `#` for anything else.
+    pub fn initial(&self) -> char {
+        match self.name().chars().next() {
+            Some(chr) if chr.is_alphabetic() => chr.to_uppercase().next().unwrap_or(chr),
+            _ => '#',
+        }
+    }
+
+    /// Name of runner used by game, `native` for native games.
+    pub fn runner_name(&self) -> String {
+        match self {
+            Game::Lutris(lutris_game) => lutris_game.runner.to_string(),
+            Game::Native { .. } => "native".to_owned(),
+        }
+    }
+
+    /// Short summary of already loaded game data, as labeled values.
+    pub fn summary(&self) -> Vec<(&'static str, String)> {
+        let installed = ::chrono::DateTime::from_timestamp(self.installed_at(), 0)
+            .map(|installed| installed.format("%Y-%m-%d").to_string());
+
+        [
+            Some(("Runner", self.runner_name())),
+            installed.map(|installed| ("Installed", installed)),
+            self.slug().map(|slug| ("Slug", slug.to_owned())),
+            self.hidden().then(|| ("Hidden", "yes".to_owned())),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
     /// Get id of game.
     pub const fn id(&self) -> GameId {
         match self {
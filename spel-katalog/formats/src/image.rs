@@ -1,8 +1,11 @@
 //! [Image] impl.
-use ::std::borrow::Cow;
+use ::std::{borrow::Cow, io::Cursor};
 
 pub use ::bytes::Bytes;
-use ::image::{DynamicImage, RgbaImage};
+use ::image::{
+    DynamicImage, ImageError, ImageFormat, RgbaImage,
+    error::{ParameterError, ParameterErrorKind},
+};
 pub use ::serde::{Deserialize, Serialize};
 
 /// Bytes and dimensions of an rgba image.
@@ -70,6 +73,22 @@ impl Image {
         Self::from_rgba(image.into_rgba8())
     }
 
+    /// Encode image as png.
+    ///
+    /// # Errors
+    /// If the dimensions do not match the amount of bytes, or if encoding fails.
+    pub fn to_png(&self) -> Result<Vec<u8>, ImageError> {
+        let image = self.to_rgba().ok_or_else(|| {
+            ImageError::Parameter(ParameterError::from_kind(
+                ParameterErrorKind::DimensionMismatch,
+            ))
+        })?;
+
+        let mut buf = Cursor::new(Vec::new());
+        image.write_to(&mut buf, ImageFormat::Png)?;
+        Ok(buf.into_inner())
+    }
+
     /// Give a function width, height and bytes to create a value of some type.
     #[inline]
     pub fn map<F, T>(self, f: F) -> T
@@ -1,25 +1,33 @@
 //! Shared data formats in use buy application.
 
 pub use self::{
-    addititional_config::AdditionalConfig,
+    addititional_config::{AdditionalConfig, AdditionalConfigError},
+    atomic::{temp_path, write_atomic},
+    attr_edit::AttrEdit,
     bind::{Bind, Symlink},
     daemon::{DaemonRunConfigRequest, DaemonRunResponse},
+    export::{ExportFormat, ExportedGame, export_csv, export_games, export_json},
     game::{Game, GameId},
     image::Image,
     installer::{ExeChoice, InstallerConfig, InstallerPrepareConfig},
     lutris_game::{LutrisGame, LutrisRunner},
     native_game::{NativeGame, NativeRunner, RunMode},
+    slug::slugify,
     timestamp::{TimeStampParseError, Timestamp, TimestampFromIntError},
 };
 
 mod addititional_config;
+mod atomic;
+mod attr_edit;
 mod bind;
 mod daemon;
+mod export;
 mod game;
 mod image;
 mod installer;
 mod lutris_game;
 mod native_game;
+mod slug;
 mod timestamp;
 
 pub mod lutris_config;
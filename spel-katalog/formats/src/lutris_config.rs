@@ -8,6 +8,8 @@ use ::std::{
 use ::rustc_hash::FxHashMap;
 use ::yaml_rust2::{ScanError, Yaml, YamlLoader};
 
+use crate::LutrisRunner;
+
 /// A game config.
 #[derive(Debug, Clone, Default)]
 pub struct Config {
@@ -113,6 +115,17 @@ fn get_env(yml: &Yaml) -> Option<FxHashMap<String, String>> {
 }
 
 impl Config {
+    /// Get wine prefix of config, if `runner` is wine and a non-empty prefix is set.
+    pub fn wine_prefix(&self, runner: &LutrisRunner) -> Option<&Path> {
+        if !runner.is_wine() {
+            return None;
+        }
+        self.game
+            .prefix
+            .as_deref()
+            .filter(|prefix| !prefix.as_os_str().is_empty())
+    }
+
     /// Parse game config.
     ///
     /// # Errors
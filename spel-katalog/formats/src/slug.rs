@@ -0,0 +1,28 @@
+//! Slug generation following the conventions of lutris.
+
+use ::unicode_normalization::UnicodeNormalization;
+
+/// Create a slug from a game name.
+///
+/// The name is decomposed and anything that is not ascii is dropped, the
+/// result is lowercase where runs of whitespace and hyphens are replaced by a
+/// single hyphen, other punctuation is removed, and leading or trailing
+/// hyphens and underscores are stripped, matching the slugs used by lutris.
+pub fn slugify(name: &str) -> String {
+    let mut slug = String::with_capacity(name.len());
+    let mut separate = false;
+
+    for c in name.nfkd().filter(char::is_ascii) {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            if separate && !slug.is_empty() {
+                slug.push('-');
+            }
+            separate = false;
+            slug.push(c.to_ascii_lowercase());
+        } else if c.is_ascii_whitespace() || c == '-' {
+            separate = true;
+        }
+    }
+
+    slug.trim_matches(['-', '_']).to_owned()
+}
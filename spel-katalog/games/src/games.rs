@@ -64,6 +64,10 @@ pub struct WithThumb {
     pub shadows: Option<GameId>,
     /// This game will be removed on the next refresh.
     pub ghost: bool,
+    /// Is the game a favorite.
+    pub favorite: bool,
+    /// Is the game pinned to the top.
+    pub pinned: bool,
 }
 
 impl From<WithThumb> for Game {
@@ -86,6 +90,8 @@ impl From<(Uuid, NativeGame)> for WithThumb {
             shadows: game.shadow,
             ghost: false,
             thumb_thumb: None,
+            favorite: false,
+            pinned: false,
         }
     }
 }
@@ -211,6 +217,11 @@ impl Games {
         self.displayed.len()
     }
 
+    /// Amount of batch selected games.
+    pub fn batch_selected_count(&self) -> usize {
+        self.batch_selected().count()
+    }
+
     /// Remove all games matching condition.
     ///
     /// Games are only removed from current session.
@@ -250,8 +261,18 @@ impl Games {
         Some(replacement)
     }
 
-    /// Sort displayed games.
+    /// Sort displayed games, pinned games are placed first.
     pub fn sort(&mut self, settings: &Settings, filter: &str) {
+        self.sort_displayed(settings, filter);
+
+        let Self {
+            games, displayed, ..
+        } = self;
+        displayed.sort_by_key(|&idx| !games[idx].pinned);
+    }
+
+    /// Sort displayed games, not considering pinned state.
+    fn sort_displayed(&mut self, settings: &Settings, filter: &str) {
         let Self {
             games,
             displayed,
@@ -265,29 +286,14 @@ impl Games {
         fn get_filterend<'src>(
             games: &'src mut [WithThumb],
             cache: &'src mut [Option<GameCache>],
+            show: Show,
         ) -> Vec<(usize, &'src mut Game, &'src mut Option<GameCache>)> {
             izip!(0.., games, cache)
+                .filter(|(_, game, _)| show.includes(game.hidden(), game.favorite))
                 .map(|(i, WithThumb { game, .. }, cache)| (i, game, cache))
                 .collect()
         }
 
-        fn filter_hidden<'a>(
-            items: Vec<(usize, &'a mut Game, &'a mut Option<GameCache>)>,
-            show: Show,
-        ) -> Vec<(usize, &'a mut Game, &'a mut Option<GameCache>)> {
-            match show {
-                ::spel_katalog_settings::Show::Apparent => items
-                    .into_iter()
-                    .filter(|(_, game, _)| !game.hidden())
-                    .collect(),
-                ::spel_katalog_settings::Show::Hidden => items
-                    .into_iter()
-                    .filter(|(_, game, _)| game.hidden())
-                    .collect(),
-                ::spel_katalog_settings::Show::All => items,
-            }
-        }
-
         fn get_cache<'a>(game: &Game, cache: &'a mut Option<GameCache>) -> &'a GameCache {
             cache.get_or_insert_with(|| GameCache::from(game))
         }
@@ -326,8 +332,7 @@ impl Games {
         *last_show = Some(show);
 
         if filter.trim().is_empty() {
-            let mut filtered = get_filterend(games, cache);
-            filtered = filter_hidden(filtered, show);
+            let mut filtered = get_filterend(games, cache, show);
             sort_items(
                 &mut filtered,
                 *settings.get::<SortBy>(),
@@ -344,8 +349,7 @@ impl Games {
                         *filter = filter.to_uppercase();
                     }
                 }) {
-                    let mut filtered = get_filterend(games, cache);
-                    filtered = filter_hidden(filtered, settings[Show::as_idx()]);
+                    let mut filtered = get_filterend(games, cache, show);
                     filtered = filtered
                         .into_iter()
                         .filter_map(|mut value| {
@@ -376,8 +380,7 @@ impl Games {
                 };
             }
             FilterMode::Search => {
-                let mut filtered = get_filterend(games, cache);
-                filtered = filter_hidden(filtered, settings[Show::as_idx()]);
+                let mut filtered = get_filterend(games, cache, show);
                 let filter = filter.to_uppercase();
                 let mut dists = filtered
                     .iter_mut()
@@ -405,8 +408,7 @@ impl Games {
             }
             FilterMode::Regex => {
                 if let Ok(re) = RegexBuilder::new(filter).case_insensitive(true).build() {
-                    let mut filtered = get_filterend(games, cache);
-                    filtered = filter_hidden(filtered, settings[Show::as_idx()]);
+                    let mut filtered = get_filterend(games, cache, show);
                     filtered.retain(|(_, game, _)| re.is_match(game.name()));
                     sort_items(
                         &mut filtered,
@@ -477,6 +479,20 @@ impl Games {
         }
     }
 
+    /// Set favorite state of games with given ids, returns true if any game was changed.
+    pub fn set_favorites(&mut self, ids: impl IntoIterator<Item = GameId>, favorite: bool) -> bool {
+        let mut changed = false;
+        for id in ids {
+            if let Some(game) = self.by_id_mut(id)
+                && game.favorite != favorite
+            {
+                game.favorite = favorite;
+                changed = true;
+            }
+        }
+        changed
+    }
+
     /// Remove the thumbnail of a game.
     pub(crate) fn remove_image(&mut self, slug: &str) {
         if let Some(game) = self.by_slug_mut(slug) {
@@ -581,6 +597,52 @@ impl Games {
         self.sort(settings, filter);
     }
 
+    /// Set pinned state of games with given ids, returns true if any game was changed.
+    pub fn set_pinned(&mut self, ids: impl IntoIterator<Item = GameId>, pinned: bool) -> bool {
+        let mut changed = false;
+        for id in ids {
+            if let Some(game) = self.by_id_mut(id)
+                && game.pinned != pinned
+            {
+                game.pinned = pinned;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Merge reloaded games into current games.
+    ///
+    /// Current games for which `reloaded` holds are replaced by `games`,
+    /// keeping thumbnails, favorites, pins and batch selection of games that remain.
+    pub fn merge(
+        &mut self,
+        games: Vec<WithThumb>,
+        reloaded: impl Fn(&WithThumb) -> bool,
+        settings: &Settings,
+        filter: &str,
+    ) {
+        let (previous, kept): (Vec<_>, Vec<_>) =
+            mem::take(&mut self.games).into_iter().partition(&reloaded);
+        let mut previous = previous
+            .into_iter()
+            .map(|game| (game.id(), game))
+            .collect::<FxHashMap<_, _>>();
+
+        let games = games.into_iter().map(|mut game| {
+            if let Some(previous) = previous.remove(&game.id()) {
+                game.thumb = previous.thumb;
+                game.thumb_thumb = previous.thumb_thumb;
+                game.batch_selected = previous.batch_selected;
+                game.favorite = previous.favorite;
+                game.pinned = previous.pinned;
+            }
+            game
+        });
+
+        self.set(kept.into_iter().chain(games).collect(), settings, filter);
+    }
+
     /// Set current games to the ones provided, then update lookups and display.
     pub fn set(&mut self, games: Vec<WithThumb>, settings: &Settings, filter: &str) {
         self.clear();
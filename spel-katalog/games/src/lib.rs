@@ -1,10 +1,12 @@
 //! Game management utilities.
 
 mod games;
+mod quick_launch;
 mod state;
 
 pub use games::{GameAddDelta, Games, RemoveGames, WithThumb as GameWithThumb};
-pub use state::{Message, Request, SelDir, State};
+pub use quick_launch::QuickLaunch;
+pub use state::{CardContent, Message, Request, SelDir, State, thumbnail_radius};
 
 /// Element alias.
 type Element<'a, M> = ::iced_core::Element<'a, M, ::iced_core::Theme, ::iced_renderer::Renderer>;
@@ -0,0 +1,98 @@
+//! [QuickLaunch] impl.
+
+use ::spel_katalog_formats::GameId;
+use ::spel_katalog_settings::{Settings, Show};
+
+use crate::Games;
+
+/// State of a quick launch prompt, matching games by name as a query is typed.
+#[derive(Debug, Clone, Default)]
+pub struct QuickLaunch {
+    /// Current query.
+    query: String,
+    /// Best matches for query, best first.
+    matches: Vec<GameId>,
+}
+
+impl QuickLaunch {
+    /// Max amount of matches kept.
+    pub const MAX_MATCHES: usize = 8;
+
+    /// Least similarity to query of games not containing it for them to match.
+    pub const MIN_SIMILARITY: f64 = 0.5;
+
+    /// Current query.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Best matches for current query, best first.
+    pub fn matches(&self) -> &[GameId] {
+        &self.matches
+    }
+
+    /// Best match for current query.
+    pub fn top(&self) -> Option<GameId> {
+        self.matches.first().copied()
+    }
+
+    /// Set query, updating matches.
+    pub fn set_query(&mut self, query: String, games: &Games, settings: &Settings) {
+        self.matches = games.quick_matches(&query, settings, Self::MAX_MATCHES);
+        self.query = query;
+    }
+
+    /// Submit prompt, returning the game to run if any matched, none if no game is similar
+    /// enough to query.
+    pub fn submit(self) -> Option<GameId> {
+        self.top()
+    }
+}
+
+impl Games {
+    /// Get ids of at most `limit` games matching `query`, best first.
+    ///
+    /// Games containing the query in their name are ranked first, then by similarity,
+    /// only games included by the [Show] setting are considered. Games not containing the query
+    /// need a similarity of at least [QuickLaunch::MIN_SIMILARITY] to match.
+    pub fn quick_matches(&self, query: &str, settings: &Settings, limit: usize) -> Vec<GameId> {
+        let query = query.trim().to_uppercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let show = *settings.get::<Show>();
+
+        let mut ranked = self
+            .all()
+            .iter()
+            .filter(|game| show.includes(game.hidden(), game.favorite))
+            .map(|game| {
+                let name = game.name().to_uppercase();
+                (
+                    game,
+                    name.contains(&query),
+                    ::strsim::normalized_damerau_levenshtein(&name, &query),
+                )
+            })
+            .filter(|(_, contains, similarity)| {
+                *contains || *similarity >= QuickLaunch::MIN_SIMILARITY
+            })
+            .collect::<Vec<_>>();
+
+        ranked.sort_by(
+            |(game_a, contains_a, dist_a), (game_b, contains_b, dist_b)| {
+                contains_a
+                    .cmp(contains_b)
+                    .reverse()
+                    .then(dist_a.total_cmp(dist_b).reverse())
+                    .then_with(|| game_a.name().cmp(game_b.name()))
+            },
+        );
+
+        ranked
+            .into_iter()
+            .take(limit)
+            .map(|(game, ..)| game.id())
+            .collect()
+    }
+}
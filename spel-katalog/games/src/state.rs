@@ -2,6 +2,7 @@
 
 use ::core::{cell::Cell, convert::identity, iter, mem, ops::ControlFlow, time::Duration};
 use ::std::{
+    collections::BTreeMap,
     io::Cursor,
     path::{Path, PathBuf},
     sync::{Arc, LazyLock},
@@ -9,7 +10,7 @@ use ::std::{
 
 use ::derive_more::{Deref, DerefMut, IsVariant};
 use ::iced_aw::ContextMenu;
-use ::iced_core::{Border, Length::Fill, text::Wrapping};
+use ::iced_core::{Border, Length::Fill, border::Radius, text::Wrapping};
 use ::iced_futures::Subscription;
 use ::iced_runtime::Task;
 use ::iced_widget::{self as widget, Sensor, container, stack};
@@ -19,22 +20,25 @@ use ::parking_lot::Mutex;
 use ::rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 use ::rusqlite::{Connection, Statement, named_params};
 use ::rustc_hash::FxHashSet;
-use ::spel_katalog_common::{IntoOrRequest, OrRequest, StatusSender, async_status, status};
+use ::spel_katalog_common::{
+    Busy, IntoOrRequest, OrRequest, StatusSender, async_status, group_by_key, status,
+};
 use ::spel_katalog_formats::{Game, GameId, NativeGame};
 use ::spel_katalog_gather::{
-    CoverGatherer, CoverGathererOptions, LoadDbError, load_games_from_database,
-    load_thumbnail_database,
+    CoverGatherer, CoverGathererOptions, LoadDbError, RemoteCoverOptions, THUMBNAILS_FILENAME,
+    import_lutris_covers, import_remote_covers, load_favorites, load_games_from_database,
+    load_pinned, load_thumbnail_database, lutris_coverart_dir,
 };
 use ::spel_katalog_profiler as timing;
-use ::spel_katalog_settings::{CoverartDir, Settings, UnloadThumbnails};
+use ::spel_katalog_settings::{
+    CoverartDir, DATA, GroupBy, HOME, PlaceholderImage, Settings, SortDir, ThumbnailPlaceholder,
+    ThumbnailRadius, UnloadThumbnails,
+};
 use ::tap::{Conv, Pipe};
 use ::uuid::Uuid;
 
 use crate::{Element, Games, games::WithThumb};
 
-/// Filename of thumbnails cache database.
-const THUMBNAILS_FILENAME: &str = "thumbnails.db";
-
 /// State of games element.
 #[derive(Debug, Default, Deref, DerefMut)]
 pub struct State {
@@ -48,6 +52,25 @@ pub struct State {
     selected: Option<GameId>,
     /// How many columns to display.
     columns: Cell<usize>,
+    /// How displayed games are grouped, and in which direction.
+    grouping: Cell<(GroupBy, SortDir)>,
+    /// Tracker of long running operations.
+    busy: Busy,
+    /// Render cards as text only.
+    hide_thumbnails: bool,
+    /// Lutris ids of games with terminal output available.
+    with_output: FxHashSet<i64>,
+}
+
+/// What a game card is rendered with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, IsVariant)]
+pub enum CardContent {
+    /// Card shows the thumbnail of the game.
+    Thumbnail,
+    /// Card shows a placeholder in place of a missing thumbnail.
+    Placeholder,
+    /// Card shows only text.
+    Text,
 }
 
 /// What direction to select element in.
@@ -83,6 +106,11 @@ pub enum Message {
         /// Games to add.
         games: Vec<(Uuid, NativeGame)>,
     },
+    /// Replace all native games, keeping state of games that remain.
+    SetNativeGames {
+        /// Games to set.
+        games: Vec<(Uuid, NativeGame)>,
+    },
     /// Add a single game.
     AddNativeGame {
         /// Uuid of game to add.
@@ -90,6 +118,13 @@ pub enum Message {
         /// Config of game.
         config: Box<NativeGame>,
     },
+    /// Import covers from lutris coverart cache for games without a thumbnail.
+    ImportLutrisCovers,
+    /// Fetch covers from urls and store them in the thumbnail cache.
+    ImportRemoteCovers {
+        /// Urls of covers by slug.
+        urls: BTreeMap<String, String>,
+    },
     /// Set thumbnails.
     SetImages {
         /// Slugs for games to set thumbnails for.
@@ -147,6 +182,20 @@ pub enum Message {
     FlushCache,
     /// Force re-sort of games.
     Sort,
+    /// Set favorite state of games.
+    SetFavorites {
+        /// Ids of games to set favorite state for.
+        ids: Vec<GameId>,
+        /// Should the games be favorites.
+        favorite: bool,
+    },
+    /// Set pinned state of games.
+    SetPinned {
+        /// Ids of games to set pinned state for.
+        ids: Vec<GameId>,
+        /// Should the games be pinned.
+        pinned: bool,
+    },
 }
 
 /// Requests for other widgets.
@@ -170,6 +219,8 @@ pub enum Request {
     Convert(GameId),
     /// Open game install view.
     InstallGame,
+    /// Show terminal output of game.
+    ShowOutput(GameId),
 }
 
 /// Messages produced by game areas.
@@ -285,7 +336,17 @@ fn load_thumbs(
     Some([update_task, thumb_thumb_task])
 }
 
+/// Get corner radius of thumbnails on cards, a radius of 0 keeps them square.
+pub fn thumbnail_radius(settings: &Settings) -> Radius {
+    Radius::from(settings.get::<ThumbnailRadius>().pixels())
+}
+
 impl State {
+    /// Get state using given tracker for long running operations.
+    pub fn with_busy(self, busy: Busy) -> Self {
+        Self { busy, ..self }
+    }
+
     /// Get current amount of columns.
     pub const fn columns(&self) -> usize {
         self.columns.get()
@@ -301,6 +362,62 @@ impl State {
         self.selected = None;
     }
 
+    /// Are cards rendered as text only.
+    pub const fn thumbnails_hidden(&self) -> bool {
+        self.hide_thumbnails
+    }
+
+    /// Toggle whether cards are rendered as text only, returning the new state.
+    pub const fn toggle_thumbnails(&mut self) -> bool {
+        self.hide_thumbnails = !self.hide_thumbnails;
+        self.hide_thumbnails
+    }
+
+    /// Get what the card of a game is rendered with.
+    pub fn card_content(&self, game: &WithThumb, settings: &Settings) -> CardContent {
+        if self.hide_thumbnails {
+            CardContent::Text
+        } else if game.thumb.is_some() || game.thumb_thumb.is_some() {
+            CardContent::Thumbnail
+        } else {
+            match settings.get::<ThumbnailPlaceholder>() {
+                ThumbnailPlaceholder::None => CardContent::Text,
+                ThumbnailPlaceholder::Color => CardContent::Placeholder,
+                ThumbnailPlaceholder::Image => {
+                    if settings
+                        .get::<PlaceholderImage>()
+                        .as_path()
+                        .as_os_str()
+                        .is_empty()
+                    {
+                        CardContent::Text
+                    } else {
+                        CardContent::Placeholder
+                    }
+                }
+            }
+        }
+    }
+
+    /// Merge reloaded games, keeping selection if the selected game remains.
+    ///
+    /// See [Games::merge].
+    pub fn merge_games(
+        &mut self,
+        games: Vec<WithThumb>,
+        reloaded: impl Fn(&WithThumb) -> bool,
+        settings: &Settings,
+        filter: &str,
+    ) {
+        self.games.merge(games, reloaded, settings, filter);
+
+        if let Some(selected) = self.selected
+            && self.by_id(selected).is_none()
+        {
+            self.selected = None;
+        }
+    }
+
     /// Subscription used by games state.
     pub fn subscription(&self) -> Subscription<Message> {
         if !self.cache_queue.0.is_empty() {
@@ -327,7 +444,9 @@ impl State {
             }
             Message::LoadDb { db_path } => {
                 let tx = tx.clone();
+                let busy = self.busy.start("loading games");
                 Task::future(async move {
+                    let _busy = busy;
                     match ::smol::unblock(move || load_games_from_database(&db_path)).await {
                         Ok(games) => games
                             .pipe(|games| Message::AddGames { games })
@@ -345,27 +464,77 @@ impl State {
                 .then(identity)
             }
             Message::AddGames { games } => {
-                self.add_games(
-                    games.into_iter().map(|game| WithThumb {
-                        game,
-                        thumb: None,
-                        thumb_thumb: None,
-                        batch_selected: false,
-                        shadows: None,
-                        ghost: false,
-                    }),
+                self.merge_games(
+                    games
+                        .into_iter()
+                        .map(|game| WithThumb {
+                            game,
+                            thumb: None,
+                            thumb_thumb: None,
+                            batch_selected: false,
+                            shadows: None,
+                            ghost: false,
+                            favorite: false,
+                            pinned: false,
+                        })
+                        .collect(),
+                    |game| matches!(game.id(), GameId::Lutris(..)),
                     settings,
                     filter,
                 );
 
                 status!(tx, "read games from database");
 
-                self.find_cached(settings)
+                let load_marked = match settings.xdg().get_config_home() {
+                    Some(config_dir) => Task::future(::smol::unblock(move || {
+                        let games_dir = config_dir.join("games");
+                        (load_favorites(&games_dir), load_pinned(&games_dir))
+                    }))
+                    .then(|(favorites, pinned)| {
+                        Task::batch([
+                            Task::done(OrRequest::Message(Message::SetFavorites {
+                                ids: favorites.into_iter().map(GameId::Lutris).collect(),
+                                favorite: true,
+                            })),
+                            Task::done(OrRequest::Message(Message::SetPinned {
+                                ids: pinned.into_iter().map(GameId::Lutris).collect(),
+                                pinned: true,
+                            })),
+                        ])
+                    }),
+                    None => {
+                        ::log::error!("could not get config dir");
+                        Task::none()
+                    }
+                };
+
+                Task::batch([self.find_cached(settings), load_marked])
+            }
+            Message::SetFavorites { ids, favorite } => {
+                if self.set_favorites(ids, favorite) {
+                    self.sort(settings, filter);
+                }
+                Task::none()
+            }
+            Message::SetPinned { ids, pinned } => {
+                if self.set_pinned(ids, pinned) {
+                    self.sort(settings, filter);
+                }
+                Task::none()
             }
             Message::AddNativeGames { games } => {
                 self.add_games(games.into_iter().map(WithThumb::from), settings, filter);
                 Task::none()
             }
+            Message::SetNativeGames { games } => {
+                self.merge_games(
+                    games.into_iter().map(WithThumb::from).collect(),
+                    |game| matches!(game.id(), GameId::Native(..)),
+                    settings,
+                    filter,
+                );
+                Task::none()
+            }
             Message::AddNativeGame { uuid, config } => {
                 self.add_games(
                     iter::once(WithThumb::from((uuid, *config))),
@@ -374,6 +543,8 @@ impl State {
                 );
                 Task::none()
             }
+            Message::ImportLutrisCovers => self.import_lutris_covers(tx, settings),
+            Message::ImportRemoteCovers { urls } => self.import_remote_covers(urls, tx, settings),
             Message::SetImages {
                 slugs,
                 images,
@@ -505,25 +676,51 @@ impl State {
             .filter_map(|game| game.slug().map(ToOwned::to_owned))
             .collect::<Vec<_>>();
 
-        let find_cached = ::smol::unblock(move || {
-            let db_path = cache_dir.join(THUMBNAILS_FILENAME);
-            let (slugs, images) = load_thumbnail_database(&db_path)
-                .map_err(|err| ::log::warn!("could not load thumbnail cache at {db_path:?}\n{err}"))
-                .unwrap_or_default()
-                .into_iter()
-                .unzip();
-            let mut game_slugs = FxHashSet::from_iter(game_slugs);
+        let mut busy = Some(self.busy.start("gathering covers"));
+
+        let find_cached = async move {
+            let (slugs, images, game_slugs) = ::smol::unblock(move || {
+                let db_path = cache_dir.join(THUMBNAILS_FILENAME);
+                let (slugs, images): (Vec<_>, Vec<_>) = load_thumbnail_database(&db_path)
+                    .map_err(|err| {
+                        ::log::warn!("could not load thumbnail cache at {db_path:?}\n{err}")
+                    })
+                    .unwrap_or_default()
+                    .into_iter()
+                    .unzip();
+                let mut game_slugs = FxHashSet::from_iter(game_slugs);
+
+                for slug in &slugs {
+                    game_slugs.remove(slug);
+                }
 
-            for slug in &slugs {
-                game_slugs.remove(slug);
-            }
+                (slugs, images, Vec::from_iter(game_slugs))
+            })
+            .await;
 
-            let game_slugs = Vec::from_iter(game_slugs);
+            let cover_gatherer = if !game_slugs.is_empty() {
+                ::log::info!(
+                    "looking for {} thumbnails in {cover_dir:?}",
+                    game_slugs.len()
+                );
+                let options = CoverGathererOptions {
+                    slugs: Some(game_slugs),
+                    ..Default::default()
+                };
+                CoverGatherer::spawn(cover_dir.clone(), options)
+                    .await
+                    .map_err(|err| ::log::warn!("could not read cover dir {cover_dir:?}\n{err}"))
+                    .ok()
+            } else {
+                ::log::info!("no need to load covers from {cover_dir:?}");
+                None
+            };
 
-            (game_slugs, slugs, images)
-        });
+            (cover_gatherer, slugs, images)
+        };
 
-        Task::future(find_cached).then(move |(game_slugs, slugs, images)| {
+        Task::future(find_cached).then(move |(cover_gatherer, slugs, images)| {
+            let busy = busy.take();
             let set_images = Message::SetImages {
                 slugs,
                 images,
@@ -532,37 +729,147 @@ impl State {
             .pipe(OrRequest::Message)
             .pipe(Task::done);
 
-            let load_covers = if !game_slugs.is_empty() {
-                ::log::info!(
-                    "looking for {} thumbnails in {cover_dir:?}",
-                    game_slugs.len()
-                );
-                CoverGatherer::with_options(
-                    &cover_dir,
-                    CoverGathererOptions {
-                        slugs: Some(game_slugs),
-                        ..Default::default()
-                    },
-                )
-                .map_err(|err| ::log::warn!("could not read cover dir {cover_dir:?}\n{err}"))
-                .map(|cover_gatherer| cover_gatherer.into_stream().pipe(Task::stream))
-                .ok()
+            let load_covers = cover_gatherer
+                .map(|cover_gatherer| cover_gatherer.into_progress_stream().pipe(Task::stream))
                 .unwrap_or_else(Task::none)
-                .map(|(slug, image)| Message::SetImage {
-                    slug,
-                    image,
-                    add_to_cache: true,
+                .map(move |(progress, (slug, image))| {
+                    // Keep operation active until all covers are gathered.
+                    if let Some(busy) = &busy {
+                        busy.set_progress(progress.processed, progress.total);
+                    }
+                    Message::SetImage {
+                        slug,
+                        image,
+                        add_to_cache: true,
+                    }
                 })
-                .map(OrRequest::Message)
-            } else {
-                ::log::info!("no need to load covers from {cover_dir:?}");
-                Task::none()
-            };
+                .map(OrRequest::Message);
 
             Task::batch([set_images, load_covers])
         })
     }
 
+    /// Import covers of games without a thumbnail from lutris coverart cache.
+    pub fn import_lutris_covers(
+        &mut self,
+        tx: &StatusSender,
+        settings: &Settings,
+    ) -> Task<OrRequest<Message, Request>> {
+        let Some(cache_dir) = settings.xdg().get_cache_home() else {
+            ::log::error!("could not get cache dir");
+            return Task::none();
+        };
+        let Some(coverart_dir) = lutris_coverart_dir(DATA.as_path(), HOME.as_path()) else {
+            status!(tx, "could not find lutris coverart directory");
+            return Task::none();
+        };
+
+        let slugs = self
+            .all()
+            .iter()
+            .filter(|game| game.thumb.is_none())
+            .filter_map(|game| game.slug().map(ToOwned::to_owned))
+            .collect::<Vec<_>>();
+
+        let tx = tx.clone();
+        let busy = self.busy.start("importing lutris covers");
+        Task::future(async move {
+            let _busy = busy;
+            let result = ::smol::unblock(move || {
+                let db_path = cache_dir.join(THUMBNAILS_FILENAME);
+                let imported = FxHashSet::from_iter(import_lutris_covers(
+                    &coverart_dir,
+                    slugs,
+                    &db_path,
+                    CoverGathererOptions::default().dimensions,
+                )?);
+                let (slugs, images) = load_thumbnail_database(&db_path)?
+                    .into_iter()
+                    .filter(|(slug, _)| imported.contains(slug))
+                    .unzip::<_, _, Vec<_>, Vec<_>>();
+                Ok::<_, LoadDbError>((slugs, images))
+            })
+            .await;
+
+            match result {
+                Ok((slugs, images)) => {
+                    async_status!(tx, "imported {} lutris covers", slugs.len()).await;
+                    Message::SetImages {
+                        slugs,
+                        images,
+                        add_to_cache: false,
+                    }
+                    .pipe(OrRequest::Message)
+                    .pipe(Task::done)
+                }
+                Err(err) => {
+                    ::log::error!("could not import lutris covers\n{err}");
+                    async_status!(tx, "could not import lutris covers").await;
+                    Task::none()
+                }
+            }
+        })
+        .then(identity)
+    }
+
+    /// Fetch covers from urls into the thumbnail cache, setting the fetched thumbnails.
+    pub fn import_remote_covers(
+        &mut self,
+        urls: BTreeMap<String, String>,
+        tx: &StatusSender,
+        settings: &Settings,
+    ) -> Task<OrRequest<Message, Request>> {
+        let Some(cache_dir) = settings.xdg().get_cache_home() else {
+            ::log::error!("could not get cache dir");
+            return Task::none();
+        };
+        let db_path = cache_dir.join(THUMBNAILS_FILENAME);
+
+        let tx = tx.clone();
+        let busy = self.busy.start("fetching covers");
+        Task::future(async move {
+            let _busy = busy;
+            let result = async {
+                let report =
+                    import_remote_covers(urls, db_path.clone(), &RemoteCoverOptions::default())
+                        .await?;
+                let fetched = FxHashSet::from_iter(report.succeeded.iter().cloned());
+                let (slugs, images) = ::smol::unblock(move || load_thumbnail_database(&db_path))
+                    .await?
+                    .into_iter()
+                    .filter(|(slug, _)| fetched.contains(slug))
+                    .unzip::<_, _, Vec<_>, Vec<_>>();
+                Ok::<_, LoadDbError>((report, slugs, images))
+            }
+            .await;
+
+            match result {
+                Ok((report, slugs, images)) => {
+                    async_status!(tx, "{}", report.summary()).await;
+                    Message::SetImages {
+                        slugs,
+                        images,
+                        add_to_cache: false,
+                    }
+                    .pipe(OrRequest::Message)
+                    .pipe(Task::done)
+                }
+                Err(err) => {
+                    ::log::error!("could not fetch covers\n{err}");
+                    async_status!(tx, "could not fetch covers").await;
+                    Task::none()
+                }
+            }
+        })
+        .then(identity)
+    }
+
+    /// Set lutris ids of games with terminal output available.
+    pub fn set_with_output(&mut self, ids: impl IntoIterator<Item = i64>) {
+        self.with_output.clear();
+        self.with_output.extend(ids);
+    }
+
     /// Deselect all batch selected games.
     pub fn deselect_batch(&mut self) {
         for game in self.all_mut() {
@@ -575,42 +882,81 @@ impl State {
     /// Select a game in a direction.
     pub fn select(&mut self, sel_dir: SelDir) {
         use SelDir::{Down, Left, Right, Up};
-        let Some(selected) = self.selected else {
+        let rows = self.rows();
+
+        let position = self.selected.and_then(|selected| {
+            rows.iter().enumerate().find_map(|(row, ids)| {
+                ids.iter()
+                    .position(|id| *id == selected)
+                    .map(|column| (row, column))
+            })
+        });
+
+        let Some((row, column)) = position else {
             self.selected = match sel_dir {
-                Up | Left => self.displayed().next_back(),
-                Down | Right => self.displayed().next(),
+                Up | Left => rows.last().and_then(|ids| ids.last()),
+                Down | Right => rows.first().and_then(|ids| ids.first()),
                 SelDir::None => Option::None,
             }
-            .map(|game| game.id());
+            .copied();
             return;
         };
 
-        let m = |game: &WithThumb| game.id() == selected;
-
-        let idx = match sel_dir {
-            Up | Left => self.displayed().rev().position(m),
-            Down | Right => self.displayed().position(m),
-            SelDir::None => Option::None,
-        };
-
-        let Some(idx) = idx else {
-            self.selected = None;
-            self.select(sel_dir);
-            return;
+        let prev = row.checked_sub(1).unwrap_or(rows.len() - 1);
+        let next = (row + 1) % rows.len();
+        let at = |row: usize, column: usize| {
+            let ids = &rows[row];
+            ids.get(column).or(ids.last()).copied()
         };
 
         self.selected = match sel_dir {
-            Up => self.displayed().rev().cycle().nth(idx + self.columns()),
-            Down => self.displayed().cycle().nth(idx + self.columns()),
-            Left => self.displayed().rev().cycle().nth(idx + 1),
-            Right => self.displayed().cycle().nth(idx + 1),
+            Up => at(prev, column),
+            Down => at(next, column),
+            Left if column == 0 => at(prev, usize::MAX),
+            Left => at(row, column - 1),
+            Right if column + 1 == rows[row].len() => at(next, 0),
+            Right => at(row, column + 1),
             SelDir::None => Option::None,
+        };
+    }
+
+    /// Displayed games in groups, with a header for each group if grouped.
+    fn groups(&self) -> Vec<(Option<String>, Vec<&WithThumb>)> {
+        let (group_by, sort_dir) = self.grouping.get();
+        let reverse = sort_dir.is_reverse();
+        match group_by {
+            GroupBy::None => vec![(None, self.displayed().collect())],
+            GroupBy::Runner => group_by_key(self.displayed(), |game| game.runner_name(), reverse)
+                .into_iter()
+                .map(|(runner, games)| (Some(runner), games))
+                .collect(),
+            GroupBy::Letter => group_by_key(self.displayed(), |game| game.initial(), reverse)
+                .into_iter()
+                .map(|(initial, games)| (Some(initial.to_string()), games))
+                .collect(),
         }
-        .map(|game| game.id());
+    }
+
+    /// Ids of displayed games by row as laid out in view, groups start on a new row.
+    fn rows(&self) -> Vec<Vec<GameId>> {
+        let columns = self.columns().max(1);
+        self.groups()
+            .into_iter()
+            .flat_map(|(_, games)| {
+                games
+                    .chunks(columns)
+                    .map(|chunk| chunk.iter().map(|game| game.id()).collect())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
     }
 
     /// Get a card to display a game thumbnail.
-    fn card<'a>(&self, game: &'a WithThumb) -> Element<'a, OrRequest<Message, Request>> {
+    fn card<'a>(
+        &self,
+        game: &'a WithThumb,
+        settings: &Settings,
+    ) -> Element<'a, OrRequest<Message, Request>> {
         let id = game.id();
         let handle = game.thumb.as_ref().or(game.thumb_thumb.as_ref());
         let selected = self.selected;
@@ -658,21 +1004,95 @@ impl State {
             .pipe(widget::bottom_center)
             .padding(3);
 
-        let element = match handle {
-            Some(handle) => {
-                let image = widget::image(handle)
+        let favorite = game.favorite.then(|| {
+            widget::text("★")
+                .size(14)
+                .pipe(container)
+                .padding(3)
+                .style(style)
+                .pipe(container)
+                .padding(3)
+                .into()
+        });
+
+        let pinned = game.pinned.then(|| {
+            widget::text("▲")
+                .size(14)
+                .pipe(container)
+                .padding(3)
+                .style(style)
+                .pipe(widget::right)
+                .padding(3)
+                .into()
+        });
+
+        let radius = thumbnail_radius(settings);
+        let background: Option<Element<'a, _>> = match self.card_content(game, settings) {
+            CardContent::Thumbnail => handle.map(|handle| {
+                widget::image(handle)
                     .width(Fill)
-                    .content_fit(::iced_core::ContentFit::Contain);
-                widget::mouse_area(stack([image.into(), text.into()]))
-            }
-            None => widget::mouse_area(text),
-        }
+                    .content_fit(::iced_core::ContentFit::Contain)
+                    .border_radius(radius)
+                    .into()
+            }),
+            CardContent::Placeholder => match settings.get::<ThumbnailPlaceholder>() {
+                ThumbnailPlaceholder::Color => {
+                    let color = ::spel_katalog_common::styling::name_color(name);
+                    widget::Space::new()
+                        .pipe(container)
+                        .width(Fill)
+                        .height(200)
+                        .style(move |_| {
+                            container::Style::default()
+                                .background(color)
+                                .border(Border::default().rounded(radius))
+                        })
+                        .pipe(Element::from)
+                        .pipe(Some)
+                }
+                ThumbnailPlaceholder::Image => settings
+                    .get::<PlaceholderImage>()
+                    .to_path_buf()
+                    .pipe(widget::image)
+                    .width(Fill)
+                    .content_fit(::iced_core::ContentFit::Contain)
+                    .border_radius(radius)
+                    .pipe(Element::from)
+                    .pipe(Some),
+                ThumbnailPlaceholder::None => None,
+            },
+            CardContent::Text => None,
+        };
+
+        let element = widget::mouse_area(stack(
+            background
+                .into_iter()
+                .chain(iter::once(text.into()))
+                .chain(favorite)
+                .chain(pinned),
+        ))
         .interaction(::iced_core::mouse::Interaction::Pointer)
         .on_release(AreaMessage::Select { id })
         .on_middle_release(AreaMessage::Run { id, sandbox: true })
+        .pipe(|element| {
+            let summary = game
+                .summary()
+                .into_iter()
+                .map(|(label, value)| format!("{label}: {value}"))
+                .join("\n");
+            widget::tooltip(
+                element,
+                container(widget::text(summary).size(12))
+                    .padding(4)
+                    .style(container::bordered_box),
+                widget::tooltip::Position::FollowCursor,
+            )
+            .delay(Duration::from_millis(600))
+        })
         .pipe(Element::from)
         .map(OrRequest::<Message, Request>::from);
 
+        let has_output = matches!(id, GameId::Lutris(id) if self.with_output.contains(&id));
         let element = ContextMenu::new(element, move || {
             ::spel_katalog_widget::ListMenu::new()
                 .push("Spel Katalog")
@@ -686,6 +1106,9 @@ impl State {
                 })
                 .button("Batch", move || Message::BatchSelect(id).into_message())
                 .button("Info", move || Message::SelectId(id).into_message())
+                .button_if(has_output, "Output", move || {
+                    Request::ShowOutput(id).into_request()
+                })
                 .separator()
                 .button("Convert", move || Request::Convert(id).into_request())
                 .into()
@@ -696,48 +1119,66 @@ impl State {
         element
     }
 
-    /// Render elements.
-    pub fn view(&self, settings: &Settings) -> Element<'_, OrRequest<Message, Request>> {
+    /// Get a row of cards, watched by a sensor for thumbnail loading.
+    fn row<'a>(
+        &self,
+        chunk: &[&'a WithThumb],
+        columns: usize,
+        settings: &Settings,
+    ) -> Element<'a, OrRequest<Message, Request>> {
         let should_unload_thumbnails = settings.get::<UnloadThumbnails>().is_yes();
+        let mut grid = widget::Grid::new().columns(columns).spacing(4);
+        let mut watched = Vec::new();
+
+        for game in chunk {
+            grid = grid.push(self.card(game, settings));
+            if let GameId::Native(uuid) = game.id() {
+                watched.push(uuid);
+            }
+        }
+
+        let watched = Arc::<[_]>::from(watched);
+
+        if watched.is_empty() {
+            Element::from(grid)
+        } else {
+            let sensor = Sensor::new(grid).key((watched.first().copied(), watched.last().copied()));
+
+            if should_unload_thumbnails {
+                let watched = watched.clone();
+                sensor.on_hide(
+                    Message::UpdateThumbnails(
+                        watched.iter().cloned().map(|uuid| (uuid, None)).collect(),
+                    )
+                    .into_message(),
+                )
+            } else {
+                sensor
+            }
+            .on_show(move |_| Message::LoadThumbnails(watched.clone()).into_message())
+            .anticipate(200)
+            .into()
+        }
+    }
+
+    /// Render elements.
+    pub fn view<'a>(&'a self, settings: &'a Settings) -> Element<'a, OrRequest<Message, Request>> {
+        self.grouping
+            .set((*settings.get::<GroupBy>(), *settings.get::<SortDir>()));
         widget::responsive(move |size| {
             let columns = ((size.width / 150.0) as usize).clamp(1, 24);
             self.columns.set(columns);
 
             spel_katalog_widget::scrollable(widget::Column::new().width(Fill).spacing(4).extend(
-                self.displayed().chunks(columns).into_iter().map(|chunk| {
-                    let mut grid = widget::Grid::new().columns(columns).spacing(4);
-                    let mut watched = Vec::new();
-
-                    for game in chunk {
-                        grid = grid.push(self.card(game));
-                        if let GameId::Native(uuid) = game.id() {
-                            watched.push(uuid);
-                        }
-                    }
-
-                    let watched = Arc::<[_]>::from(watched);
-
-                    if watched.is_empty() {
-                        grid.into()
-                    } else {
-                        let sensor = Sensor::new(grid)
-                            .key((watched.first().copied(), watched.last().copied()));
-
-                        if should_unload_thumbnails {
-                            let watched = watched.clone();
-                            sensor.on_hide(
-                                Message::UpdateThumbnails(
-                                    watched.iter().cloned().map(|uuid| (uuid, None)).collect(),
-                                )
-                                .into_message(),
-                            )
-                        } else {
-                            sensor
-                        }
-                        .on_show(move |_| Message::LoadThumbnails(watched.clone()).into_message())
-                        .anticipate(200)
-                        .into()
-                    }
+                self.groups().into_iter().flat_map(|(header, games)| {
+                    let header =
+                        header.map(|header| widget::text(header).size(18).pipe(Element::from));
+                    header.into_iter().chain(
+                        games
+                            .chunks(columns)
+                            .map(|chunk| self.row(chunk, columns, settings))
+                            .collect::<Vec<_>>(),
+                    )
                 }),
             ))
             .id(widget::Id::new("games-view"))
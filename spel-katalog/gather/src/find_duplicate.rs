@@ -0,0 +1,59 @@
+//! Functions to find duplicate thumbnails.
+
+use ::std::path::Path;
+
+use ::rusqlite::{Connection, OpenFlags, OptionalExtension, named_params};
+use ::spel_katalog_formats::Image;
+
+use crate::LoadDbError;
+
+/// Find a thumbnail identical to `image` among `cached` thumbnails,
+/// ignoring the one cached for `slug`.
+///
+/// Returns the slug of the first duplicate found.
+pub fn find_duplicate_thumbnail<'a>(
+    image: &Image,
+    slug: &str,
+    cached: &'a [(String, Image)],
+) -> Option<&'a str> {
+    cached
+        .iter()
+        .find(|(other_slug, other)| other_slug != slug && other == image)
+        .map(|(other_slug, _)| other_slug.as_str())
+}
+
+/// Find a thumbnail identical to `image` in the thumbnail database at `db_path`,
+/// ignoring the one cached for `slug`.
+///
+/// The image is encoded the same way thumbnails are cached and compared
+/// against the stored bytes, such that no cached thumbnail has to be decoded.
+///
+/// Returns the slug of the first duplicate found, if the database cannot be
+/// read no duplicate is found.
+pub fn find_cached_duplicate(db_path: &Path, image: &Image, slug: &str) -> Option<String> {
+    let png = image
+        .to_png()
+        .map_err(|err| ::log::warn!("could not encode thumbnail for {slug} as png\n{err}"))
+        .ok()?;
+
+    query_duplicate(db_path, &png, slug)
+        .map_err(|err| ::log::warn!("could not query thumbnail cache at {db_path:?}\n{err}"))
+        .ok()
+        .flatten()
+}
+
+/// Query thumbnail database for a slug other than `slug` with an image equal to `png`.
+fn query_duplicate(db_path: &Path, png: &[u8], slug: &str) -> Result<Option<String>, LoadDbError> {
+    let db = Connection::open_with_flags(
+        db_path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?;
+    let slug = db
+        .prepare_cached("SELECT slug FROM images WHERE image = :image AND slug != :slug LIMIT 1")?
+        .query_row(named_params! {":image": png, ":slug": slug}, |row| {
+            row.get("slug")
+        })
+        .optional()?;
+
+    Ok(slug)
+}
@@ -0,0 +1,85 @@
+//! Import thumbnails from the coverart cache of lutris.
+
+use ::std::path::{Path, PathBuf};
+
+use ::rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{LoadDbError, load_thumbnail_db::store_thumbnails, open_cover, thumbnail};
+
+/// Extensions of lutris covers, in order of preference.
+const COVER_EXTENSIONS: [&str; 3] = ["png", "jpg", "jpeg"];
+
+/// Path of lutris data directory when installed as a flatpak, relative to home.
+const FLATPAK_DATA: &str = ".var/app/net.lutris.Lutris/data";
+
+/// Resolve the coverart directory of lutris.
+///
+/// `{data_home}/lutris/coverart` is used if it exists, otherwise the
+/// flatpak location `{home}/.var/app/net.lutris.Lutris/data/lutris/coverart`
+/// is used if it exists.
+pub fn lutris_coverart_dir(data_home: &Path, home: &Path) -> Option<PathBuf> {
+    [data_home.to_path_buf(), home.join(FLATPAK_DATA)]
+        .into_iter()
+        .map(|data| data.join("lutris").join("coverart"))
+        .find(|dir| dir.is_dir())
+}
+
+/// Find the cover of a slug in lutris coverart directory.
+///
+/// Covers are named after the slug of the game, if multiple images exist
+/// for the same slug png is preferred.
+pub fn find_lutris_cover(coverart_dir: &Path, slug: &str) -> Option<PathBuf> {
+    if slug.is_empty() || slug.contains(['/', '\\']) {
+        return None;
+    }
+
+    COVER_EXTENSIONS
+        .into_iter()
+        .map(|ext| coverart_dir.join(format!("{slug}.{ext}")))
+        .find(|path| path.is_file())
+}
+
+/// Import covers of given slugs from lutris coverart directory into the
+/// thumbnail database at `db_path`, scaling them to at most `dimensions`.
+///
+/// Slugs without a cover, and covers that cannot be read, are skipped.
+/// Returns the slugs that were imported.
+///
+/// # Errors
+/// If the thumbnail database cannot be opened or written to.
+pub fn import_lutris_covers(
+    coverart_dir: &Path,
+    slugs: Vec<String>,
+    db_path: &Path,
+    dimensions: u32,
+) -> Result<Vec<String>, LoadDbError> {
+    let images = slugs
+        .into_par_iter()
+        .filter_map(|slug| {
+            let path = find_lutris_cover(coverart_dir, &slug)?;
+            let image = open_cover(&path)
+                .map_err(|err| {
+                    ::log::warn!("could not read lutris cover for {slug} from {path:?}\n{err}")
+                })
+                .ok()?;
+
+            let image = thumbnail(image, dimensions)
+                .to_png()
+                .map_err(|err| {
+                    ::log::warn!("could not convert lutris cover for {slug} to png\n{err}")
+                })
+                .ok()?;
+
+            Some((slug, image))
+        })
+        .collect::<Vec<_>>();
+
+    let imported = store_thumbnails(db_path, images)?;
+
+    ::log::info!(
+        "imported {} thumbnails from lutris coverart in {coverart_dir:?}",
+        imported.len()
+    );
+
+    Ok(imported)
+}
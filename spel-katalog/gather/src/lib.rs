@@ -1,13 +1,28 @@
 //! Utilities to gather resources.
 
+mod find_duplicate;
+mod import_lutris;
 mod load_covers;
+mod load_favorites;
 mod load_game_db;
 mod load_thumbnail_db;
+mod remote_covers;
+mod scan_executables;
 
 pub use self::{
-    load_covers::{CoverGatherer, CoverGathererOptions, thumbnail},
+    find_duplicate::{find_cached_duplicate, find_duplicate_thumbnail},
+    import_lutris::{find_lutris_cover, import_lutris_covers, lutris_coverart_dir},
+    load_covers::{
+        CoverGatherer, CoverGathererOptions, GatherProgress, load_cover, open_cover, thumbnail,
+    },
+    load_favorites::{load_favorites, load_pinned},
     load_game_db::load_games_from_database,
-    load_thumbnail_db::load_thumbnail_database,
+    load_thumbnail_db::{THUMBNAILS_FILENAME, load_thumbnail_database},
+    remote_covers::{
+        CoverUrlsError, FetchCoverError, RemoteCoverOptions, RemoteCoverReport, fetch_cover,
+        fetch_covers, import_remote_covers, parse_cover_urls, read_cover_urls,
+    },
+    scan_executables::{executable_runner, remove_known, scan_executables},
 };
 
 /// Errors occuring during database load.
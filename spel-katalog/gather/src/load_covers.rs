@@ -1,14 +1,24 @@
 //! Functions to gather game covers.
 
+use ::core::{
+    fmt::Display,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use ::std::{
-    borrow::Cow,
     collections::hash_map::Entry,
     fs::ReadDir,
+    io::{BufRead, Cursor, Seek},
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
-use ::futures::Stream;
-use ::image::{DynamicImage, imageops::FilterType::Lanczos3};
+use ::futures::{Stream, StreamExt};
+use ::image::{
+    AnimationDecoder, DynamicImage, ImageError, ImageFormat, ImageReader, ImageResult,
+    codecs::{gif::GifDecoder, webp::WebPDecoder},
+    error::{DecodingError, ImageFormatHint},
+    imageops::FilterType::Lanczos3,
+};
 use ::rayon::iter::{IntoParallelIterator, ParallelBridge, ParallelIterator};
 use ::rustc_hash::FxHashMap;
 
@@ -41,11 +51,38 @@ impl Default for CoverGathererOptions {
     }
 }
 
+/// Progress of gathering covers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GatherProgress {
+    /// Amount of candidates processed, including those that could not be loaded.
+    pub processed: usize,
+    /// Total amount of candidates.
+    pub total: usize,
+}
+
+impl GatherProgress {
+    /// Check if all candidates have been processed.
+    pub const fn is_done(self) -> bool {
+        self.processed >= self.total
+    }
+}
+
+impl Display for GatherProgress {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let Self { processed, total } = self;
+        write!(f, "{processed}/{total}")
+    }
+}
+
 /// Iterator or Stream receiving gathered covers.
 #[derive(Debug, Clone)]
 pub struct CoverGatherer {
     /// Receiver of loaded covers.
     receiver: ::flume::Receiver<(String, ::spel_katalog_formats::Image)>,
+    /// Total amount of candidate covers.
+    total: usize,
+    /// Amount of processed candidate covers.
+    processed: Arc<AtomicUsize>,
 }
 
 /// Gather covers from directory reader.
@@ -95,6 +132,55 @@ fn gather_covers(dir: ReadDir) -> FxHashMap<String, PathBuf> {
         })
 }
 
+/// Get first frame of an animation.
+fn first_frame<'a>(
+    decoder: impl AnimationDecoder<'a>,
+    format: ImageFormat,
+) -> ImageResult<DynamicImage> {
+    let frame = decoder.into_frames().next().ok_or_else(|| {
+        ImageError::Decoding(DecodingError::new(
+            ImageFormatHint::Exact(format),
+            "animation contains no frames",
+        ))
+    })??;
+    Ok(DynamicImage::ImageRgba8(frame.into_buffer()))
+}
+
+/// Decode a cover, animated covers are decoded as their first frame.
+fn decode_cover(reader: ImageReader<impl BufRead + Seek>) -> ImageResult<DynamicImage> {
+    let reader = reader.with_guessed_format()?;
+    match reader.format() {
+        Some(format @ ImageFormat::Gif) => {
+            first_frame(GifDecoder::new(reader.into_inner())?, format)
+        }
+        Some(format @ ImageFormat::WebP) => {
+            let decoder = WebPDecoder::new(reader.into_inner())?;
+            if decoder.has_animation() {
+                first_frame(decoder, format)
+            } else {
+                DynamicImage::from_decoder(decoder)
+            }
+        }
+        _ => reader.decode(),
+    }
+}
+
+/// Open cover at given path, animated covers are decoded as their first frame.
+///
+/// # Errors
+/// If the file cannot be read or decoded as an image.
+pub fn open_cover(path: &Path) -> ImageResult<DynamicImage> {
+    decode_cover(ImageReader::open(path)?)
+}
+
+/// Load cover from memory, animated covers are decoded as their first frame.
+///
+/// # Errors
+/// If the bytes cannot be decoded as an image.
+pub fn load_cover(bytes: &[u8]) -> ImageResult<DynamicImage> {
+    decode_cover(ImageReader::new(Cursor::new(bytes)))
+}
+
 /// Process a single image into a thumbnail.
 pub fn thumbnail(image: DynamicImage, dimensions: u32) -> ::spel_katalog_formats::Image {
     let image = if dimensions != 0 && (image.width() > dimensions || image.height() > dimensions) {
@@ -127,6 +213,10 @@ impl CoverGatherer {
 
     /// Create a new CoverGatherer for the given path and covers using the provided options.
     ///
+    /// The directory is scanned for candidates before returning, such that [CoverGatherer::total]
+    /// is known up front, loading of the covers happens in the background. As the scan
+    /// blocks, async callers should create the gatherer using [CoverGatherer::spawn].
+    ///
     /// # Errors
     /// If `cover_dir` does not exist or is not a diorectory.
     pub fn with_options(
@@ -145,30 +235,31 @@ impl CoverGatherer {
             ::flume::bounded(channel_size)
         };
 
-        ::rayon::spawn(move || {
-            let covers = gather_covers(dir);
-            let covers = if let Some(slugs) = slugs {
-                slugs
-                    .into_iter()
-                    .filter_map(|slug| {
-                        let path = covers.get(&slug)?.as_path();
-                        Some((slug, Cow::Borrowed(path)))
-                    })
-                    .collect::<Vec<_>>()
-            } else {
-                covers
-                    .into_iter()
-                    .map(|(key, value)| (key, Cow::Owned(value)))
-                    .collect::<Vec<_>>()
-            };
+        let covers = gather_covers(dir);
+        let covers = if let Some(slugs) = slugs {
+            slugs
+                .into_iter()
+                .filter_map(|slug| {
+                    let path = covers.get(&slug)?.clone();
+                    Some((slug, path))
+                })
+                .collect::<Vec<_>>()
+        } else {
+            covers.into_iter().collect::<Vec<_>>()
+        };
+        let total = covers.len();
+        let processed = Arc::new(AtomicUsize::new(0));
 
+        let counter = processed.clone();
+        ::rayon::spawn(move || {
             let result = covers
                 .into_par_iter()
                 .try_fold(
                     || 0usize,
                     |c, (slug, path)| {
-                        let path = path.as_ref();
-                        let image = match ::image::open(path) {
+                        let image = open_cover(&path);
+                        counter.fetch_add(1, Ordering::Relaxed);
+                        let image = match image {
                             Err(err) => {
                                 ::log::warn!(
                                     "could not read image for {slug} from {path:?}\n{err}"
@@ -196,14 +287,61 @@ impl CoverGatherer {
             }
         });
 
-        Ok(Self { receiver: rx })
+        Ok(Self {
+            receiver: rx,
+            total,
+            processed,
+        })
+    }
+
+    /// Create a new CoverGatherer using [CoverGatherer::with_options], scanning `cover_dir`
+    /// on a blocking thread.
+    ///
+    /// # Errors
+    /// If `cover_dir` does not exist or is not a diorectory.
+    pub async fn spawn(
+        cover_dir: PathBuf,
+        options: CoverGathererOptions,
+    ) -> ::std::io::Result<Self> {
+        ::smol::unblock(move || Self::with_options(&cover_dir, options)).await
+    }
+
+    /// Total amount of candidate covers, known when created.
+    pub const fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Current progress of gathering.
+    pub fn progress(&self) -> GatherProgress {
+        GatherProgress {
+            processed: self.processed.load(Ordering::Relaxed),
+            total: self.total,
+        }
     }
 
     /// Convert into a stream of slugs and images.
     pub fn into_stream(self) -> impl Stream<Item = (String, ::spel_katalog_formats::Image)> {
-        let Self { receiver } = self;
+        let Self { receiver, .. } = self;
         receiver.into_stream()
     }
+
+    /// Convert into a stream of slugs and images, with the progress at the time each was received.
+    pub fn into_progress_stream(
+        self,
+    ) -> impl Stream<Item = (GatherProgress, (String, ::spel_katalog_formats::Image))> {
+        let Self {
+            receiver,
+            total,
+            processed,
+        } = self;
+        receiver.into_stream().map(move |item| {
+            let progress = GatherProgress {
+                processed: processed.load(Ordering::Relaxed),
+                total,
+            };
+            (progress, item)
+        })
+    }
 }
 
 impl IntoIterator for CoverGatherer {
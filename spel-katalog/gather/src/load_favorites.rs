@@ -0,0 +1,57 @@
+//! Functions to load favorite and pinned games from additional configs.
+
+use ::std::path::Path;
+
+use ::spel_katalog_formats::AdditionalConfig;
+
+/// Load ids of lutris games marked as favorites in the additional configs
+/// stored in given directory.
+pub fn load_favorites(games_dir: &Path) -> Vec<i64> {
+    load_marked(games_dir, |additional| additional.favorite)
+}
+
+/// Load ids of lutris games marked as pinned in the additional configs
+/// stored in given directory.
+pub fn load_pinned(games_dir: &Path) -> Vec<i64> {
+    load_marked(games_dir, |additional| additional.pinned)
+}
+
+/// Load ids of lutris games for which `marked` holds in the additional configs
+/// stored in given directory.
+fn load_marked(games_dir: &Path, marked: fn(&AdditionalConfig) -> bool) -> Vec<i64> {
+    if !games_dir.exists() {
+        return Vec::new();
+    }
+
+    let entries = match ::std::fs::read_dir(games_dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            ::log::error!("could not read additional config dir {games_dir:?}\n{err}");
+            return Vec::new();
+        }
+    };
+
+    entries
+        .filter_map(|entry| {
+            let path = entry
+                .map_err(|err| ::log::warn!("could not read entry of {games_dir:?}\n{err}"))
+                .ok()?
+                .path();
+
+            if path.extension().is_none_or(|ext| ext != "toml") {
+                return None;
+            }
+
+            let id = path.file_stem()?.to_str()?.parse::<i64>().ok()?;
+
+            let content = ::std::fs::read_to_string(&path)
+                .map_err(|err| ::log::warn!("could not read {path:?}\n{err}"))
+                .ok()?;
+            let additional = ::toml::from_str::<AdditionalConfig>(&content)
+                .map_err(|err| ::log::warn!("could not deserialize {path:?}\n{err}"))
+                .ok()?;
+
+            marked(&additional).then_some(id)
+        })
+        .collect()
+}
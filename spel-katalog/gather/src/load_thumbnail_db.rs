@@ -3,10 +3,13 @@
 use ::std::path::Path;
 
 use ::rayon::iter::{IntoParallelIterator, ParallelIterator};
-use ::rusqlite::{Connection, OpenFlags};
+use ::rusqlite::{Connection, OpenFlags, named_params};
 
 use crate::LoadDbError;
 
+/// Filename of thumbnails cache database.
+pub const THUMBNAILS_FILENAME: &str = "thumbnails.db";
+
 /// Load thumbnail database.
 ///
 /// # Errors
@@ -69,3 +72,34 @@ pub fn load_thumbnail_database(
 
     Ok(loaded)
 }
+
+/// Store png encoded thumbnails in the thumbnail database at `db_path`,
+/// creating the images table if needed and replacing existing entries.
+/// Returns the slugs that were stored.
+///
+/// # Errors
+/// If the thumbnail database cannot be opened or written to.
+pub(crate) fn store_thumbnails(
+    db_path: &Path,
+    images: Vec<(String, Vec<u8>)>,
+) -> Result<Vec<String>, LoadDbError> {
+    let db = Connection::open(db_path)?;
+    db.execute(
+        r#"
+CREATE TABLE IF NOT EXISTS images(
+    slug TEXT NOT NULL UNIQUE ON CONFLICT REPLACE,
+    image BLOB NOT NULL
+)
+"#,
+        [],
+    )?;
+
+    let mut stmt = db.prepare_cached("INSERT INTO images (slug, image) VALUES (:slug, :image)")?;
+    let mut stored = Vec::with_capacity(images.len());
+    for (slug, image) in images {
+        stmt.execute(named_params! {":slug": slug, ":image": image})?;
+        stored.push(slug);
+    }
+
+    Ok(stored)
+}
@@ -0,0 +1,444 @@
+//! Gather covers from a list of remote urls.
+//!
+//! Urls using the `file://` scheme are always supported, `http://` and
+//! `https://` urls require the `network` feature.
+
+use ::core::{fmt::Display, time::Duration};
+use ::std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use ::futures::{StreamExt, stream};
+use ::smol::{Timer, future::FutureExt};
+
+use crate::{LoadDbError, load_cover, load_thumbnail_db::store_thumbnails, thumbnail};
+
+/// Options used when fetching remote covers.
+#[derive(Debug, Clone)]
+pub struct RemoteCoverOptions {
+    /// Max amount of covers fetched at the same time.
+    /// A value of 0 is treated as 1.
+    /// (default: 4)
+    pub concurrency: usize,
+
+    /// Time allowed for fetching and decoding a single cover.
+    /// (default: 30s)
+    pub timeout: Duration,
+
+    /// Max Width and height to scale cover to.
+    /// If 0 do not scale.
+    /// (default: 200)
+    pub dimensions: u32,
+}
+
+impl Default for RemoteCoverOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            timeout: Duration::from_secs(30),
+            dimensions: 200,
+        }
+    }
+}
+
+/// Errors occurring when reading a cover url list.
+#[derive(Debug, ::thiserror::Error)]
+pub enum CoverUrlsError {
+    /// List could not be read.
+    #[error("could not read cover url list\n{0}")]
+    Read(#[from] ::std::io::Error),
+    /// List could not be parsed.
+    #[error("could not parse cover url list\n{0}")]
+    Parse(#[from] ::toml::de::Error),
+}
+
+/// Errors occurring when fetching a single cover.
+#[derive(Debug, ::thiserror::Error)]
+pub enum FetchCoverError {
+    /// Url uses a scheme that is not supported.
+    #[error("unsupported url {0:?}")]
+    UnsupportedUrl(String),
+    /// Url requires network support which is not enabled.
+    #[error("network support is not enabled, cannot fetch {0:?}")]
+    NetworkDisabled(String),
+    /// Local file could not be read.
+    #[error("could not read {path:?}\n{source}")]
+    Read {
+        /// Path of file.
+        path: PathBuf,
+        /// Error that occurred.
+        source: ::std::io::Error,
+    },
+    /// Fetched bytes could not be decoded as an image.
+    #[error("could not decode image\n{0}")]
+    Decode(#[from] ::image::ImageError),
+    /// Fetching took longer than allowed.
+    #[error("timed out after {0:?}")]
+    Timeout(Duration),
+    /// Could not connect to host.
+    #[cfg(feature = "network")]
+    #[error("could not connect to host\n{0}")]
+    Connect(::std::io::Error),
+    /// Http request could not be built.
+    #[cfg(feature = "network")]
+    #[error("could not build http request\n{0}")]
+    HttpRequest(::hyper::http::Error),
+    /// Http communication failed.
+    #[cfg(feature = "network")]
+    #[error("http communication failed\n{0}")]
+    Http(::hyper::Error),
+    /// Server responded with a non success status.
+    #[cfg(feature = "network")]
+    #[error("server responded with status {0}")]
+    Status(u16),
+    /// Url redirected more than [MAX_REDIRECTS] times.
+    #[cfg(feature = "network")]
+    #[error("too many redirects fetching {0:?}")]
+    TooManyRedirects(String),
+    /// Response body was larger than allowed.
+    #[cfg(feature = "network")]
+    #[error("response body larger than {0} bytes")]
+    TooLarge(usize),
+}
+
+/// Outcome of fetching a set of remote covers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RemoteCoverReport {
+    /// Slugs for which a cover was fetched, sorted.
+    pub succeeded: Vec<String>,
+    /// Slugs for which a cover could not be fetched, with the reason, sorted by slug.
+    pub failed: Vec<(String, String)>,
+}
+
+impl RemoteCoverReport {
+    /// Split per slug results into a report and the successful values, both sorted by slug.
+    pub fn split<T, E: Display>(
+        results: impl IntoIterator<Item = (String, Result<T, E>)>,
+    ) -> (Self, Vec<(String, T)>) {
+        let mut report = Self::default();
+        let mut values = Vec::new();
+        for (slug, result) in results {
+            match result {
+                Ok(value) => values.push((slug, value)),
+                Err(err) => report.failed.push((slug, err.to_string())),
+            }
+        }
+        values.sort_by(|(a, _), (b, _)| a.cmp(b));
+        report.failed.sort();
+        report.succeeded = values.iter().map(|(slug, _)| slug.clone()).collect();
+        (report, values)
+    }
+
+    /// Short human readable summary of report.
+    pub fn summary(&self) -> String {
+        format!(
+            "fetched {} covers, {} failed",
+            self.succeeded.len(),
+            self.failed.len()
+        )
+    }
+}
+
+/// Parse a cover url list, a toml table of slugs to urls.
+///
+/// # Errors
+/// If the content is not a toml table of strings.
+pub fn parse_cover_urls(content: &str) -> Result<BTreeMap<String, String>, ::toml::de::Error> {
+    ::toml::from_str(content)
+}
+
+/// Read and parse a cover url list from a file.
+///
+/// # Errors
+/// If the file cannot be read or parsed.
+pub fn read_cover_urls(path: &Path) -> Result<BTreeMap<String, String>, CoverUrlsError> {
+    let content = ::std::fs::read_to_string(path)?;
+    Ok(parse_cover_urls(&content)?)
+}
+
+/// Max amount of redirects followed when fetching a cover.
+#[cfg(feature = "network")]
+pub const MAX_REDIRECTS: usize = 5;
+
+/// Max size in bytes of a fetched cover.
+#[cfg(feature = "network")]
+pub const MAX_COVER_BYTES: usize = 16 * 1024 * 1024;
+
+/// Get the path of a `file://` url, percent decoding it.
+fn file_url_path(path: &str) -> PathBuf {
+    use ::std::{ffi::OsString, os::unix::ffi::OsStringExt};
+
+    let bytes = ::percent_encoding::percent_decode_str(path).collect::<Vec<u8>>();
+    PathBuf::from(OsString::from_vec(bytes))
+}
+
+/// Fetch the raw bytes of a url.
+async fn fetch_bytes(url: &str) -> Result<Vec<u8>, FetchCoverError> {
+    if let Some(path) = url.strip_prefix("file://") {
+        let path = file_url_path(path);
+        return ::smol::unblock(move || {
+            ::std::fs::read(&path).map_err(|source| FetchCoverError::Read { path, source })
+        })
+        .await;
+    }
+
+    if url.starts_with("http://") || url.starts_with("https://") {
+        #[cfg(feature = "network")]
+        return fetch_http(url).await;
+        #[cfg(not(feature = "network"))]
+        return Err(FetchCoverError::NetworkDisabled(url.to_owned()));
+    }
+
+    Err(FetchCoverError::UnsupportedUrl(url.to_owned()))
+}
+
+/// Response to a single http request.
+#[cfg(feature = "network")]
+enum Fetched {
+    /// Body of a successful response.
+    Body(Vec<u8>),
+    /// Location redirected to.
+    Redirect(String),
+}
+
+/// Fetch the body of a http or https url, following at most [MAX_REDIRECTS] redirects.
+#[cfg(feature = "network")]
+async fn fetch_http(url: &str) -> Result<Vec<u8>, FetchCoverError> {
+    use ::hyper::Uri;
+
+    let mut uri = url
+        .parse::<Uri>()
+        .map_err(|_| FetchCoverError::UnsupportedUrl(url.to_owned()))?;
+
+    for _ in 0..=MAX_REDIRECTS {
+        match request(&uri).await? {
+            Fetched::Body(body) => return Ok(body),
+            Fetched::Redirect(location) => {
+                ::log::debug!("{uri} redirected to {location:?}");
+                uri = resolve_redirect(&uri, &location)
+                    .ok_or(FetchCoverError::UnsupportedUrl(location))?;
+            }
+        }
+    }
+
+    Err(FetchCoverError::TooManyRedirects(url.to_owned()))
+}
+
+/// Resolve the location of a redirect relative to the uri that was redirected.
+#[cfg(feature = "network")]
+fn resolve_redirect(uri: &::hyper::Uri, location: &str) -> Option<::hyper::Uri> {
+    use ::hyper::Uri;
+
+    let location = location.parse::<Uri>().ok()?;
+    if location.scheme().is_some() {
+        return Some(location);
+    }
+
+    let mut parts = location.into_parts();
+    parts.scheme = uri.scheme().cloned();
+    parts.authority = uri.authority().cloned();
+    Uri::from_parts(parts).ok()
+}
+
+/// Get the connector used for https urls.
+#[cfg(feature = "network")]
+fn tls_connector() -> ::futures_rustls::TlsConnector {
+    use ::futures_rustls::rustls::{ClientConfig, RootCertStore};
+    use ::std::sync::{Arc, LazyLock};
+
+    static CONFIG: LazyLock<Arc<ClientConfig>> = LazyLock::new(|| {
+        let roots = ::webpki_roots::TLS_SERVER_ROOTS
+            .iter()
+            .cloned()
+            .collect::<RootCertStore>();
+        Arc::new(
+            ClientConfig::builder()
+                .with_root_certificates(roots)
+                .with_no_client_auth(),
+        )
+    });
+
+    ::futures_rustls::TlsConnector::from(CONFIG.clone())
+}
+
+/// Perform a single get request for uri.
+#[cfg(feature = "network")]
+async fn request(uri: &::hyper::Uri) -> Result<Fetched, FetchCoverError> {
+    use ::bytes::Bytes;
+    use ::futures_rustls::rustls::pki_types::ServerName;
+    use ::http_body_util::Empty;
+    use ::hyper::{Request, header::HOST};
+
+    let unsupported = || FetchCoverError::UnsupportedUrl(uri.to_string());
+    let (Some(host), Some(authority)) = (uri.host(), uri.authority()) else {
+        return Err(unsupported());
+    };
+    let https = match uri.scheme_str() {
+        Some("https") => true,
+        Some("http") => false,
+        _ => return Err(unsupported()),
+    };
+    let port = uri.port_u16().unwrap_or(if https { 443 } else { 80 });
+    let path = uri.path_and_query().map_or("/", |path| path.as_str());
+
+    let req = Request::builder()
+        .uri(path)
+        .header(HOST, authority.as_str())
+        .body(Empty::<Bytes>::new())
+        .map_err(FetchCoverError::HttpRequest)?;
+
+    let stream = ::smol::net::TcpStream::connect((host, port))
+        .await
+        .map_err(FetchCoverError::Connect)?;
+
+    if https {
+        let domain = ServerName::try_from(host.to_owned()).map_err(|_| unsupported())?;
+        let stream = tls_connector()
+            .connect(domain, stream)
+            .await
+            .map_err(FetchCoverError::Connect)?;
+        send_request(stream, req).await
+    } else {
+        send_request(stream, req).await
+    }
+}
+
+/// Send a request over stream, reading at most [MAX_COVER_BYTES] of the response body.
+#[cfg(feature = "network")]
+async fn send_request<S>(
+    stream: S,
+    req: ::hyper::Request<::http_body_util::Empty<::bytes::Bytes>>,
+) -> Result<Fetched, FetchCoverError>
+where
+    S: ::futures::AsyncRead + ::futures::AsyncWrite + Unpin,
+{
+    use ::http_body_util::{BodyExt, Limited};
+    use ::hyper::{client::conn::http1, header::LOCATION};
+    use ::smol_hyper::rt::FuturesIo;
+
+    let (mut sender, conn) = http1::handshake(FuturesIo::new(stream))
+        .await
+        .map_err(FetchCoverError::Http)?;
+    // The connection finishing is not an error by itself, should it finish before
+    // a response is received sending the request fails.
+    let run = async move {
+        conn.await.map_err(FetchCoverError::Http)?;
+        ::smol::future::pending().await
+    };
+    let send = async move {
+        let res = sender
+            .send_request(req)
+            .await
+            .map_err(FetchCoverError::Http)?;
+        let status = res.status();
+
+        if status.is_redirection() {
+            return res
+                .headers()
+                .get(LOCATION)
+                .and_then(|location| location.to_str().ok())
+                .map(|location| Fetched::Redirect(location.to_owned()))
+                .ok_or(FetchCoverError::Status(status.as_u16()));
+        }
+
+        if !status.is_success() {
+            return Err(FetchCoverError::Status(status.as_u16()));
+        }
+
+        Limited::new(res.into_body(), MAX_COVER_BYTES)
+            .collect()
+            .await
+            .map(|body| Fetched::Body(body.to_bytes().to_vec()))
+            .map_err(|err| match err.downcast::<::hyper::Error>() {
+                Ok(err) => FetchCoverError::Http(*err),
+                Err(_) => FetchCoverError::TooLarge(MAX_COVER_BYTES),
+            })
+    };
+
+    send.or(run).await
+}
+
+/// Fetch a single cover and convert it to a thumbnail of at most `dimensions`.
+///
+/// # Errors
+/// If the cover cannot be fetched or decoded, or if it takes longer than `timeout`.
+pub async fn fetch_cover(
+    url: &str,
+    dimensions: u32,
+    timeout: Duration,
+) -> Result<::spel_katalog_formats::Image, FetchCoverError> {
+    let fetch = async {
+        let bytes = fetch_bytes(url).await?;
+        ::smol::unblock(move || {
+            load_cover(&bytes)
+                .map(|image| thumbnail(image, dimensions))
+                .map_err(FetchCoverError::from)
+        })
+        .await
+    };
+    let timer = async {
+        Timer::after(timeout).await;
+        Err(FetchCoverError::Timeout(timeout))
+    };
+
+    fetch.or(timer).await
+}
+
+/// Fetch covers of all slugs in `urls`, at most `options.concurrency` at a time.
+/// Results are sorted by slug.
+pub async fn fetch_covers(
+    urls: BTreeMap<String, String>,
+    options: &RemoteCoverOptions,
+) -> Vec<(
+    String,
+    Result<::spel_katalog_formats::Image, FetchCoverError>,
+)> {
+    let RemoteCoverOptions {
+        concurrency,
+        timeout,
+        dimensions,
+    } = *options;
+
+    let mut results = stream::iter(urls)
+        .map(|(slug, url)| async move {
+            let result = fetch_cover(&url, dimensions, timeout).await;
+            match &result {
+                Ok(..) => ::log::info!("fetched cover for {slug} from {url:?}"),
+                Err(err) => ::log::warn!("could not fetch cover for {slug} from {url:?}\n{err}"),
+            }
+            (slug, result)
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect::<Vec<_>>()
+        .await;
+
+    results.sort_by(|(a, _), (b, _)| a.cmp(b));
+    results
+}
+
+/// Fetch covers of all slugs in `urls` and store them in the thumbnail database at `db_path`.
+///
+/// Covers that cannot be fetched are reported as failed and skipped.
+///
+/// # Errors
+/// If the thumbnail database cannot be opened or written to.
+pub async fn import_remote_covers(
+    urls: BTreeMap<String, String>,
+    db_path: PathBuf,
+    options: &RemoteCoverOptions,
+) -> Result<RemoteCoverReport, LoadDbError> {
+    let results = fetch_covers(urls, options)
+        .await
+        .into_iter()
+        .map(|(slug, result)| {
+            let png = result.and_then(|image| image.to_png().map_err(FetchCoverError::from));
+            (slug, png)
+        });
+    let (report, images) = RemoteCoverReport::split(results);
+
+    ::smol::unblock(move || store_thumbnails(&db_path, images)).await?;
+    ::log::info!("{}", report.summary());
+
+    Ok(report)
+}
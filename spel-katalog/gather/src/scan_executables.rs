@@ -0,0 +1,97 @@
+//! Import of games from a plain directory of executables.
+
+use ::std::{
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+};
+
+use ::rustc_hash::FxHashSet;
+use ::spel_katalog_formats::{NativeGame, NativeRunner, Timestamp, slugify};
+
+/// Extensions of files ran using wine.
+const WINE_EXTENSIONS: &[&str] = &["exe", "bat"];
+
+/// Get the runner to use for a file, [None] if it is not an executable.
+///
+/// Windows executables are recognized by extension, other files by having
+/// an executable permission bit set.
+pub fn executable_runner(path: &Path) -> Option<NativeRunner> {
+    let metadata = path
+        .metadata()
+        .map_err(|err| ::log::warn!("could not get metadata of {path:?}\n{err}"))
+        .ok()?;
+
+    if !metadata.is_file() {
+        return None;
+    }
+
+    let is_wine = path.extension().is_some_and(|ext| {
+        WINE_EXTENSIONS
+            .iter()
+            .any(|wine_ext| ext.eq_ignore_ascii_case(wine_ext))
+    });
+
+    if is_wine {
+        Some(NativeRunner::Wine)
+    } else if metadata.permissions().mode() & 0o111 != 0 {
+        Some(NativeRunner::Linux)
+    } else {
+        None
+    }
+}
+
+/// Scan a directory for executables, creating a game for each.
+///
+/// Only direct children of the directory are considered. Games are named by
+/// the file stem of their executable, sorted by name, and have their generated
+/// slug stored as the `slug` attribute.
+///
+/// # Errors
+/// If the directory cannot be read.
+pub fn scan_executables(dir: &Path, timestamp: Timestamp) -> ::std::io::Result<Vec<NativeGame>> {
+    let mut games = Vec::new();
+
+    for entry in dir.read_dir()? {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) => {
+                ::log::warn!("could not read entry of {dir:?}\n{err}");
+                continue;
+            }
+        };
+        let path = entry.path();
+
+        let Some(runner) = executable_runner(&path) else {
+            continue;
+        };
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            ::log::warn!("skipping executable with non utf-8 name {path:?}");
+            continue;
+        };
+
+        let mut game = NativeGame::new(name.to_owned(), timestamp.clone(), path.clone(), runner);
+        game.attrs.insert("slug".to_owned(), slugify(name));
+        games.push(game);
+    }
+
+    games.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(games)
+}
+
+/// Remove scanned games whose slug or executable is already known.
+pub fn remove_known(
+    games: Vec<NativeGame>,
+    slugs: &FxHashSet<String>,
+    exes: &FxHashSet<PathBuf>,
+) -> Vec<NativeGame> {
+    games
+        .into_iter()
+        .filter(|game| {
+            !exes.contains(&game.exe)
+                && !game
+                    .attrs
+                    .get("slug")
+                    .is_some_and(|slug| slugs.contains(slug))
+        })
+        .collect()
+}
@@ -16,13 +16,13 @@ use ::iced_highlighter::Highlighter;
 use ::iced_runtime::Task;
 use ::iced_widget::{self as widget, button};
 use ::image::ImageError;
-use ::open::that;
 use ::spel_katalog_common::{
-    OrRequest, StatusSender, async_status, in_place::PushMaybe as _, status, styling, w,
+    OrRequest, StatusSender, async_status, autosave, in_place::PushMaybe as _, status, styling, w,
 };
 use ::spel_katalog_formats::{AdditionalConfig, Game, GameId, NativeGame, lutris_config};
+use ::spel_katalog_gather::{THUMBNAILS_FILENAME, find_cached_duplicate};
 use ::spel_katalog_native::Pool;
-use ::spel_katalog_settings::{CoverartDir, Settings, YmlDir};
+use ::spel_katalog_settings::{AutosaveDelay, CoverartDir, Opener, Settings, YmlDir};
 use ::tap::Pipe;
 use ::uuid::Uuid;
 use ::yaml_rust2::Yaml;
@@ -49,12 +49,18 @@ pub enum State {
         config_path: Option<PathBuf>,
         /// Common parent of wine prefix and executable.
         common_parent: PathBuf,
+        /// Wine prefix of game, if it uses wine and has one set.
+        wine_prefix: Option<PathBuf>,
         /// Content of additional roots editor.
         additional_roots_content: widget::text_editor::Content,
         /// Additional config of game.
         additional: AdditionalConfig,
         /// Attribute editor.
         attrs: attrs::State,
+        /// Debounce for autosaving config editor.
+        autosave: autosave::Debounce,
+        /// Autosaved config content which may be restored.
+        restore: Option<String>,
     },
     /// A native game is displayed.
     Native {
@@ -79,6 +85,8 @@ pub enum GameContent {
         path: PathBuf,
         /// Additional config.
         additional: AdditionalConfig,
+        /// Autosaved content of config.
+        autosave: Option<String>,
     },
     /// Content is for a native game.
     Native {
@@ -107,8 +115,21 @@ pub enum Message {
     UpdateAttrs(attrs::Message),
     /// Save config content to file.
     SaveContent,
+    /// Autosave config content to sidecar file if no edits have been made since.
+    Autosave {
+        /// Generation of edit the autosave was scheduled for.
+        generation: u64,
+    },
+    /// Restore autosaved config content.
+    RestoreAutosave,
+    /// Discard autosaved config content.
+    DiscardAutosave,
     /// Save additional config to file.
     SaveAdditional,
+    /// Toggle favorite state of game and save additional config.
+    ToggleFavorite,
+    /// Toggle pinned state of game and save additional config.
+    TogglePin,
     /// Add a thumbail.
     AddThumb {
         /// Game id to add thumbnail for
@@ -128,6 +149,8 @@ pub enum Message {
     OpenExe,
     /// Open directory of game.
     OpenDir,
+    /// Open wine prefix directory of game.
+    OpenPrefix,
     /// Clear viewed content.
     Clear,
 }
@@ -164,10 +187,33 @@ pub enum Request {
         /// Id of game to run lutris in sandbox of.
         id: GameId,
     },
+    /// Set favorite state of game.
+    SetFavorite {
+        /// Id of game to set favorite state for.
+        id: GameId,
+        /// Is the game a favorite.
+        favorite: bool,
+    },
+    /// Set pinned state of game.
+    SetPinned {
+        /// Id of game to set pinned state for.
+        id: GameId,
+        /// Is the game pinned.
+        pinned: bool,
+    },
     /// Native info request.
     NativeInfo(native_info::Request),
 }
 
+/// Remove an autosave sidecar file, if it exists.
+async fn remove_autosave(path: PathBuf) {
+    match ::smol::fs::remove_file(&path).await {
+        Ok(_) => (),
+        Err(err) if err.kind() == ::std::io::ErrorKind::NotFound => (),
+        Err(err) => ::log::warn!("could not remove autosave {path:?}\n{err}"),
+    }
+}
+
 impl State {
     /// Get id of currently viewed game.
     pub const fn id(&self) -> Option<GameId> {
@@ -201,9 +247,12 @@ impl State {
                     content: Default::default(),
                     config_path: Default::default(),
                     common_parent: Default::default(),
+                    wine_prefix: Default::default(),
                     additional_roots_content: Default::default(),
                     additional: Default::default(),
                     attrs: Default::default(),
+                    autosave: Default::default(),
+                    restore: Default::default(),
                 };
 
                 let path = settings
@@ -217,34 +266,28 @@ impl State {
                     return Task::none();
                 };
 
-                let additional_path = config_dir.join("games").join(format!("{id}.toml"));
-
-                async fn read_additional(path: &Path) -> Option<AdditionalConfig> {
-                    // Usually a bad idea however since we deal with it not existing correctly
-                    // anyways this is just some redundancy that prevents log spam.
-                    if !path.exists() {
-                        return None;
-                    };
-                    ::smol::fs::read_to_string(path)
-                        .await
-                        .map_err(|err| ::log::error!("could not read {path:?} to string\n{err}"))
-                        .ok()?
-                        .pipe_deref(::toml::from_str)
-                        .map_err(|err| ::log::error!("could not deserialize {path:?}\n{err}"))
-                        .ok()
-                }
+                let additional_path = AdditionalConfig::path(&config_dir, id);
 
                 let tx = tx.clone();
                 Task::future(async move {
                     match ::smol::fs::read_to_string(&path).await {
                         Ok(content) => {
-                            let additional =
-                                read_additional(&additional_path).await.unwrap_or_default();
+                            let additional = AdditionalConfig::read(&additional_path)
+                                .await
+                                .map_err(|err| ::log::error!("{err}"))
+                                .ok()
+                                .flatten()
+                                .unwrap_or_default();
+                            let autosave =
+                                ::smol::fs::read_to_string(autosave::sidecar_path(&path))
+                                    .await
+                                    .ok();
                             Task::done(Message::SetContent(GameContent::Lutris {
                                 id,
                                 content,
                                 path,
                                 additional,
+                                autosave,
                             }))
                         }
                         Err(err) => {
@@ -301,6 +344,7 @@ impl State {
                         content,
                         path,
                         additional,
+                        autosave,
                     } => {
                         let Self::Lutris {
                             id: current_id,
@@ -310,6 +354,8 @@ impl State {
                             attrs,
                             additional: w_additional,
                             common_parent,
+                            wine_prefix,
+                            restore,
                             ..
                         } = self
                         else {
@@ -319,6 +365,7 @@ impl State {
                             return Task::none();
                         }
                         w::set_text_editor_content(w, content.clone());
+                        *restore = autosave::restorable(&content, autosave.as_deref());
                         *config_path = Some(path.clone());
                         *additional_roots_content = widget::text_editor::Content::with_text(
                             &additional.sandbox_root.join("\n"),
@@ -343,6 +390,12 @@ impl State {
                         *common_parent = yml
                             .game
                             .common_parent(|| ::spel_katalog_settings::HOME.as_path());
+                        *wine_prefix = match game_by_id(id) {
+                            Some(Game::Lutris(game)) => {
+                                yml.wine_prefix(&game.runner).map(Path::to_path_buf)
+                            }
+                            _ => None,
+                        };
                     }
                     GameContent::Native { uuid, config } => {
                         let (state, task) = native_info::State::new(uuid, *config, games_db);
@@ -354,8 +407,73 @@ impl State {
                 Task::none()
             }
             Message::UpdateContent(action) => {
-                if let Self::Lutris { content, .. } = self {
+                if let Self::Lutris {
+                    content,
+                    autosave,
+                    config_path,
+                    ..
+                } = self
+                {
+                    let is_edit = action.is_edit();
                     content.perform(action);
+
+                    if is_edit
+                        && config_path.is_some()
+                        && let Some(delay) = settings.get::<AutosaveDelay>().duration()
+                    {
+                        let generation = autosave.edit();
+                        return Task::future(async move {
+                            ::smol::Timer::after(delay).await;
+                            OrRequest::Message(Message::Autosave { generation })
+                        });
+                    }
+                }
+                Task::none()
+            }
+            Message::Autosave { generation } => {
+                if let Self::Lutris {
+                    content,
+                    config_path: Some(path),
+                    autosave,
+                    ..
+                } = self
+                    && autosave.should_save(generation)
+                {
+                    let path = autosave::sidecar_path(path);
+                    let text = content.text();
+                    Task::future(async move {
+                        match ::smol::fs::write(&path, text).await {
+                            Ok(_) => ::log::info!("autosaved game config to {path:?}"),
+                            Err(err) => ::log::error!("could not autosave to {path:?}\n{err}"),
+                        }
+                    })
+                    .then(|_| Task::none())
+                } else {
+                    Task::none()
+                }
+            }
+            Message::RestoreAutosave => {
+                if let Self::Lutris {
+                    content, restore, ..
+                } = self
+                    && let Some(text) = restore.take()
+                {
+                    w::set_text_editor_content(content, text);
+                }
+                Task::none()
+            }
+            Message::DiscardAutosave => {
+                if let Self::Lutris {
+                    config_path,
+                    restore,
+                    ..
+                } = self
+                {
+                    *restore = None;
+                    if let Some(path) = config_path {
+                        let path = autosave::sidecar_path(path);
+                        return Task::future(remove_autosave(path)).then(|_| Task::none());
+                    }
                 }
                 Task::none()
             }
@@ -363,16 +481,21 @@ impl State {
                 if let Self::Lutris {
                     content,
                     config_path,
+                    autosave,
+                    restore,
                     ..
                 } = self
                     && let Some(path) = config_path
                 {
+                    autosave.cancel();
+                    *restore = None;
                     let path = path.to_path_buf();
                     let tx = tx.clone();
                     let text = content.text();
                     Task::future(async move {
                         match ::smol::fs::write(&path, text).await {
                             Ok(_) => {
+                                remove_autosave(autosave::sidecar_path(&path)).await;
                                 async_status!(tx, "wrote game config {path:?}").await;
                                 Task::none()
                             }
@@ -431,6 +554,11 @@ impl State {
                         return Task::none();
                     };
                     let dest = settings.get::<CoverartDir>().as_path().join(&slug);
+                    let thumbnail_db = settings
+                        .xdg()
+                        .get_cache_home()
+                        .map(|cache_home| cache_home.join(THUMBNAILS_FILENAME));
+                    let status_tx = tx.clone();
 
                     let task = async move {
                         let dialog = ::rfd::AsyncFileDialog::new()
@@ -459,7 +587,7 @@ impl State {
                             Err(source) => return Err(AddThumbError::Read { source, path: dest }),
                         };
 
-                        let image = match ::image::load_from_memory(&content) {
+                        let image = match ::spel_katalog_gather::load_cover(&content) {
                             Ok(handle) => handle,
                             Err(source) => {
                                 return Err(AddThumbError::Process { source, path: dest });
@@ -471,6 +599,25 @@ impl State {
                             ::spel_katalog_gather::CoverGathererOptions::default().dimensions,
                         );
 
+                        if let Some(thumbnail_db) = thumbnail_db {
+                            let duplicate = ::smol::unblock({
+                                let (image, slug) = (image.clone(), slug.clone());
+                                move || find_cached_duplicate(&thumbnail_db, &image, &slug)
+                            })
+                            .await;
+
+                            if let Some(duplicate) = duplicate {
+                                ::log::warn!(
+                                    "thumbnail added for {slug} is identical to the one of {duplicate}"
+                                );
+                                async_status!(
+                                    status_tx,
+                                    "thumbnail is identical to the one of {duplicate}"
+                                )
+                                .await;
+                            }
+                        }
+
                         Ok(OrRequest::Request(Request::SetImage { slug, image }))
                     };
 
@@ -555,18 +702,6 @@ impl State {
                 }
             }
             Message::SaveAdditional => {
-                async fn write_additional(path: &Path, additional: AdditionalConfig) -> Option<()> {
-                    let content = ::toml::to_string(&additional)
-                        .map_err(|err| {
-                            ::log::error!("could not serialize additional to {path:?}\n{err}")
-                        })
-                        .ok()?;
-                    ::smol::fs::write(path, content.as_bytes())
-                        .await
-                        .map_err(|err| ::log::error!("could not write to {path:?}\n{err}"))
-                        .ok()
-                }
-
                 if let Self::Lutris { id, additional, .. } = self {
                     let id = *id;
                     let additional = additional.clone();
@@ -574,21 +709,14 @@ impl State {
                         ::log::error!("could not get config home");
                         return Task::none();
                     };
-                    let extra_config_dir = config_dir.join("games");
+                    let additional_path = AdditionalConfig::path(&config_dir, id);
 
                     let tx = tx.clone();
                     Task::future(async move {
-                        if let Err(err) = ::smol::fs::create_dir_all(&extra_config_dir).await {
-                            async_status!(tx, "could not create {extra_config_dir:?}").await;
-                            ::log::error!(
-                                "could not create extra config dir {extra_config_dir:?}\n{err}"
-                            );
-                            return;
-                        };
-                        let additional_path = extra_config_dir.join(format!("{id}.toml"));
-                        match write_additional(&additional_path, additional).await {
-                            Some(_) => async_status!(tx, "saved additional for game {id}").await,
-                            None => {
+                        match additional.write(&additional_path).await {
+                            Ok(()) => async_status!(tx, "saved additional for game {id}").await,
+                            Err(err) => {
+                                ::log::error!("{err}");
                                 async_status!(tx, "could not save addotional for game {id}").await
                             }
                         }
@@ -598,6 +726,36 @@ impl State {
                     Task::none()
                 }
             }
+            Message::ToggleFavorite => {
+                if let Self::Lutris { id, additional, .. } = self {
+                    additional.favorite = !additional.favorite;
+                    let request = Request::SetFavorite {
+                        id: *id,
+                        favorite: additional.favorite,
+                    };
+                    Task::batch([
+                        Task::done(OrRequest::Request(request)),
+                        Task::done(OrRequest::Message(Message::SaveAdditional)),
+                    ])
+                } else {
+                    Task::none()
+                }
+            }
+            Message::TogglePin => {
+                if let Self::Lutris { id, additional, .. } = self {
+                    additional.pinned = !additional.pinned;
+                    let request = Request::SetPinned {
+                        id: *id,
+                        pinned: additional.pinned,
+                    };
+                    Task::batch([
+                        Task::done(OrRequest::Request(request)),
+                        Task::done(OrRequest::Message(Message::SaveAdditional)),
+                    ])
+                } else {
+                    Task::none()
+                }
+            }
             Message::SetExe { path } => {
                 self.set_exe(path, tx);
                 Task::none()
@@ -622,6 +780,7 @@ impl State {
                         .with_extension("yml");
 
                     let tx = tx.clone();
+                    let opener = settings.get::<Opener>().clone();
 
                     Task::future(async move {
                         let content = match ::smol::fs::read_to_string(&config_path).await {
@@ -661,7 +820,7 @@ impl State {
                             }
                         };
 
-                        if let Err(err) = that(&parent) {
+                        if let Err(err) = opener.open(&parent) {
                             async_status!(&tx, "failed to open {parent:?}").await;
                             ::log::error!("failed to open {parent:?}\n{err}");
                         }
@@ -673,6 +832,30 @@ impl State {
                     Task::none()
                 }
             }
+            Message::OpenPrefix => {
+                let Self::Lutris {
+                    wine_prefix: Some(prefix),
+                    ..
+                } = self
+                else {
+                    ::log::warn!("no wine prefix available to open");
+                    return Task::none();
+                };
+
+                let prefix = prefix.clone();
+                let tx = tx.clone();
+                let opener = settings.get::<Opener>().clone();
+                Task::future(async move {
+                    if let Err(err) = opener.open(&prefix) {
+                        async_status!(&tx, "failed to open {prefix:?}").await;
+                        ::log::error!("failed to open {prefix:?}\n{err}");
+                        return;
+                    }
+
+                    async_status!(&tx, "opened {prefix:?}").await;
+                })
+                .then(|_| Task::none())
+            }
             Message::NativeInfo(message) => {
                 if let Self::Native { state } = self {
                     state.update(message, games_db, settings).map(|msg| {
@@ -878,9 +1061,12 @@ impl State {
                 content,
                 config_path,
                 common_parent,
+                wine_prefix,
                 additional_roots_content,
                 additional,
                 attrs,
+                autosave: _,
+                restore,
             } => {
                 let id = *id;
                 widget::Column::new()
@@ -908,6 +1094,17 @@ impl State {
                                         .then(|| OrRequest::Message(Message::RemoveThumb { id })),
                                 ),
                             button("Open").on_press(OrRequest::Message(Message::OpenDir)),
+                            button("Prefix").on_press_maybe(
+                                wine_prefix
+                                    .is_some()
+                                    .then_some(OrRequest::Message(Message::OpenPrefix)),
+                            ),
+                            button(if additional.favorite { "-Fav" } else { "+Fav" })
+                                .style(widget::button::secondary)
+                                .on_press(OrRequest::Message(Message::ToggleFavorite)),
+                            button(if additional.pinned { "-Pin" } else { "+Pin" })
+                                .style(widget::button::secondary)
+                                .on_press(OrRequest::Message(Message::TogglePin)),
                         ]
                         .into_iter()
                         .fold(w::row(), |row, btn| row.push(btn.padding(3))),
@@ -987,12 +1184,32 @@ impl State {
                                         ),
                                     ),
                             )
-                            .push(widget::themer(
-                                Some(::iced_core::Theme::SolarizedDark),
+                            .push_maybe(restore.is_some().then(|| {
+                                w::row()
+                                    .push(
+                                        widget::container("Autosaved changes were found")
+                                            .padding(3),
+                                    )
+                                    .push(widget::space::horizontal())
+                                    .push(
+                                        button("Restore")
+                                            .padding(3)
+                                            .on_press(OrRequest::Message(Message::RestoreAutosave)),
+                                    )
+                                    .push(
+                                        button("Discard")
+                                            .padding(3)
+                                            .style(widget::button::danger)
+                                            .on_press(OrRequest::Message(Message::DiscardAutosave)),
+                                    )
+                            }))
+                            .push(
                                 widget::text_editor(content)
                                     .highlight_with::<Highlighter>(
                                         ::iced_highlighter::Settings {
-                                            theme: ::iced_highlighter::Theme::SolarizedDark,
+                                            theme: ::spel_katalog_settings_view::highlighter_theme(
+                                                *settings.get::<::spel_katalog_settings::Theme>(),
+                                            ),
                                             token: "yml".to_owned(),
                                         },
                                         |h, _| h.to_format(),
@@ -1001,7 +1218,7 @@ impl State {
                                         action.pipe(Message::from).pipe(OrRequest::Message)
                                     })
                                     .padding(6),
-                            ))
+                            )
                             .push(widget::space::horizontal().width(0)),
                     ))
                     .into()
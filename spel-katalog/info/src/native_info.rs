@@ -23,7 +23,7 @@ use ::smol::unblock;
 use ::spel_katalog_common::{IntoOrRequest, OrRequest, in_place::PushMaybe as _, w};
 use ::spel_katalog_formats::{GameId, NativeGame};
 use ::spel_katalog_native::Pool;
-use ::spel_katalog_settings::{CompToolsDir, ThmubnailSource};
+use ::spel_katalog_settings::{CompToolsDir, Opener, ThmubnailSource};
 use ::spel_katalog_widget::monospace;
 use ::tap::{Pipe, TapOptional};
 use ::uuid::Uuid;
@@ -325,17 +325,20 @@ impl State {
                         .pipe(OrRequest::Request)
                         .pipe(Some)
                 }),
-                QuickMessage::Open => self.with_content(|game| {
-                    let parent = game.exe.parent().tap_none(|| {
-                        ::log::error!("game executable {exe:?} has not parent", exe = game.exe)
-                    })?;
+                QuickMessage::Open => {
+                    let opener = settings.get::<Opener>().clone();
+                    self.with_content(move |game| {
+                        let parent = game.exe.parent().tap_none(|| {
+                            ::log::error!("game executable {exe:?} has not parent", exe = game.exe)
+                        })?;
 
-                    if let Err(err) = ::open::that_detached(parent) {
-                        ::log::error!("failed to open {parent:?}\n{err}");
-                    }
+                        if let Err(err) = opener.open(parent) {
+                            ::log::error!("failed to open {parent:?}\n{err}");
+                        }
 
-                    None
-                }),
+                        None
+                    })
+                }
                 QuickMessage::Save => {
                     let game_db = game_db.clone();
                     let uuid = self.uuid;
@@ -460,7 +463,7 @@ impl State {
                             })
                             .ok()?;
 
-                        let image = ::image::load_from_memory(&content)
+                        let image = ::spel_katalog_gather::load_cover(&content)
                             .map_err(|err| {
                                 ::log::error!("could not load {path:?}\n{err}", path = file.path())
                             })
@@ -747,19 +750,9 @@ impl State {
                     })
                     .highlight_with::<::iced_highlighter::Highlighter>(
                         ::iced_highlighter::Settings {
-                            theme: match settings.get::<::spel_katalog_settings::Theme>() {
-                                ::spel_katalog_settings::Theme::SolarizedDark => {
-                                    ::iced_highlighter::Theme::SolarizedDark
-                                }
-                                theme
-                                    if ::spel_katalog_settings_view::conv_theme(*theme)
-                                        .extended_palette()
-                                        .is_dark =>
-                                {
-                                    ::iced_highlighter::Theme::Base16Mocha
-                                }
-                                _ => ::iced_highlighter::Theme::InspiredGitHub,
-                            },
+                            theme: ::spel_katalog_settings_view::highlighter_theme(
+                                *settings.get::<::spel_katalog_settings::Theme>(),
+                            ),
                             token: "toml".to_owned(),
                         },
                         |h, _| h.to_format(),
@@ -177,19 +177,9 @@ impl Editor {
                 })
                 .highlight_with::<::iced_highlighter::Highlighter>(
                     ::iced_highlighter::Settings {
-                        theme: match settings.get::<::spel_katalog_settings::Theme>() {
-                            ::spel_katalog_settings::Theme::SolarizedDark => {
-                                ::iced_highlighter::Theme::SolarizedDark
-                            }
-                            theme
-                                if ::spel_katalog_settings_view::conv_theme(*theme)
-                                    .extended_palette()
-                                    .is_dark =>
-                            {
-                                ::iced_highlighter::Theme::Base16Mocha
-                            }
-                            _ => ::iced_highlighter::Theme::InspiredGitHub,
-                        },
+                        theme: ::spel_katalog_settings_view::highlighter_theme(
+                            *settings.get::<::spel_katalog_settings::Theme>(),
+                        ),
                         token: "toml".to_owned(),
                     },
                     |h, _| h.to_format(),
@@ -2,16 +2,18 @@
 
 use ::core::{convert::Infallible, fmt::Debug};
 use ::std::{
+    ffi::OsStr,
     io::PipeReader,
-    os::fd::OwnedFd,
+    os::{fd::OwnedFd, unix::process::CommandExt},
     path::{Path, PathBuf},
+    process::Stdio,
 };
 
 use ::smol::io::{AsyncReadExt, AsyncWriteExt};
 use ::spel_katalog_formats::{NativeGame, RunMode};
 use ::spel_katalog_settings::{
-    BubblewrapExe, DllOverrides, GamescopeExe, Network, SandboxExtras, Settings, ShellExe,
-    TermCommand, UmuRunExe, UseGamescope,
+    BubblewrapExe, DllOverrides, GamescopeExe, LaunchMode, Network, SandboxExtras, Settings,
+    ShellExe, TermCommand, UmuRunExe, UseGamescope,
 };
 use ::spel_katalog_sink::{AsyncSinkWriter, SinkBuilder, SinkIdentity};
 use ::tap::Conv;
@@ -51,6 +53,34 @@ pub fn dll_overrides(settings: &Settings) -> Vec<String> {
         .collect()
 }
 
+/// Create a command running `program` with the session and output of `launch_mode`.
+///
+/// Detached commands are placed in a new session using `setsid`, such that they are
+/// neither part of the process group nor the terminal of the app.
+pub fn game_command(
+    program: impl AsRef<OsStr>,
+    launch_mode: LaunchMode,
+    stdout: Stdio,
+    stderr: Stdio,
+) -> ::smol::process::Command {
+    let mut cmd = ::std::process::Command::new(program);
+    if launch_mode.new_session() {
+        // SAFETY: setsid is async-signal-safe and does not allocate.
+        unsafe {
+            cmd.pre_exec(|| {
+                ::rustix::process::setsid()?;
+                Ok(())
+            });
+        }
+    }
+
+    let mut cmd = ::smol::process::Command::from(cmd);
+    cmd.kill_on_drop(launch_mode.kill_on_drop())
+        .stdout(stdout)
+        .stderr(stderr);
+    cmd
+}
+
 /// Get stdout and stderr file handles.
 async fn sink_proxy(log_dir: &Path, name: &str, sink_builder: SinkBuilder) -> Option<SinkBuilder> {
     let when = ::spel_katalog_formats::Timestamp::now();
@@ -148,6 +178,7 @@ pub fn run_native_game(
     let gamescope = settings.get::<GamescopeExe>().clone();
     let sandbox_ro_dirs = sandbox_ro_dirs(settings);
     let dll_overrides = dll_overrides(settings);
+    let launch_mode = *settings.get::<LaunchMode>();
     let log_dir = log_dir(settings.xdg())?;
 
     Some(async move {
@@ -167,8 +198,10 @@ pub fn run_native_game(
                 sink_builder,
                 gamescope: gamescope.as_path(),
                 callback: Callback::default(),
+                launch_mode,
             },
             config: game,
+            launch_override: None,
         };
 
         ctx.run(run_mode)
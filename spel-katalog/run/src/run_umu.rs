@@ -5,6 +5,7 @@ use ::std::{
     ffi::OsString,
     io::ErrorKind,
     path::{Path, PathBuf},
+    process::Stdio,
 };
 
 use ::color_eyre::{Section, eyre::eyre};
@@ -14,10 +15,11 @@ use ::spel_katalog_formats::{
     AdditionalConfig, Bind, GameId, LutrisRunner, NativeGame, NativeRunner, RunMode, Timestamp,
     lutris_config,
 };
+use ::spel_katalog_settings::LaunchMode;
 use ::spel_katalog_sink::SinkBuilder;
 use ::tap::Pipe;
 
-use crate::{Callback, macros::args};
+use crate::{Callback, game_command, macros::args};
 
 /// Context needed to run game with bubblewrap and umu.
 #[derive(Debug)]
@@ -45,6 +47,8 @@ pub struct CommonUmuCtx<'a> {
     pub callback: Callback,
     /// Sink builder to use for command outputs.
     pub sink_builder: SinkBuilder,
+    /// How the game is launched relative to the app.
+    pub launch_mode: LaunchMode,
 }
 
 /// Context needed to run native games.
@@ -54,6 +58,8 @@ pub struct NativeUmuCtx<'a> {
     pub common: CommonUmuCtx<'a>,
     /// Game config.
     pub config: NativeGame,
+    /// Command line to launch game with instead of executable.
+    pub launch_override: Option<Vec<OsString>>,
 }
 
 /// If possible bind user in wine prefix to steamuser in umu prefix.
@@ -174,8 +180,10 @@ impl NativeUmuCtx<'_> {
                     use_gamescope: global_use_gamescope,
                     gamescope,
                     sink_builder,
+                    launch_mode,
                 },
             config,
+            launch_override,
         } = self;
         ::log::info!("using game config\n{config:#?}");
         let NativeGame {
@@ -338,19 +346,26 @@ impl NativeUmuCtx<'_> {
                 args.extend(gamescope_args.iter().map(OsString::from));
                 args.extend(args!["--"]);
             }
-            if runner.is_wine() {
-                args.extend(args![umu]);
+            if let Some(launch_override) = launch_override {
+                args.extend(launch_override);
+            } else {
+                if runner.is_wine() {
+                    args.extend(args![umu]);
+                }
+                args.extend(args![exe]);
             }
-            args.extend(args![exe]);
         }
 
         let process_path = term_path.unwrap_or_else(|| bwrap.to_path_buf());
         ::log::info!("running {process_path:?} with args\n{args:#?}");
-        let [stdout, stderr] = sink_builder.build(|| name.clone())?;
-        let cmd = Command::new(process_path)
+        let (stdout, stderr) = if launch_mode.routes_output() {
+            let [stdout, stderr] = sink_builder.build(|| name.clone())?;
+            (stdout, stderr)
+        } else {
+            (Stdio::null(), Stdio::null())
+        };
+        let cmd = game_command(process_path, launch_mode, stdout, stderr)
             .args(args)
-            .stdout(stdout)
-            .stderr(stderr)
             .status();
 
         send_open.call();
@@ -502,9 +517,18 @@ impl<'a> LutrisUmuCtx<'a> {
     /// If the lutris context cannot produce a native context.
     pub fn into_native(self) -> ::color_eyre::Result<NativeUmuCtx<'a>> {
         let Self { common, lutris } = self;
+        let extra_config = lutris.extra_config;
+        let config = lutris.into_native()?;
+        let launch_override = extra_config
+            .map(|extra| extra.launch_command(&config.exe, config.prefix.as_deref()))
+            .transpose()
+            .map_err(|err| eyre!(err).note("could not split launch override"))?
+            .flatten();
+
         Ok(NativeUmuCtx {
             common,
-            config: lutris.into_native()?,
+            config,
+            launch_override,
         })
     }
 }
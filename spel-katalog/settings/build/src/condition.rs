@@ -0,0 +1,81 @@
+//! Conditions on values of enum settings.
+
+use ::core::{fmt::Display, str::FromStr};
+
+use ::serde::Deserialize;
+
+/// Comparison used by a [Condition].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Comparison {
+    /// Setting should have variant.
+    Eq,
+    /// Setting should not have variant.
+    Ne,
+}
+
+/// A condition on the value of an enum setting, such as `Sandbox != None`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Condition {
+    /// Name of setting compared.
+    pub setting: String,
+    /// How setting is compared.
+    pub comparison: Comparison,
+    /// Variant setting is compared against.
+    pub variant: String,
+}
+
+/// Error returned when a [Condition] cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseConditionError(String);
+
+impl Display for ParseConditionError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        write!(
+            f,
+            "could not parse condition {:?}, expected \"Setting == Variant\" or \"Setting != Variant\"",
+            self.0
+        )
+    }
+}
+
+impl ::core::error::Error for ParseConditionError {}
+
+impl FromStr for Condition {
+    type Err = ParseConditionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseConditionError(s.to_owned());
+
+        let (setting, comparison, variant) = if let Some((setting, variant)) = s.split_once("==") {
+            (setting, Comparison::Eq, variant)
+        } else if let Some((setting, variant)) = s.split_once("!=") {
+            (setting, Comparison::Ne, variant)
+        } else {
+            return Err(err());
+        };
+
+        let is_ident = |value: &str| {
+            !value.is_empty() && value.chars().all(|c| c.is_alphanumeric() || c == '_')
+        };
+
+        let (setting, variant) = (setting.trim(), variant.trim());
+        if !is_ident(setting) || !is_ident(variant) {
+            return Err(err());
+        }
+
+        Ok(Self {
+            setting: setting.to_owned(),
+            comparison,
+            variant: variant.to_owned(),
+        })
+    }
+}
+
+impl TryFrom<String> for Condition {
+    type Error = ParseConditionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
@@ -30,6 +30,8 @@ pub mod settings {
 
     use ::serde::Deserialize;
 
+    use crate::condition::Condition;
+
     /// The differing content of a Single setting.
     #[derive(Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
     #[serde(untagged)]
@@ -51,6 +53,21 @@ pub mod settings {
             /// Setting default string.
             string: String,
         },
+        /// Setting is an enum overriding another enum setting, with the variants of it and a
+        /// `Global` variant deferring to it.
+        Override {
+            /// Name of overridden setting.
+            overrides: String,
+        },
+        /// Setting is an integer.
+        Int {
+            /// Smallest allowed value.
+            min: i64,
+            /// Largest allowed value.
+            max: i64,
+            /// Setting default value.
+            default: i64,
+        },
     }
 
     /// A Single setting.
@@ -60,6 +77,9 @@ pub mod settings {
         pub title: Option<String>,
         /// Help message of setting.
         pub help: String,
+        /// Setting is only visible when condition holds.
+        #[serde(default)]
+        pub visible_when: Option<Condition>,
         /// Content of setting.
         #[serde(flatten)]
         pub content: SettingContent,
@@ -1,5 +1,7 @@
 //! Library used fo build.rs
 
+pub mod condition;
+
 pub mod format;
 
 pub mod expr;
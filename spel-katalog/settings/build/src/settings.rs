@@ -12,6 +12,7 @@ use ::quote::{format_ident, quote};
 use ::syn::{Ident, parse_quote};
 
 use crate::{
+    condition::{Comparison, Condition},
     expr::{str_expr, title_expr},
     format::{
         Settings,
@@ -21,6 +22,9 @@ use crate::{
     string::doc_str,
 };
 
+/// Variant of override settings deferring to the overridden setting.
+const GLOBAL_VARIANT: &str = "Global";
+
 /// Content to emit to file.
 struct Emit {
     /// Type to emit.
@@ -374,8 +378,176 @@ fn emit_string(setting: &Setting, name: &str, ident: &Ident, path: &str) -> Emit
     }
 }
 
+/// Create an [Emit] for an integer setting.
+///
+/// # Panics
+/// If the range is empty or does not contain the default value.
+fn emit_int(
+    setting: &Setting,
+    name: &str,
+    ident: &Ident,
+    min: i64,
+    max: i64,
+    default: i64,
+) -> Emit {
+    if !(min..=max).contains(&default) {
+        panic!("default of {name}, {default}, is not in range {min}..={max}");
+    }
+
+    let title_body = title_expr(name, setting.title.as_deref());
+    let doc = doc_str(&setting.help);
+    let default_value = str_expr(&default.to_string());
+    let help_expr = &str_expr(doc.trim_end_matches('.'));
+
+    let parse_err_ident = format_ident!("Parse{ident}Error");
+    let parse_err_doc = format!(
+        "Error used by [FromStr][::core::str::FromStr] implementation for [{name}]",
+        name = name.to_case(Case::Pascal)
+    );
+
+    let ty = parse_quote! {
+        #[derive(
+            Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash,
+            ::serde::Serialize, ::serde::Deserialize,
+            ::spel_katalog_settings_traits::Title,
+            ::spel_katalog_settings_traits::Help,
+            ::spel_katalog_settings_traits::DefaultStr
+        )]
+        #[doc = #doc]
+        #[serde(from = "i64", into = "i64")]
+        #[settings(help = #help_expr, title = #title_body, default_str = #default_value)]
+        pub struct #ident(i64);
+    };
+
+    let impls = item::file(
+        &mut [
+            parse_quote! {
+                impl #ident {
+                    #[doc = "Smallest allowed value."]
+                    pub const MIN: i64 = #min;
+
+                    #[doc = "Largest allowed value."]
+                    pub const MAX: i64 = #max;
+
+                    #[doc = "Construct a new value, clamped to the allowed range."]
+                    #[inline]
+                    pub const fn new(value: i64) -> Self {
+                        if value < Self::MIN {
+                            Self(Self::MIN)
+                        } else if value > Self::MAX {
+                            Self(Self::MAX)
+                        } else {
+                            Self(value)
+                        }
+                    }
+
+                    #[doc = "Get value of setting."]
+                    #[inline]
+                    pub const fn get(&self) -> i64 {
+                        self.0
+                    }
+
+                    #[doc = "Set value of setting, clamped to the allowed range."]
+                    #[inline]
+                    pub const fn set(&mut self, value: i64) {
+                        *self = Self::new(value);
+                    }
+                }
+            },
+            item::default(ident, &quote! { Self::new(#default) }),
+            item::display(ident, &quote! { ::core::fmt::Display::fmt(&self.0, f) }),
+            item::as_ref(ident, &quote! { i64 }, &quote! { &self.0 }),
+            item::from(ident, &quote! { i64 }, &quote! { Self::new(value) }),
+            item::from(&quote! { i64 }, ident, &quote! { value.get() }),
+        ]
+        .into_iter(),
+    );
+
+    let from_str = parse_quote! {
+        impl ::core::str::FromStr for #ident {
+            type Err = #parse_err_ident;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.trim().parse::<i64>().map(Self::new).map_err(#parse_err_ident)
+            }
+        }
+
+        #[derive(Debug)]
+        #[doc = #parse_err_doc]
+        pub struct #parse_err_ident(::core::num::ParseIntError);
+
+        impl ::core::fmt::Display for #parse_err_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::error::Error for #parse_err_ident {
+            fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+    };
+
+    Emit {
+        ty,
+        impls,
+        from_str,
+    }
+}
+
+/// Create an [Emit] for an enum setting overriding another enum setting.
+///
+/// # Panics
+/// If the overridden setting is not an enum setting.
+fn emit_override(
+    settings: &Settings,
+    setting: &Setting,
+    name: &str,
+    ident: &Ident,
+    overrides: &str,
+) -> Emit {
+    let Some(SettingContent::Enum { variants, .. }) = settings
+        .settings
+        .get(overrides)
+        .map(|setting| &setting.content)
+    else {
+        panic!("{name} overrides {overrides}, which is not an enum setting");
+    };
+
+    let all_variants = ::core::iter::once(GLOBAL_VARIANT.to_owned())
+        .chain(variants.iter().cloned())
+        .collect::<Vec<_>>();
+    let mut emit = emit_enum(setting, name, ident, &all_variants, GLOBAL_VARIANT);
+
+    let global_ident = format_ident!("{GLOBAL_VARIANT}");
+    let base_ident = format_ident!("{}", overrides.to_case(Case::Pascal));
+    let method_ident = format_ident!("{}", overrides.to_case(Case::Snake));
+    let variant_idents = variants
+        .iter()
+        .map(|variant| format_ident!("{}", variant.to_case(Case::Pascal)))
+        .collect::<Vec<_>>();
+    let method_doc = format!(
+        "Get overriding [{base_ident}], [None] if the [{base_ident}] setting should be used."
+    );
+
+    emit.impls.items.push(parse_quote! {
+        impl #ident {
+            #[doc = #method_doc]
+            pub const fn #method_ident(&self) -> Option<#base_ident> {
+                match self {
+                    Self::#global_ident => None,
+                    #( Self::#variant_idents => Some(#base_ident::#variant_idents), )*
+                }
+            }
+        }
+    });
+
+    emit
+}
+
 /// Create an [Emit] based on the provided setting.
-fn emit_type(setting: &Setting, name: &str) -> Emit {
+fn emit_type(settings: &Settings, setting: &Setting, name: &str) -> Emit {
     let ident = format_ident!("{}", name.to_case(Case::Pascal));
     match &setting.content {
         SettingContent::Enum { variants, default } => {
@@ -383,20 +555,71 @@ fn emit_type(setting: &Setting, name: &str) -> Emit {
         }
         SettingContent::Path { path } => emit_path(setting, name, &ident, path),
         SettingContent::String { string } => emit_string(setting, name, &ident, string),
+        SettingContent::Override { overrides } => {
+            emit_override(settings, setting, name, &ident, overrides)
+        }
+        SettingContent::Int { min, max, default } => {
+            emit_int(setting, name, &ident, *min, *max, *default)
+        }
     }
 }
 
+/// Create an argument name and expression checking if a setting should be visible.
+///
+/// # Panics
+/// If the condition references a setting that is not an enum, or a variant it does not have.
+fn visible_expr(
+    settings: &Settings,
+    name: &str,
+    condition: Option<&Condition>,
+) -> (Ident, ::syn::Expr) {
+    let Some(Condition {
+        setting,
+        comparison,
+        variant,
+    }) = condition
+    else {
+        return (format_ident!("_settings"), parse_quote!(true));
+    };
+
+    let Some(SettingContent::Enum { variants, .. }) = settings
+        .settings
+        .get(setting)
+        .map(|setting| &setting.content)
+    else {
+        panic!("visibility of {name} depends on {setting}, which is not an enum setting");
+    };
+
+    if !variants.contains(variant) {
+        panic!("visibility of {name} depends on {setting} being {variant}, which is not a variant");
+    }
+
+    let ty = format_ident!("{}", setting.to_case(Case::Pascal));
+    let field = format_ident!("{}", setting.to_case(Case::Snake));
+    let variant = format_ident!("{}", variant.to_case(Case::Pascal));
+    let expr = match comparison {
+        Comparison::Eq => parse_quote!(settings.#field() == &#ty::#variant),
+        Comparison::Ne => parse_quote!(settings.#field() != &#ty::#variant),
+    };
+
+    (format_ident!("settings"), expr)
+}
+
 /// Write settings rust code to destination path.
 ///
 /// # Panics
 /// If content cannot be written.
 pub fn write(settings: Settings, dest: &Path) {
     let mut file = BufWriter::new(File::create(dest).unwrap());
-    let Settings { settings } = settings;
-
+    let (visible_args, visible_exprs) = settings
+        .settings
+        .iter()
+        .map(|(name, setting)| visible_expr(&settings, name, setting.visible_when.as_ref()))
+        .collect::<(Vec<_>, Vec<_>)>();
     let emitted = settings
+        .settings
         .iter()
-        .map(|(name, setting)| (name, setting, emit_type(setting, name)))
+        .map(|(name, setting)| (name, setting, emit_type(&settings, setting, name)))
         .collect::<Vec<_>>();
 
     let from_str = emitted.iter().map(|(.., e)| &e.from_str);
@@ -412,10 +635,13 @@ pub fn write(settings: Settings, dest: &Path) {
     let mut enum_field_names = Vec::new();
     let mut path_field_names = Vec::new();
     let mut path_field_names_mut = Vec::new();
+    let mut int_field_names = Vec::new();
     let mut enum_ty_names = Vec::new();
     let mut path_ty_names = Vec::new();
+    let mut int_ty_names = Vec::new();
     let mut enum_ty_doc = Vec::new();
     let mut path_ty_doc = Vec::new();
+    let mut int_ty_doc = Vec::new();
     let mut generic_names = Vec::new();
     let settings_ident = Ident::new("SettingsStore", Span::call_site());
 
@@ -433,7 +659,7 @@ pub fn write(settings: Settings, dest: &Path) {
         generic_names.push(String::clone(name));
 
         match setting.content {
-            SettingContent::Enum { .. } => {
+            SettingContent::Enum { .. } | SettingContent::Override { .. } => {
                 enum_field_names.push(format_ident!("{snake_ident}"));
                 enum_ty_names.push(format_ident!("{pascal_ident}"));
                 enum_ty_doc.push(format!(
@@ -456,6 +682,13 @@ pub fn write(settings: Settings, dest: &Path) {
                     "Get the [{pascal_ident}][super::{pascal_ident}] setting."
                 ));
             }
+            SettingContent::Int { .. } => {
+                int_field_names.push(format_ident!("{snake_ident}"));
+                int_ty_names.push(format_ident!("{pascal_ident}"));
+                int_ty_doc.push(format!(
+                    "Get the [{pascal_ident}][super::{pascal_ident}] setting."
+                ));
+            }
         }
     }
 
@@ -524,6 +757,25 @@ pub fn write(settings: Settings, dest: &Path) {
                     }
                 }
 
+                /// Index integer settings.
+                #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, ::spel_katalog_settings_traits::TrustedVariants)]
+                pub enum Int {
+                    #(
+                        #[doc = #int_ty_doc]
+                        #int_ty_names,
+                    )*
+                }
+
+                impl crate::SettingsIndex<#settings_ident> for Int {
+                    type Output = i64;
+
+                    fn get(self, settings: &#settings_ident) -> &Self::Output {
+                        match self {#(
+                            Self::#int_ty_names => settings.#int_field_names().as_ref(),
+                        )*}
+                    }
+                }
+
                 #(
                 impl crate::AsIndex<#settings_ident> for #ty_names {
                     type Output = #ty_names;
@@ -616,7 +868,10 @@ pub fn write(settings: Settings, dest: &Path) {
                 ($this:expr, $for_each:path) => {
                     {
                         let _s: &#settings_ident = &$this;
-                        [ #( ($for_each)(_s.#enum_field_names) ),* ]
+                        [ #( ($for_each)(
+                            _s.#enum_field_names,
+                            <$crate::#enum_ty_names as $crate::Visible<$crate::#settings_ident>>::is_visible(_s),
+                        ) ),* ]
                     }
                 };
             }
@@ -628,7 +883,26 @@ pub fn write(settings: Settings, dest: &Path) {
                 ($this:expr, $for_each:path) => {
                     {
                         let _s: &#settings_ident = &$this;
-                        [ #( ($for_each)(&_s.#path_field_names) ),* ]
+                        [ #( ($for_each)(
+                            &_s.#path_field_names,
+                            <$crate::#path_ty_names as $crate::Visible<$crate::#settings_ident>>::is_visible(_s),
+                        ) ),* ]
+                    }
+                };
+            }
+
+            /// Get an array with `for_each` applied to each integer setting, passing any extra
+            /// arguments after value and visibility.
+            #[macro_export]
+            macro_rules! view_ints {
+                ($this:expr, $for_each:path $(, $arg:expr)* $(,)?) => {
+                    {
+                        let _s: &#settings_ident = &$this;
+                        [ #( ($for_each)(
+                            _s.#int_field_names,
+                            <$crate::#int_ty_names as $crate::Visible<$crate::#settings_ident>>::is_visible(_s),
+                            $($arg,)*
+                        ) ),* ]
                     }
                 };
             }
@@ -669,6 +943,14 @@ pub fn write(settings: Settings, dest: &Path) {
                 )*
             }
 
+            #(
+            impl crate::Visible<#settings_ident> for #ty_names {
+                fn is_visible(#visible_args: &#settings_ident) -> bool {
+                    #visible_exprs
+                }
+            }
+            )*
+
             #(
             impl From<#ty_names> for Delta {
                 fn from(value: #ty_names) -> Self {
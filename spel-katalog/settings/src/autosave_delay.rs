@@ -0,0 +1,15 @@
+//! [AutosaveDelay] impl.
+
+use ::core::time::Duration;
+
+use crate::AutosaveDelay;
+
+impl AutosaveDelay {
+    /// Get delay before autosaving, [None] if autosave is disabled.
+    pub fn duration(&self) -> Option<Duration> {
+        u64::try_from(self.get())
+            .ok()
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+    }
+}
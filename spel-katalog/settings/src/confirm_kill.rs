@@ -0,0 +1,12 @@
+//! [ConfirmKill] impl.
+
+use crate::ConfirmKill;
+
+impl ConfirmKill {
+    /// Should sending a signal to a process wait for confirmation.
+    ///
+    /// Terminating is never gated, since processes may handle it gracefully.
+    pub const fn requires_confirmation(self, terminate: bool) -> bool {
+        !terminate && matches!(self, ConfirmKill::Yes)
+    }
+}
@@ -0,0 +1,68 @@
+//! Persisted filter state.
+
+use ::std::path::Path;
+
+use ::serde::{Deserialize, Serialize};
+
+use crate::{Delta, FilterMode, Settings, Show};
+
+/// File name used for filter state in xdg state directory.
+pub const FILTER_STATE_FILE: &str = "filter.toml";
+
+/// Filter state that may be restored between sessions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct FilterState {
+    /// Filter string.
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub filter: String,
+    /// How hidden games are shown.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub show: Option<Show>,
+    /// How games are filtered.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub filter_mode: Option<FilterMode>,
+}
+
+impl FilterState {
+    /// Capture filter state from a filter and settings.
+    pub fn capture(filter: &str, settings: &Settings) -> Self {
+        Self {
+            filter: filter.to_owned(),
+            show: Some(*settings.get::<Show>()),
+            filter_mode: Some(*settings.get::<FilterMode>()),
+        }
+    }
+
+    /// Get setting deltas needed to restore state.
+    pub fn deltas(&self) -> impl Iterator<Item = Delta> {
+        [
+            self.show.map(Delta::Show),
+            self.filter_mode.map(Delta::FilterMode),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// Read filter state from given path, logging any errors.
+    pub fn read(path: &Path) -> Option<Self> {
+        let content = ::std::fs::read_to_string(path)
+            .map_err(|err| ::log::warn!("could not read filter state {path:?}\n{err}"))
+            .ok()?;
+
+        ::toml::from_str(&content)
+            .map_err(|err| ::log::warn!("could not parse filter state {path:?}\n{err}"))
+            .ok()
+    }
+
+    /// Write filter state to given path, logging any errors.
+    pub fn write(&self, path: &Path) -> Option<()> {
+        let content = ::toml::to_string_pretty(self)
+            .map_err(|err| ::log::error!("could not serialize filter state\n{err}"))
+            .ok()?;
+
+        ::std::fs::write(path, content)
+            .map_err(|err| ::log::error!("could not write filter state to {path:?}\n{err}"))
+            .ok()
+    }
+}
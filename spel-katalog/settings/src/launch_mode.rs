@@ -0,0 +1,20 @@
+//! [LaunchMode] impl.
+
+use crate::LaunchMode;
+
+impl LaunchMode {
+    /// Should the game be killed when the app stops waiting for it.
+    pub const fn kill_on_drop(self) -> bool {
+        matches!(self, LaunchMode::Attached)
+    }
+
+    /// Should the game be started in a new session, leaving the process group of the app.
+    pub const fn new_session(self) -> bool {
+        matches!(self, LaunchMode::Detached)
+    }
+
+    /// Should the game output be routed to the app sinks, if not it is discarded.
+    pub const fn routes_output(self) -> bool {
+        matches!(self, LaunchMode::Attached)
+    }
+}
@@ -10,9 +10,21 @@ use ::std::{
     sync::Arc,
 };
 
+pub use ::spel_katalog_formats::{temp_path, write_atomic};
 pub use ::spel_katalog_settings_traits::*;
 
+mod autosave_delay;
+mod confirm_kill;
 mod environment;
+mod filter_state;
+mod launch_mode;
+mod log_level;
+mod migrate;
+mod opener;
+mod show;
+mod terminal_lines;
+mod thumbnail_radius;
+mod window_theme;
 
 #[doc(hidden)]
 mod generated {
@@ -21,6 +33,14 @@ mod generated {
 }
 pub use generated::*;
 
+pub use filter_state::{FILTER_STATE_FILE, FilterState};
+pub use log_level::LOG_ENV;
+pub use migrate::{
+    MIGRATIONS, Migration, SETTINGS_VERSION, VERSION_KEY, migrate_table, migrate_with,
+    table_version,
+};
+pub use window_theme::ThemedWindow;
+
 /// Command line arguments for settings.
 #[derive(Debug, Args, Default, Clone)]
 pub struct SettingsArgs {
@@ -152,38 +172,116 @@ where
     }
 }
 
-/// Save settings to given path.
+/// Get path of the backup kept for a settings file.
+pub fn backup_path(path: &Path) -> PathBuf {
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(".bak");
+    PathBuf::from(backup)
+}
+
+/// Read settings from given path, migrating them to the current schema,
+/// [None] if it cannot be read or parsed.
+fn read_settings(config: &Path) -> Option<SettingsStore> {
+    let content = ::std::fs::read_to_string(config)
+        .map_err(|err| {
+            ::log::warn!("could not read {config:?}, does it exists an is it readable?\n{err}");
+        })
+        .ok()?;
+
+    migrate_with(&content, MIGRATIONS)
+        .map_err(|err| {
+            ::log::warn!("could not parse {config:?} as toml, is it a toml file?\n{err}")
+        })
+        .ok()
+}
+
+/// Save settings to given path, tagged with the current [SETTINGS_VERSION].
+///
+/// If the existing file at path holds valid settings it is first copied to
+/// the [backup_path] of path. The settings are written using [write_atomic].
 ///
 /// # Errors
 /// If settings cannot be either serialized or saved.
 pub async fn save(settings: Settings, path: PathBuf) -> Result<PathBuf, PathBuf> {
-    match ::toml::to_string_pretty(&*settings.inner) {
-        Ok(contents) => match ::smol::fs::write(&path, contents).await {
-            Ok(_) => Ok(path),
-            Err(err) => {
-                ::log::error!("could not write settings to {path:?}\n{err}");
-                Err(path)
-            }
-        },
+    let contents = ::toml::Table::try_from(&*settings.inner)
+        .map(|mut table| {
+            table.insert(VERSION_KEY.to_owned(), SETTINGS_VERSION.into());
+            table
+        })
+        .and_then(|table| ::toml::to_string_pretty(&table));
+    let contents = match contents {
+        Ok(contents) => contents,
         Err(err) => {
             ::log::error!("could not serialize settings\n{err}");
+            return Err(path);
+        }
+    };
+
+    if let Ok(existing) = ::smol::fs::read_to_string(&path).await
+        && migrate_with(&existing, MIGRATIONS).is_ok()
+    {
+        let backup = backup_path(&path);
+        if let Err(err) = ::smol::fs::write(&backup, existing).await {
+            ::log::warn!("could not back up settings to {backup:?}\n{err}");
+        }
+    }
+
+    match write_atomic(&path, contents.as_bytes()).await {
+        Ok(_) => Ok(path),
+        Err(err) => {
+            ::log::error!("could not write settings to {path:?}\n{err}");
             Err(path)
         }
     }
 }
 
-/// Load settings from given path, with specified overrides.
-pub fn load(path: &Path, overrides: SettingsArgs) -> Settings {
-    fn read_settings(config: &Path) -> Result<SettingsStore, ()> {
-        let content = ::std::fs::read_to_string(config).map_err(|err| {
-            ::log::warn!("could not read {config:?}, does it exists an is it readable?\n{err}");
-        })?;
+/// Get the [backup_path] of path if the file at path exists but cannot be
+/// read as settings, while the backup can.
+pub fn restorable_backup(path: &Path) -> Option<PathBuf> {
+    let backup = backup_path(path);
+    (path.exists()
+        && backup.exists()
+        && read_settings(path).is_none()
+        && read_settings(&backup).is_some())
+    .then_some(backup)
+}
 
-        ::toml::from_str(&content).map_err(|err| {
-            ::log::warn!("could not parse {config:?} as toml, is it a toml file?\n{err}")
-        })
+/// Restore settings at given path from its [backup_path], replacing the file at
+/// path using [write_atomic]. Returns the restored settings.
+///
+/// # Errors
+/// If the backup cannot be read as settings or written to path.
+pub async fn restore_backup(path: PathBuf) -> Result<SettingsStore, PathBuf> {
+    let backup = backup_path(&path);
+    let contents = match ::smol::fs::read_to_string(&backup).await {
+        Ok(contents) => contents,
+        Err(err) => {
+            ::log::error!("could not read settings backup {backup:?}\n{err}");
+            return Err(path);
+        }
+    };
+    let store = match migrate_with(&contents, MIGRATIONS) {
+        Ok(store) => store,
+        Err(err) => {
+            ::log::error!("could not parse settings backup {backup:?}\n{err}");
+            return Err(path);
+        }
+    };
+
+    match write_atomic(&path, contents.as_bytes()).await {
+        Ok(_) => Ok(store),
+        Err(err) => {
+            ::log::error!("could not restore settings to {path:?}\n{err}");
+            Err(path)
+        }
     }
+}
 
+/// Load settings from given path, with specified overrides.
+///
+/// Should the file at path not be readable as settings defaults are used, the
+/// file is left as is, see [restorable_backup].
+pub fn load(path: &Path, overrides: SettingsArgs) -> Settings {
     Settings {
         xdg: Arc::new(overrides.get_xdg()),
         inner: Arc::new(
@@ -0,0 +1,40 @@
+//! [LogLevel] impl.
+
+use ::log::LevelFilter;
+
+use crate::LogLevel;
+
+/// Environment variable which when set decides log level instead of [LogLevel].
+pub const LOG_ENV: &str = "RUST_LOG";
+
+impl LogLevel {
+    /// Get the log filter level corresponding to this setting.
+    pub const fn level_filter(self) -> LevelFilter {
+        match self {
+            LogLevel::Error => LevelFilter::Error,
+            LogLevel::Warn => LevelFilter::Warn,
+            LogLevel::Info => LevelFilter::Info,
+            LogLevel::Debug => LevelFilter::Debug,
+            LogLevel::Trace => LevelFilter::Trace,
+        }
+    }
+
+    /// Set the maximum level of emitted log records to this setting,
+    /// unless [LOG_ENV] is set.
+    pub fn apply(self) {
+        if !Self::from_env() {
+            ::log::set_max_level(self.level_filter());
+        }
+    }
+
+    /// Check if log level is decided by [LOG_ENV].
+    pub fn from_env() -> bool {
+        ::std::env::var_os(LOG_ENV).is_some()
+    }
+}
+
+impl From<LogLevel> for LevelFilter {
+    fn from(value: LogLevel) -> Self {
+        value.level_filter()
+    }
+}
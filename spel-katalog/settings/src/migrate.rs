@@ -0,0 +1,97 @@
+//! Migration of settings files written with an older schema.
+
+use ::toml::{Table, Value};
+
+use crate::{Settings, SettingsStore};
+
+/// Key holding the schema version of a settings file.
+pub const VERSION_KEY: &str = "version";
+
+/// Current schema version of settings files.
+pub const SETTINGS_VERSION: i64 = 1;
+
+/// Registered migrations, ordered by version.
+///
+/// Files without a version are of version 0.
+pub const MIGRATIONS: &[Migration] = &[
+    // Introduction of versioned settings files.
+    Migration {
+        version: 1,
+        renames: &[],
+        transform: None,
+    },
+];
+
+/// A migration of settings to a schema version.
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    /// Version produced by migration, applied to settings of a lower version.
+    pub version: i64,
+    /// Keys to rename as `(old, new)`, a present new key is kept over an old one.
+    pub renames: &'static [(&'static str, &'static str)],
+    /// Transform applied after renames.
+    pub transform: Option<fn(&mut Table)>,
+}
+
+impl Migration {
+    /// Apply migration to a settings table.
+    pub fn apply(&self, table: &mut Table) {
+        for (old, new) in self.renames {
+            if let Some(value) = table.remove(*old)
+                && !table.contains_key(*new)
+            {
+                table.insert((*new).to_owned(), value);
+            }
+        }
+
+        if let Some(transform) = self.transform {
+            transform(table);
+        }
+
+        table.insert(VERSION_KEY.to_owned(), Value::Integer(self.version));
+    }
+}
+
+/// Get schema version of a settings table.
+pub fn table_version(table: &Table) -> i64 {
+    table
+        .get(VERSION_KEY)
+        .and_then(Value::as_integer)
+        .unwrap_or(0)
+}
+
+/// Apply all migrations of a higher version than the table, in order.
+pub fn migrate_table(table: &mut Table, migrations: &[Migration]) {
+    let version = table_version(table);
+    if version > SETTINGS_VERSION {
+        ::log::warn!("settings version {version} is newer than supported {SETTINGS_VERSION}");
+    }
+
+    for migration in migrations.iter().filter(|m| m.version > version) {
+        ::log::info!("migrating settings to version {}", migration.version);
+        migration.apply(table);
+    }
+}
+
+/// Parse settings, migrating them to the current schema using given migrations.
+///
+/// # Errors
+/// If `raw` is not a toml table, or if the migrated table is not valid settings.
+pub fn migrate_with(
+    raw: &str,
+    migrations: &[Migration],
+) -> Result<SettingsStore, ::toml::de::Error> {
+    let mut table = ::toml::from_str::<Table>(raw)?;
+    migrate_table(&mut table, migrations);
+    table.try_into()
+}
+
+impl Settings {
+    /// Parse settings of any known schema version, migrating them to the current schema.
+    ///
+    /// # Errors
+    /// If `raw` is not a toml table, or if the migrated table is not valid settings.
+    pub fn migrate(raw: &str) -> Result<Settings, ::toml::de::Error> {
+        migrate_with(raw, MIGRATIONS).map(Settings::from)
+    }
+}
@@ -0,0 +1,67 @@
+//! [Opener] impl.
+
+use ::std::{ffi::OsString, path::Path, process::Command};
+
+use crate::Opener;
+
+/// Placeholder replaced by path in opener command.
+const PATH_PLACEHOLDER: &str = "{path}";
+
+impl Opener {
+    /// Get command line used to open `path`, [None] if the system default should be used.
+    ///
+    /// Occurrences of `{path}` are replaced by the path, if there are none the path
+    /// is appended as the last argument.
+    ///
+    /// # Errors
+    /// If the configured command cannot be split into arguments.
+    pub fn command_line(
+        &self,
+        path: &Path,
+    ) -> Result<Option<Vec<OsString>>, ::shell_words::ParseError> {
+        let words = ::shell_words::split(self)?;
+        if words.is_empty() {
+            return Ok(None);
+        }
+
+        let mut interpolated = false;
+        let mut command_line = words
+            .into_iter()
+            .map(|word| {
+                if word == PATH_PLACEHOLDER {
+                    interpolated = true;
+                    OsString::from(path)
+                } else if word.contains(PATH_PLACEHOLDER) {
+                    interpolated = true;
+                    OsString::from(word.replace(PATH_PLACEHOLDER, &path.to_string_lossy()))
+                } else {
+                    OsString::from(word)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if !interpolated {
+            command_line.push(OsString::from(path));
+        }
+
+        Ok(Some(command_line))
+    }
+
+    /// Open `path` using the configured command, or the system default if not set.
+    ///
+    /// # Errors
+    /// If the command cannot be split into arguments or spawned.
+    pub fn open(&self, path: &Path) -> ::std::io::Result<()> {
+        let command_line = self
+            .command_line(path)
+            .map_err(::std::io::Error::other)?
+            .unwrap_or_default();
+        let Some((program, args)) = command_line.split_first() else {
+            return ::open::that_detached(path);
+        };
+
+        let mut child = Command::new(program).args(args).spawn()?;
+        ::std::thread::spawn(move || child.wait());
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+//! [Show] impl.
+
+use crate::Show;
+
+impl Show {
+    /// Should a game with the given hidden and favorite state be shown.
+    pub const fn includes(self, hidden: bool, favorite: bool) -> bool {
+        match self {
+            Show::Apparent => !hidden,
+            Show::Hidden => hidden,
+            Show::All => true,
+            Show::Favorites => favorite,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! [TerminalLines] impl.
+
+use crate::TerminalLines;
+
+impl TerminalLines {
+    /// Get line limit to use for terminal.
+    pub fn limit(&self) -> u16 {
+        u16::try_from(self.get()).unwrap_or(u16::MAX)
+    }
+}
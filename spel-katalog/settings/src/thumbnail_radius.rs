@@ -0,0 +1,10 @@
+//! [ThumbnailRadius] impl.
+
+use crate::ThumbnailRadius;
+
+impl ThumbnailRadius {
+    /// Get corner radius in pixels.
+    pub fn pixels(&self) -> f32 {
+        u16::try_from(self.get()).map_or(0.0, f32::from)
+    }
+}
@@ -0,0 +1,29 @@
+//! Per window [Theme] overrides.
+
+use crate::{InstallerTheme, Settings, SettingsTheme, TerminalTheme, Theme};
+
+/// Kind of window a theme is resolved for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThemedWindow {
+    /// Main window, always uses the global theme.
+    Main,
+    /// Settings window.
+    Settings,
+    /// Terminal window.
+    Terminal,
+    /// Installer windows.
+    Installer,
+}
+
+impl Settings {
+    /// Get theme to use for a window, falling back to the global theme when not overridden.
+    pub fn window_theme(&self, window: ThemedWindow) -> Theme {
+        match window {
+            ThemedWindow::Main => None,
+            ThemedWindow::Settings => self.get::<SettingsTheme>().theme(),
+            ThemedWindow::Terminal => self.get::<TerminalTheme>().theme(),
+            ThemedWindow::Installer => self.get::<InstallerTheme>().theme(),
+        }
+        .unwrap_or(*self.get::<Theme>())
+    }
+}
@@ -20,6 +20,12 @@ pub trait Help {
     fn help() -> &'static str;
 }
 
+/// Trait for settings which are only relevant depending on other settings.
+pub trait Visible<S> {
+    /// Check if setting should be visible given current settings.
+    fn is_visible(settings: &S) -> bool;
+}
+
 /// Trait for types which index settings.
 pub trait SettingsIndex<S> {
     /// Output type returned by indexing
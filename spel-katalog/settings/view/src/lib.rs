@@ -3,14 +3,24 @@
 mod list;
 
 use ::core::ops::{Deref, DerefMut};
-use ::std::path::PathBuf;
+use ::std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
 use ::derive_more::{From, IsVariant};
 use ::iced_core::{Alignment, Element, Length::Fill};
 use ::iced_runtime::Task;
 use ::iced_widget::{button, space, text};
-use ::spel_katalog_common::{StatusSender, async_status, w};
-use ::spel_katalog_settings::{Delta, Settings, SettingsStore, save, view_enums, view_paths};
+use ::spel_katalog_common::{
+    StatusSender, async_status,
+    in_place::PushMaybe as _,
+    units::{UnitBase, format_size},
+    w,
+};
+use ::spel_katalog_settings::{
+    Delta, Settings, SettingsStore, restore_backup, save, view_enums, view_ints, view_paths,
+};
 use ::tap::Pipe;
 
 /// Convert settings theme to iced theme.
@@ -49,6 +59,17 @@ pub const fn conv_theme(value: ::spel_katalog_settings::Theme) -> ::iced_core::T
     )
 }
 
+/// Get syntax highlighter theme matching settings theme.
+pub fn highlighter_theme(value: ::spel_katalog_settings::Theme) -> ::iced_highlighter::Theme {
+    match value {
+        ::spel_katalog_settings::Theme::SolarizedDark => ::iced_highlighter::Theme::SolarizedDark,
+        theme if conv_theme(theme).extended_palette().is_dark => {
+            ::iced_highlighter::Theme::Base16Mocha
+        }
+        _ => ::iced_highlighter::Theme::InspiredGitHub,
+    }
+}
+
 /// Message used by settings view.
 #[derive(Debug, IsVariant, Clone, From)]
 pub enum Message {
@@ -56,6 +77,30 @@ pub enum Message {
     Delta(Delta),
     /// Save settings.
     Save,
+    /// Refresh displayed cache size.
+    RefreshCacheSize,
+    /// Set displayed cache size.
+    CacheSize(Option<u64>),
+    /// Restore settings from backup.
+    Restore,
+    /// Use restored settings.
+    Restored(Box<SettingsStore>),
+    /// Edit raw text of an integer setting.
+    IntInput {
+        /// Title of edited setting.
+        title: &'static str,
+        /// Raw text of input.
+        text: String,
+        /// Change to apply, if text is a value in range.
+        delta: Option<Delta>,
+    },
+    /// Submit raw text of an integer setting.
+    IntSubmit {
+        /// Title of submitted setting.
+        title: &'static str,
+        /// Change to apply, if text could be parsed.
+        delta: Option<Delta>,
+    },
 }
 
 /// State of settings view.
@@ -65,6 +110,12 @@ pub struct State {
     pub settings: Settings,
     /// Path to config file.
     pub config: PathBuf,
+    /// Size in bytes of files in cache directory.
+    pub cache_size: Option<u64>,
+    /// Backup which may be restored, set when config file could not be read.
+    pub backup: Option<PathBuf>,
+    /// Raw text of integer inputs being edited, by setting title.
+    pub int_drafts: BTreeMap<&'static str, String>,
 }
 
 impl DerefMut for State {
@@ -113,6 +164,50 @@ impl State {
                 })
                 .then(|_| Task::none());
             }
+            Message::RefreshCacheSize => {
+                let Some(cache_home) = self.xdg().get_cache_home() else {
+                    return Task::none();
+                };
+                return Task::future(::smol::unblock(move || cache_size(&cache_home)))
+                    .map(Message::CacheSize);
+            }
+            Message::CacheSize(cache_size) => {
+                self.cache_size = cache_size;
+            }
+            Message::Restore => {
+                let tx = tx.clone();
+                let path = self.config.clone();
+                return Task::future(async move {
+                    match restore_backup(path).await {
+                        Ok(store) => {
+                            async_status!(tx, "restored settings from backup").await;
+                            Some(Message::Restored(Box::new(store)))
+                        }
+                        Err(path) => {
+                            async_status!(tx, "could not restore settings to {path:?}").await;
+                            None
+                        }
+                    }
+                })
+                .and_then(Task::done);
+            }
+            Message::Restored(store) => {
+                *self.settings = *store;
+                self.backup = None;
+                self.int_drafts.clear();
+            }
+            Message::IntInput { title, text, delta } => {
+                self.int_drafts.insert(title, text);
+                if let Some(delta) = delta {
+                    delta.apply(self);
+                }
+            }
+            Message::IntSubmit { title, delta } => {
+                self.int_drafts.remove(title);
+                if let Some(delta) = delta {
+                    delta.apply(self);
+                }
+            }
         };
         Task::none()
     }
@@ -121,12 +216,33 @@ impl State {
     pub fn view_enums(
         &self,
     ) -> ::iced_core::Element<'_, Delta, ::iced_core::Theme, ::iced_widget::Renderer> {
-        crate::list::enum_list(view_enums!(self, crate::list::enum_choice)).into()
+        crate::list::enum_list(
+            view_enums!(self, crate::list::enum_choice)
+                .into_iter()
+                .flatten(),
+        )
+        .into()
+    }
+
+    /// Get element to display integer options.
+    pub fn view_ints(
+        &self,
+    ) -> ::iced_core::Element<'_, Message, ::iced_core::Theme, ::iced_widget::Renderer> {
+        crate::list::enum_list(
+            view_ints!(self, crate::list::int_input, &self.int_drafts)
+                .into_iter()
+                .flatten(),
+        )
+        .into()
     }
 
     /// Get element to display path options.
     pub fn view_paths(&self) -> ::iced_widget::Column<'_, Delta> {
-        crate::list::path_list(view_paths!(self, crate::list::path_input))
+        crate::list::path_list(
+            view_paths!(self, crate::list::path_input)
+                .into_iter()
+                .flatten(),
+        )
     }
 
     /// View settings.
@@ -140,6 +256,25 @@ impl State {
                     .push(text("Settings").align_x(Alignment::Center).width(Fill))
                     .push(button("Save").padding(3).on_press(Message::Save)),
             )
+            .push_maybe(self.backup.as_ref().map(|backup| {
+                w::row()
+                    .push(
+                        text(format!(
+                            "Could not read {:?}, restore {backup:?}?",
+                            self.config
+                        ))
+                        .style(text::danger)
+                        .width(Fill),
+                    )
+                    .push(button("Restore").padding(3).on_press(Message::Restore))
+            }))
+            .push_maybe(self.cache_size.map(|cache_size| {
+                text(format!(
+                    "Cache Size: {}",
+                    format_size(cache_size, UnitBase::Binary)
+                ))
+                .style(text::secondary)
+            }))
             .push(spel_katalog_widget::rule::horizontal())
             .push(
                 self.view_enums()
@@ -147,6 +282,8 @@ impl State {
                     .pipe(::spel_katalog_widget::scrollable),
             )
             .push(spel_katalog_widget::rule::horizontal())
+            .push(self.view_ints())
+            .push(spel_katalog_widget::rule::horizontal())
             .push(
                 self.view_paths()
                     .push(space())
@@ -157,3 +294,17 @@ impl State {
             .into()
     }
 }
+
+/// Get total size of files in cache directory.
+fn cache_size(cache_home: &Path) -> Option<u64> {
+    let entries = ::std::fs::read_dir(cache_home)
+        .map_err(|err| ::log::warn!("could not read cache directory {cache_home:?}\n{err}"))
+        .ok()?;
+
+    entries
+        .filter_map(|entry| entry.ok()?.metadata().ok())
+        .filter(|metadata| metadata.is_file())
+        .map(|metadata| metadata.len())
+        .sum::<u64>()
+        .pipe(Some)
+}
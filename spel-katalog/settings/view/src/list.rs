@@ -1,12 +1,17 @@
 //! Setting viewer helpers.
 
+use ::std::collections::BTreeMap;
+
 use ::iced_core::{Alignment, Element};
 use ::iced_widget::{self as widget, Column, Row, container, pick_list, text_input, tooltip};
 use ::tap::Pipe;
 use spel_katalog_common::w;
 
+use ::spel_katalog_settings::Delta;
 use ::spel_katalog_settings_traits::{DefaultStr, Help, Title, TrustedVariants};
 
+use crate::Message;
+
 /// Display element with help tooltip.
 fn with_tooltip<'a, T: Help, M: 'a>(
     elem: impl Into<Element<'a, M, ::iced_core::Theme, ::iced_renderer::Renderer>>,
@@ -21,18 +26,23 @@ fn with_tooltip<'a, T: Help, M: 'a>(
     )
 }
 
-/// Create a choice widget for a setting.
+/// Create a choice widget for a setting, if it is visible.
 pub fn enum_choice<'a, T, M>(
     value: Option<T>,
-) -> (
+    visible: bool,
+) -> Option<(
     &'a str,
     Element<'a, M, ::iced_core::Theme, ::iced_renderer::Renderer>,
-)
+)>
 where
     T: TrustedVariants + Clone + PartialEq + ToString + Default + Title + Help,
     M: 'a + From<T>,
 {
-    (
+    if !visible {
+        return None;
+    }
+
+    Some((
         T::title(),
         with_tooltip::<T, _>(
             pick_list(
@@ -44,7 +54,7 @@ where
         )
         .pipe(Element::from)
         .map(M::from),
-    )
+    ))
 }
 
 /// Create a list of settings consisting of name and set columns.
@@ -70,18 +80,23 @@ where
     w::row().align_y(Alignment::Start).push(l).push(r)
 }
 
-/// Create a path input.
+/// Create a path input, if it is visible.
 pub fn path_input<'a, T, M>(
     value: &Option<T>,
-) -> (
+    visible: bool,
+) -> Option<(
     &'a str,
     Element<'a, M, ::iced_core::Theme, ::iced_renderer::Renderer>,
-)
+)>
 where
     T: 'static + DefaultStr + AsRef<str> + From<String> + Clone + Title + Help,
     M: 'a + From<T>,
 {
-    (
+    if !visible {
+        return None;
+    }
+
+    Some((
         T::title(),
         with_tooltip::<T, _>(
             text_input(
@@ -96,7 +111,65 @@ where
         )
         .pipe(Element::from)
         .map(M::from),
-    )
+    ))
+}
+
+/// Create a numeric input for an integer setting, if it is visible.
+///
+/// While edited the raw text is kept in `drafts`, values in range are applied as they are
+/// typed. On submit an empty input resets the value to its default, and a number out of
+/// range is clamped.
+pub fn int_input<'a, T>(
+    value: Option<T>,
+    visible: bool,
+    drafts: &BTreeMap<&'static str, String>,
+) -> Option<(
+    &'a str,
+    Element<'a, Message, ::iced_core::Theme, ::iced_renderer::Renderer>,
+)>
+where
+    T: 'static + DefaultStr + Default + Copy + Into<i64> + From<i64> + Title + Help,
+    Delta: From<T>,
+{
+    if !visible {
+        return None;
+    }
+
+    let text = match drafts.get(T::title()) {
+        Some(draft) => draft.clone(),
+        None => value
+            .map(|value| Into::<i64>::into(value).to_string())
+            .unwrap_or_default(),
+    };
+    let submitted = match text.trim() {
+        "" => Some(T::default()),
+        text => text.parse::<i64>().ok().map(T::from),
+    };
+    Some((
+        T::title(),
+        with_tooltip::<T, _>(
+            text_input(T::default_str(), &text)
+                .padding(3)
+                .on_input(|text| {
+                    let delta = text
+                        .trim()
+                        .parse::<i64>()
+                        .ok()
+                        .filter(|&number| Into::<i64>::into(T::from(number)) == number)
+                        .map(|number| Delta::from(T::from(number)));
+                    Message::IntInput {
+                        title: T::title(),
+                        text,
+                        delta,
+                    }
+                })
+                .on_submit(Message::IntSubmit {
+                    title: T::title(),
+                    delta: submitted.map(Delta::from),
+                }),
+        )
+        .into(),
+    ))
 }
 
 /// Create a list of settings consisting of name and input columns.
@@ -0,0 +1,39 @@
+//! Version, license and credits of application.
+
+use ::iced_core::Length::Fill;
+use ::iced_widget as widget;
+use ::spel_katalog_common::w;
+
+use crate::Element;
+
+/// Version of application.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// License of application.
+pub const LICENSE: &str = env!("CARGO_PKG_LICENSE");
+
+/// Authors of application.
+pub const AUTHORS: &str = env!("CARGO_PKG_AUTHORS");
+
+/// Source repository of application.
+pub const REPOSITORY: &str = "https://github.com/axel-lord/spel-katalog";
+
+/// Get name and version of application.
+pub fn version_label() -> String {
+    format!("Spel Katalog {VERSION}")
+}
+
+/// View about window.
+pub(crate) fn view<'a, M: 'a>() -> Element<'a, M> {
+    widget::container(
+        w::col()
+            .spacing(3)
+            .push(widget::text(version_label()).size(20))
+            .push(widget::text(format!("License: {LICENSE}")))
+            .push(widget::text(format!("Authors: {AUTHORS}")))
+            .push(widget::text(format!("Source: {REPOSITORY}"))),
+    )
+    .padding(5)
+    .center(Fill)
+    .into()
+}
@@ -1,21 +1,32 @@
-use ::std::{convert::identity, io::PipeReader, sync::Arc};
+use ::std::{collections::BTreeMap, io::PipeReader, sync::Arc};
 
 use ::color_eyre::{Section, eyre::eyre};
 use ::derive_more::IsVariant;
 use ::iced::Font;
 use ::iced_core::{Alignment::Center, Length::Fill, font, window};
 use ::iced_runtime::Task;
-use ::iced_widget::{self as widget, Row, text, text_input, toggler, value};
+use ::iced_widget::{self as widget, Row, text, text_input, toggler};
 use ::rustc_hash::FxHashMap;
 use ::spel_katalog_cli::Run;
-use ::spel_katalog_common::{StatusSender, w};
+use ::spel_katalog_common::{
+    Busy, OrRequest, StatusSender, in_place::PushMaybe as _, units::format_count, w,
+};
 use ::spel_katalog_installer::Installer;
-use ::spel_katalog_settings::{FilterMode, Network, Theme};
+use ::spel_katalog_settings::{
+    FILTER_STATE_FILE, FilterMode, FilterState, LogLevel, Network, RestoreFilter, TerminalLines,
+    ThemedWindow, restorable_backup,
+};
 use ::spel_katalog_sink::{SinkBuilder, SinkIdentity};
 use ::spel_katalog_widget::ListMenu;
 use ::tap::Pipe;
 
-use crate::{Element, ExitReceiver, Message, QuickMessage, get_settings, process_info, view};
+use crate::{
+    Element, ExitReceiver, FILTER_HISTORY_FILE, FilterHistory, Message, QuickMessage,
+    bulk_attrs::BulkAttrs, get_settings, import_directory::PendingImport, process_info, view,
+};
+
+/// Id of quick launch text input.
+pub(crate) const QUICK_LAUNCH_INPUT: &str = "quick-launch";
 
 /// Specific kind of window.
 #[derive(Debug, IsVariant, Clone)]
@@ -28,6 +39,20 @@ pub enum WindowType {
     Settings,
     /// Show an installer window.
     Installer(Box<Installer>),
+    /// Show version and credits.
+    About,
+}
+
+impl WindowType {
+    /// Get kind of window used to resolve theme.
+    const fn themed(&self) -> ThemedWindow {
+        match self {
+            WindowType::Main | WindowType::About => ThemedWindow::Main,
+            WindowType::Term => ThemedWindow::Terminal,
+            WindowType::Settings => ThemedWindow::Settings,
+            WindowType::Installer(..) => ThemedWindow::Installer,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -36,15 +61,21 @@ pub(crate) struct App {
     pub games: ::spel_katalog_games::State,
     pub status: String,
     pub filter: String,
+    pub filter_history: FilterHistory,
     pub view: view::State,
     pub info: ::spel_katalog_info::State,
     pub sender: StatusSender,
     pub process_list: Vec<process_info::ProcessInfo>,
+    pub bulk_attrs: BulkAttrs,
+    pub pending_kill: Option<process_info::PendingKill>,
+    pub pending_import: Option<PendingImport>,
+    pub quick_launch: Option<::spel_katalog_games::QuickLaunch>,
     pub sink_builder: SinkBuilder,
     pub windows: FxHashMap<window::Id, WindowType>,
     pub terminal: ::spel_katalog_terminal::Terminal,
     pub process_view_semaphore: Arc<::smol::lock::Semaphore>,
     pub games_db: ::spel_katalog_native::Pool,
+    pub busy: Busy,
 }
 
 /// Initial state created by new.
@@ -75,20 +106,55 @@ impl Initial {
             show_terminal,
         } = run;
 
-        let settings = get_settings(&config, settings);
+        let mut settings = get_settings(&config, settings);
+        settings.get::<LogLevel>().apply();
 
         let (status_tx, status_rx) = ::flume::bounded(64);
 
-        let filter = String::new();
-        let status = String::new();
+        let filter = if settings.get::<RestoreFilter>().is_yes()
+            && let Some(path) = settings.xdg().get_state_file(FILTER_STATE_FILE)
+            && path.exists()
+            && let Some(state) = FilterState::read(&path)
+        {
+            for delta in state.deltas() {
+                delta.apply(&mut settings);
+            }
+            state.filter
+        } else {
+            String::new()
+        };
+        let filter_history = if settings.get::<RestoreFilter>().is_yes()
+            && let Some(path) = settings.xdg().get_state_file(FILTER_HISTORY_FILE)
+            && path.exists()
+        {
+            FilterHistory::read(&path).unwrap_or_default()
+        } else {
+            FilterHistory::default()
+        };
+        let backup = restorable_backup(&config);
+        let status = if backup.is_some() {
+            format!("could not read {config:?}, a backup may be restored from settings")
+        } else {
+            String::new()
+        };
         let view = view::State::new();
-        let settings = ::spel_katalog_settings_view::State { settings, config };
-        let games = ::spel_katalog_games::State::default();
+        let settings = ::spel_katalog_settings_view::State {
+            settings,
+            config,
+            cache_size: None,
+            backup,
+            int_drafts: BTreeMap::new(),
+        };
+        let busy = Busy::default();
+        let games = ::spel_katalog_games::State::default().with_busy(busy.clone());
         let info = ::spel_katalog_info::State::default();
         let sender = status_tx.into();
         let process_list = Vec::new();
         let windows = FxHashMap::default();
-        let terminal = ::spel_katalog_terminal::Terminal::default().with_limit(256);
+        let terminal = ::spel_katalog_terminal::Terminal::default()
+            .with_limit(settings.get::<TerminalLines>().limit())
+            .with_max_pipes(64)
+            .with_byte_limit(16 * 1024 * 1024);
         let process_view_semaphore = Arc::new(::smol::lock::Semaphore::new(1));
         let games_db = ::spel_katalog_native::Pool::new(
             &settings
@@ -106,9 +172,14 @@ impl Initial {
 
         let app = App {
             filter,
+            filter_history,
             games,
             info,
             process_list,
+            bulk_attrs: BulkAttrs::default(),
+            pending_kill: None,
+            pending_import: None,
+            quick_launch: None,
             sender,
             settings,
             sink_builder,
@@ -118,6 +189,7 @@ impl Initial {
             windows,
             process_view_semaphore,
             games_db,
+            busy,
         };
 
         Ok(Self {
@@ -147,7 +219,7 @@ impl App {
 
         let receive_status = Task::stream(status_rx.into_stream()).map(Message::Status);
         let exit_recv = exit_recv
-            .map(|exit_recv| Task::future(exit_recv.recv()).then(|_| ::iced_runtime::exit()))
+            .map(|exit_recv| Task::future(exit_recv.recv()).map(|_| Message::Exit))
             .unwrap_or_else(Task::none);
         let window_recv = terminal_rx
             .map(|terminal_rx| {
@@ -206,9 +278,13 @@ impl App {
             weight: font::Weight::Medium,
             ..Font::DEFAULT
         })
-        .theme(|this: &Self, _: window::Id| {
+        .theme(|this: &Self, id: window::Id| {
+            let window = this
+                .windows
+                .get(&id)
+                .map_or(ThemedWindow::Main, WindowType::themed);
             Some(::spel_katalog_settings_view::conv_theme(
-                *this.settings.get::<Theme>(),
+                this.settings.window_theme(window),
             ))
         })
         .run()
@@ -225,6 +301,34 @@ impl App {
         }
     }
 
+    /// Save state which should persist and exit the application.
+    pub fn shutdown(&mut self) -> Task<Message> {
+        self.sink_builder = ::spel_katalog_sink::SinkBuilder::Inherit;
+        self.save_filter_state();
+        ::iced_runtime::exit()
+    }
+
+    /// Save current filter state, if it should be restored on startup.
+    pub fn save_filter_state(&self) {
+        if !self.settings.get::<RestoreFilter>().is_yes() {
+            return;
+        }
+
+        match self.settings.xdg().place_state_file(FILTER_STATE_FILE) {
+            Ok(path) => {
+                _ = FilterState::capture(&self.filter, &self.settings).write(&path);
+            }
+            Err(err) => ::log::error!("could not place filter state file\n{err}"),
+        }
+
+        match self.settings.xdg().place_state_file(FILTER_HISTORY_FILE) {
+            Ok(path) => {
+                _ = self.filter_history.write(&path);
+            }
+            Err(err) => ::log::error!("could not place filter history file\n{err}"),
+        }
+    }
+
     pub fn sort_games(&mut self) {
         self.games.sort(&self.settings, &self.filter);
     }
@@ -246,12 +350,75 @@ impl App {
                 .padding(5)
                 .into(),
             WindowType::Term => self.terminal.view().map(From::from),
+            WindowType::About => crate::about::view(),
             WindowType::Installer(installer) => installer
                 .view(&self.settings)
                 .map(move |msg| Message::Installer(id, msg)),
         }
     }
 
+    fn view_busy(&self) -> Option<Element<'_, Message>> {
+        const FRAMES: [&str; 4] = ["◐", "◓", "◑", "◒"];
+
+        let label = self.busy.label()?;
+        let frame = ::std::time::SystemTime::now()
+            .duration_since(::std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() / 120) as usize
+            % FRAMES.len();
+
+        Row::new()
+            .align_y(Center)
+            .spacing(5)
+            .push(text(FRAMES[frame]).font(Font::MONOSPACE))
+            .push(text(label))
+            .push_maybe(
+                self.busy
+                    .progress()
+                    .map(|(done, total)| text(format!("{done}/{total}")).font(Font::MONOSPACE)),
+            )
+            .pipe(widget::container)
+            .padding(5)
+            .style(widget::container::bordered_box)
+            .pipe(widget::bottom_right)
+            .padding(10)
+            .pipe(Element::from)
+            .pipe(Some)
+    }
+
+    fn view_quick_launch(&self) -> Option<Element<'_, Message>> {
+        let quick_launch = self.quick_launch.as_ref()?;
+        let top = quick_launch.top();
+
+        w::col()
+            .width(400)
+            .push(
+                text_input("launch...", quick_launch.query())
+                    .id(QUICK_LAUNCH_INPUT)
+                    .padding(3)
+                    .on_input(Message::QuickLaunch)
+                    .on_submit(Message::LaunchQuick),
+            )
+            .extend(quick_launch.matches().iter().filter_map(|&id| {
+                let game = self.games.by_id(id)?;
+                text(game.name())
+                    .pipe(|name| {
+                        if Some(id) == top {
+                            name
+                        } else {
+                            name.style(widget::text::secondary)
+                        }
+                    })
+                    .pipe(Element::from)
+                    .pipe(Some)
+            }))
+            .pipe(widget::container)
+            .padding(5)
+            .style(widget::container::bordered_box)
+            .pipe(widget::center)
+            .pipe(widget::opaque)
+            .pipe(Some)
+    }
+
     pub fn view_main(&self) -> Element<'_, Message> {
         fn with_global_context(menu: ListMenu<'_, Message>) -> ListMenu<'_, Message> {
             menu.push(widget::text("Spel Katalog"))
@@ -260,44 +427,86 @@ impl App {
                     Message::Quick(QuickMessage::OpenInstaller)
                 })
                 .button("Convert All", || Message::Quick(QuickMessage::ConvertAll))
+                .button("Import Directory", || {
+                    Message::Quick(QuickMessage::ImportDirectory)
+                })
+                .button("Import Lutris Covers", || {
+                    ::spel_katalog_games::Message::ImportLutrisCovers
+                        .pipe(OrRequest::Message)
+                        .pipe(Message::Games)
+                })
+                .button("Import Cover Urls", || {
+                    Message::Quick(QuickMessage::ImportCoverUrls)
+                })
+                .button("Export Batch", || Message::Quick(QuickMessage::ExportBatch))
+                .button("Edit Batch Attributes", || {
+                    Message::ShowInfo(view::Displayed::BulkAttrs)
+                })
                 .button("Open DB", || Message::Quick(QuickMessage::OpenDatabase))
                 .button("Reload Games", || Message::Quick(QuickMessage::ReloadGames))
+                .button("About", || Message::Quick(QuickMessage::ToggleAbout))
         }
         w::col()
             .padding(5)
             .spacing(0)
             .push(
-                text_input(
-                    match self.settings.settings.get::<FilterMode>() {
-                        ::spel_katalog_settings::FilterMode::Filter => "filter...",
-                        ::spel_katalog_settings::FilterMode::Search => "search...",
-                        ::spel_katalog_settings::FilterMode::Regex => "regex...",
-                    },
-                    &self.filter,
-                )
-                .width(Fill)
-                .padding(3)
-                .on_input(identity)
-                .pipe(Element::from)
-                .map(Message::Filter)
-                .pipe(|element| {
-                    ::iced_aw::ContextMenu::new(element, || {
-                        ListMenu::new()
-                            .push(widget::text("Filter"))
-                            .separator()
-                            .button("Copy", || Message::Quick(QuickMessage::CopyFilter))
-                            .button("Paste", || Message::Quick(QuickMessage::PasteFilter))
-                            .separator()
-                            .pipe(with_global_context)
-                            .into()
-                    })
-                }),
+                Row::new()
+                    .spacing(3)
+                    .push(
+                        text_input(
+                            match self.settings.settings.get::<FilterMode>() {
+                                ::spel_katalog_settings::FilterMode::Filter => "filter...",
+                                ::spel_katalog_settings::FilterMode::Search => "search...",
+                                ::spel_katalog_settings::FilterMode::Regex => "regex...",
+                            },
+                            &self.filter,
+                        )
+                        .width(Fill)
+                        .padding(3)
+                        .on_input(Message::Filter)
+                        .on_submit(Message::CommitFilter),
+                    )
+                    .push_maybe((!self.filter_history.is_empty()).then(|| {
+                        widget::pick_list(
+                            self.filter_history
+                                .iter()
+                                .map(String::from)
+                                .collect::<Vec<_>>(),
+                            None::<String>,
+                            Message::Filter,
+                        )
+                        .placeholder("recent")
+                        .padding(3)
+                    }))
+                    .pipe(Element::from)
+                    .pipe(|element| {
+                        ::iced_aw::ContextMenu::new(element, || {
+                            ListMenu::new()
+                                .push(widget::text("Filter"))
+                                .separator()
+                                .button("Copy", || Message::Quick(QuickMessage::CopyFilter))
+                                .button("Paste", || Message::Quick(QuickMessage::PasteFilter))
+                                .separator()
+                                .pipe(with_global_context)
+                                .into()
+                        })
+                    }),
             )
             .push(widget::space::vertical().height(5))
-            .push(
-                self.view
-                    .view(&self.games, &self.info, &self.process_list, &self.settings),
-            )
+            .push(widget::stack(
+                [self.view.view(
+                    &self.games,
+                    &self.info,
+                    &self.process_list,
+                    self.pending_kill,
+                    &self.bulk_attrs,
+                    &self.settings,
+                )]
+                .into_iter()
+                .chain(self.view_busy())
+                .chain(self.view_quick_launch())
+                .chain(self.pending_import.as_ref().map(PendingImport::view)),
+            ))
             .push(widget::space::vertical().height(3))
             .push(spel_katalog_widget::rule::horizontal())
             .push(widget::space::vertical().height(3))
@@ -305,11 +514,23 @@ impl App {
                 Row::new()
                     .align_y(Center)
                     .push(text(&self.status).width(Fill))
+                    .push_maybe(
+                        Some(self.games.batch_selected_count())
+                            .filter(|count| *count > 0)
+                            .map(|count| {
+                                Row::new()
+                                    .align_y(Center)
+                                    .push(text("Batch").style(widget::text::secondary))
+                                    .push(widget::space::horizontal().width(5))
+                                    .push(text(format_count(count as u64)))
+                                    .push(widget::space::horizontal().width(7))
+                            }),
+                    )
                     .push(text("Displayed / All").style(widget::text::secondary))
                     .push(widget::space::horizontal().width(5))
-                    .push(value(self.games.displayed_count()))
+                    .push(text(format_count(self.games.displayed_count() as u64)))
                     .push(text(" / "))
-                    .push(value(self.games.all_count()))
+                    .push(text(format_count(self.games.all_count() as u64)))
                     .push(widget::space::horizontal().width(7))
                     .push(text("Network").style(widget::text::secondary))
                     .push(widget::space::horizontal().width(5))
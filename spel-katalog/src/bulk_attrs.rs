@@ -0,0 +1,205 @@
+//! Bulk editing of attributes of batch selected games.
+
+use ::iced_core::Length::Fill;
+use ::iced_runtime::Task;
+use ::iced_widget::{self as widget, button, text, text_input};
+use ::spel_katalog_common::{OrRequest, async_status, w};
+use ::spel_katalog_formats::{AdditionalConfig, AttrEdit, GameId};
+use ::tap::Pipe;
+
+use crate::{App, Element};
+
+/// State of bulk attribute editor.
+#[derive(Debug, Default)]
+pub struct BulkAttrs {
+    /// Key of attribute to edit.
+    key: String,
+    /// Value attribute is set to.
+    value: String,
+}
+
+/// Messages for bulk attribute editor.
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// Set key of attribute to edit.
+    Key(String),
+    /// Set value attribute is set to.
+    Value(String),
+    /// Set attribute for all batch selected games.
+    Set,
+    /// Remove attribute from all batch selected games.
+    Remove,
+    /// Additional config of games was written.
+    Written(Vec<GameId>),
+}
+
+impl BulkAttrs {
+    /// Get trimmed key, [None] if empty.
+    fn key(&self) -> Option<&str> {
+        Some(self.key.trim()).filter(|key| !key.is_empty())
+    }
+
+    /// View bulk attribute editor with a preview of affected games.
+    pub fn view<'a>(
+        &'a self,
+        games: &'a ::spel_katalog_games::State,
+    ) -> Element<'a, crate::Message> {
+        let has_key = self.key().is_some();
+        let affected = games
+            .batch_selected()
+            .filter(|game| game.game.is_lutris())
+            .collect::<Vec<_>>();
+        let skipped = games.batch_selected_count() - affected.len();
+        let can_apply = has_key && !affected.is_empty();
+
+        w::col()
+            .push(
+                w::row()
+                    .push(
+                        text_input("key...", &self.key)
+                            .on_input(Message::Key)
+                            .padding(3),
+                    )
+                    .push(
+                        text_input("value...", &self.value)
+                            .on_input(Message::Value)
+                            .on_submit_maybe(can_apply.then_some(Message::Set))
+                            .padding(3),
+                    ),
+            )
+            .push(
+                w::row()
+                    .push(
+                        button("Set")
+                            .padding(3)
+                            .on_press_maybe(can_apply.then_some(Message::Set)),
+                    )
+                    .push(
+                        button("Remove")
+                            .padding(3)
+                            .style(button::danger)
+                            .on_press_maybe(can_apply.then_some(Message::Remove)),
+                    )
+                    .push(widget::space::horizontal())
+                    .push(
+                        text(match skipped {
+                            0 => format!("{} games affected", affected.len()),
+                            _ => format!(
+                                "{} games affected, {skipped} native skipped",
+                                affected.len()
+                            ),
+                        })
+                        .style(widget::text::secondary),
+                    ),
+            )
+            .push(spel_katalog_widget::rule::horizontal())
+            .push(
+                w::col()
+                    .extend(affected.into_iter().map(|game| -> Element<'a, Message> {
+                        w::row()
+                            .push(text(game.game.name()).width(Fill))
+                            .push(text(game.id().to_string()).style(widget::text::secondary))
+                            .into()
+                    }))
+                    .pipe(spel_katalog_widget::scrollable),
+            )
+            .pipe(Element::from)
+            .map(crate::Message::BulkAttrs)
+    }
+}
+
+impl App {
+    /// Update bulk attribute editor.
+    pub(crate) fn bulk_attrs_update(&mut self, message: Message) -> Task<crate::Message> {
+        match message {
+            Message::Key(key) => self.bulk_attrs.key = key,
+            Message::Value(value) => self.bulk_attrs.value = value,
+            Message::Set => {
+                if let Some(key) = self.bulk_attrs.key() {
+                    let edit = AttrEdit::Set {
+                        key: key.to_owned(),
+                        value: self.bulk_attrs.value.clone(),
+                    };
+                    return self.apply_attr_edit(edit);
+                }
+            }
+            Message::Remove => {
+                if let Some(key) = self.bulk_attrs.key() {
+                    let edit = AttrEdit::Remove {
+                        key: key.to_owned(),
+                    };
+                    return self.apply_attr_edit(edit);
+                }
+            }
+            Message::Written(ids) => {
+                // Reload info of viewed game such that saving it does not revert the edit.
+                if let Some(id) = self.info.id()
+                    && ids.contains(&id)
+                    && let Some(game) = self.games.by_id(id)
+                {
+                    return self
+                        .info
+                        .set_game(&self.sender, &self.settings, game, &self.games_db)
+                        .map(OrRequest::Message)
+                        .map(crate::Message::Info);
+                }
+            }
+        }
+        Task::none()
+    }
+
+    /// Apply an attribute edit to the additional config of all batch selected lutris games.
+    fn apply_attr_edit(&mut self, edit: AttrEdit) -> Task<crate::Message> {
+        let ids = self
+            .games
+            .batch_selected()
+            .filter(|game| game.game.is_lutris())
+            .map(|game| game.id())
+            .collect::<Vec<_>>();
+
+        if ids.is_empty() {
+            self.set_status("no lutris games are batch selected");
+            return Task::none();
+        }
+
+        let Some(config_dir) = self.settings.xdg().get_config_home() else {
+            ::log::error!("could not get config home");
+            return Task::none();
+        };
+        let tx = self.sender.clone();
+
+        Task::future(async move {
+            let mut configs = Vec::with_capacity(ids.len());
+            for id in ids {
+                // Games with unreadable configs are skipped as to not overwrite them.
+                match AdditionalConfig::read(&AdditionalConfig::path(&config_dir, id)).await {
+                    Ok(config) => configs.push((id, config.unwrap_or_default())),
+                    Err(err) => ::log::error!("{err}"),
+                }
+            }
+
+            let writes = edit.apply_to(configs);
+            let mut written = Vec::with_capacity(writes.len());
+
+            for (id, config) in writes {
+                match config.write(&AdditionalConfig::path(&config_dir, id)).await {
+                    Ok(()) => written.push(id),
+                    Err(err) => ::log::error!("{err}"),
+                }
+            }
+
+            let key = edit.key();
+            match edit {
+                AttrEdit::Set { .. } => {
+                    async_status!(tx, "set {key:?} for {} games", written.len()).await
+                }
+                AttrEdit::Remove { .. } => {
+                    async_status!(tx, "removed {key:?} from {} games", written.len()).await
+                }
+            }
+
+            Message::Written(written)
+        })
+        .map(crate::Message::BulkAttrs)
+    }
+}
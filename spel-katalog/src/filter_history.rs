@@ -0,0 +1,79 @@
+//! Persisted history of recent filters.
+
+use ::std::{collections::VecDeque, path::Path};
+
+use ::serde::{Deserialize, Serialize};
+
+/// File name used for filter history in xdg state directory.
+pub const FILTER_HISTORY_FILE: &str = "filter-history.toml";
+
+/// Recently committed filters, most recent first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilterHistory {
+    /// Filters in history.
+    #[serde(default)]
+    filters: VecDeque<String>,
+}
+
+impl FilterHistory {
+    /// Amount of filters kept at most.
+    pub const CAP: usize = 16;
+
+    /// Add a filter to the front of history, returns true if history changed.
+    ///
+    /// Blank filters are ignored, earlier occurrences of the filter are removed
+    /// and the oldest filters are dropped when exceeding [FilterHistory::CAP].
+    pub fn push(&mut self, filter: &str) -> bool {
+        if filter.trim().is_empty() {
+            return false;
+        }
+
+        if self.filters.front().is_some_and(|first| first == filter) {
+            return false;
+        }
+
+        self.filters.retain(|existing| existing != filter);
+        self.filters.push_front(filter.to_owned());
+        self.filters.truncate(Self::CAP);
+        true
+    }
+
+    /// Filters in history, most recent first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        self.filters.iter().map(String::as_str)
+    }
+
+    /// Amount of filters in history.
+    pub fn len(&self) -> usize {
+        self.filters.len()
+    }
+
+    /// History contains no filters.
+    pub fn is_empty(&self) -> bool {
+        self.filters.is_empty()
+    }
+
+    /// Read filter history from given path, logging any errors.
+    pub fn read(path: &Path) -> Option<Self> {
+        let content = ::std::fs::read_to_string(path)
+            .map_err(|err| ::log::warn!("could not read filter history {path:?}\n{err}"))
+            .ok()?;
+
+        let mut history = ::toml::from_str::<Self>(&content)
+            .map_err(|err| ::log::warn!("could not parse filter history {path:?}\n{err}"))
+            .ok()?;
+        history.filters.truncate(Self::CAP);
+        Some(history)
+    }
+
+    /// Write filter history to given path, logging any errors.
+    pub fn write(&self, path: &Path) -> Option<()> {
+        let content = ::toml::to_string_pretty(self)
+            .map_err(|err| ::log::error!("could not serialize filter history\n{err}"))
+            .ok()?;
+
+        ::std::fs::write(path, content)
+            .map_err(|err| ::log::error!("could not write filter history to {path:?}\n{err}"))
+            .ok()
+    }
+}
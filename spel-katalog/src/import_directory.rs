@@ -0,0 +1,152 @@
+//! Import of games from a directory of executables, staged for confirmation.
+
+use ::std::{io, path::PathBuf};
+
+use ::iced_core::{Color, Length::Fill, alignment::Horizontal::Left};
+use ::iced_runtime::Task;
+use ::iced_widget::{self as widget, button, container, opaque, text};
+use ::rustc_hash::FxHashSet;
+use ::spel_katalog_common::{IntoOrRequest, async_status, w};
+use ::spel_katalog_formats::{NativeGame, Timestamp};
+use ::tap::Pipe;
+
+use crate::{App, Element, Message};
+
+/// Games found in a directory awaiting confirmation before being imported.
+#[derive(Debug, Clone)]
+pub struct PendingImport {
+    /// Directory games were found in.
+    pub(crate) dir: PathBuf,
+    /// Found games and if they should be imported.
+    pub(crate) games: Vec<(bool, NativeGame)>,
+}
+
+impl PendingImport {
+    pub fn view<'e>(&'e self) -> Element<'e, Message> {
+        let any_selected = self.games.iter().any(|(selected, _)| *selected);
+
+        w::col()
+            .push(text(format!("Import games from {:?}?", self.dir)))
+            .push(
+                w::col()
+                    .align_x(Left)
+                    .extend(
+                        self.games
+                            .iter()
+                            .enumerate()
+                            .map(|(idx, (selected, game))| {
+                                widget::checkbox(*selected)
+                                    .label(game.name.as_str())
+                                    .on_toggle(move |_| Message::ToggleImport(idx))
+                                    .into()
+                            }),
+                    )
+                    .pipe(spel_katalog_widget::scrollable)
+                    .height(Fill),
+            )
+            .push(
+                w::row()
+                    .spacing(6)
+                    .push(
+                        button("Import")
+                            .padding(3)
+                            .style(button::success)
+                            .on_press_maybe(any_selected.then_some(Message::ConfirmImport)),
+                    )
+                    .push(
+                        button("Cancel")
+                            .padding(3)
+                            .style(button::secondary)
+                            .on_press(Message::CancelImport),
+                    ),
+            )
+            .pipe(container)
+            .style(container::bordered_box)
+            .padding(5)
+            .max_width(500)
+            .pipe(container)
+            .center(Fill)
+            .padding(20)
+            .style(|_theme| container::background(Color::from_rgba8(0, 0, 0, 0.7)))
+            .pipe(opaque)
+    }
+}
+
+impl App {
+    /// Pick a directory and scan it for executables of games not already known.
+    pub(crate) fn stage_import(&self) -> impl 'static + Future<Output = Option<PendingImport>> {
+        let game_db = self.games_db.clone();
+        let tx = self.sender.clone();
+        let lutris_slugs = self
+            .games
+            .all()
+            .iter()
+            .filter_map(|game| game.slug().map(ToOwned::to_owned))
+            .collect::<FxHashSet<_>>();
+
+        async move {
+            let dir = ::rfd::AsyncFileDialog::new()
+                .set_title("Import Directory")
+                .pick_folder()
+                .await?
+                .path()
+                .to_path_buf();
+
+            let scanned = ::smol::unblock({
+                let dir = dir.clone();
+                move || {
+                    let games = ::spel_katalog_gather::scan_executables(&dir, Timestamp::now())?;
+                    let mut slugs = lutris_slugs;
+                    let mut exes = FxHashSet::default();
+                    game_db.gather(&mut |_, game| {
+                        if let Some(slug) = game.attrs.get("slug") {
+                            slugs.insert(slug.clone());
+                        }
+                        exes.insert(game.exe);
+                    });
+                    Ok::<_, io::Error>(::spel_katalog_gather::remove_known(games, &slugs, &exes))
+                }
+            })
+            .await;
+            let scanned = match scanned {
+                Ok(scanned) => scanned,
+                Err(err) => {
+                    ::log::error!("could not scan {dir:?} for executables\n{err}");
+                    async_status!(tx, "could not scan {dir:?}").await;
+                    return None;
+                }
+            };
+
+            if scanned.is_empty() {
+                async_status!(tx, "no new games found in {dir:?}").await;
+                return None;
+            }
+
+            Some(PendingImport {
+                dir,
+                games: scanned.into_iter().map(|game| (true, game)).collect(),
+            })
+        }
+    }
+
+    /// Import selected games of pending import.
+    pub(crate) fn import_staged(&mut self) -> Task<Message> {
+        let Some(PendingImport { dir, games }) = self.pending_import.take() else {
+            return Task::none();
+        };
+        let game_db = self.games_db.clone();
+        let tx = self.sender.clone();
+
+        Task::future(async move {
+            let mut imported = Vec::new();
+            for (_, game) in games.into_iter().filter(|(selected, _)| *selected) {
+                imported.extend(Self::convert_game(game_db.clone(), game, None).await);
+            }
+            async_status!(tx, "imported {} games from {dir:?}", imported.len()).await;
+
+            ::spel_katalog_games::Message::AddNativeGames { games: imported }
+                .into_message()
+                .pipe(Message::Games)
+        })
+    }
+}
@@ -3,7 +3,10 @@ use ::std::path::Path;
 use ::spel_katalog_cli::Run;
 use ::spel_katalog_sink::SinkBuilder;
 
-pub use self::exit_channel::{ExitReceiver, ExitSender, exit_channel};
+pub use self::{
+    exit_channel::{ExitReceiver, ExitSender, exit_channel},
+    filter_history::{FILTER_HISTORY_FILE, FilterHistory},
+};
 
 pub(crate) use self::{
     app::App,
@@ -11,7 +14,10 @@ pub(crate) use self::{
 };
 
 mod app;
+mod bulk_attrs;
 mod exit_channel;
+mod filter_history;
+mod import_directory;
 mod message;
 mod process_info;
 mod run_game;
@@ -19,7 +25,9 @@ mod subscription;
 mod update;
 mod view;
 
+pub mod about;
 pub mod oneshot_broadcast;
+pub mod screenshot;
 
 /// Element alias
 type Element<'a, M> = ::iced_core::Element<'a, M, ::iced_core::Theme, ::iced_renderer::Renderer>;
@@ -3,6 +3,7 @@ use ::std::io::{IsTerminal, Read};
 use ::mimalloc::MiMalloc;
 use ::spel_katalog::run as run_app;
 use ::spel_katalog_cli::{Cli, Subcmd, SubcmdCallbacks};
+use ::spel_katalog_settings::LogLevel;
 use ::spel_katalog_sink::SinkBuilder;
 
 #[global_allocator]
@@ -26,12 +27,17 @@ fn main() -> ::color_eyre::Result<()> {
     let cli = Cli::parse();
     let cmd = Subcmd::from(cli);
     let mut log_builder = ::env_logger::builder();
-    log_builder.filter_level(::log::LevelFilter::Info);
+    if !LogLevel::from_env() {
+        // Actual level is set from settings once they are loaded.
+        log_builder.filter_level(::log::LevelFilter::Trace);
+    }
 
     if let Some(target) = None {
         log_builder.target(target).init();
     } else {
         log_builder.init();
     }
+
+    LogLevel::default().apply();
     cmd.perform(SubcmdCallbacks { run })
 }
@@ -3,7 +3,7 @@ use ::iced_core::window;
 use ::spel_katalog_common::OrRequest;
 use ::spel_katalog_formats::NativeGame;
 
-use crate::{app::WindowType, process_info, view};
+use crate::{app::WindowType, bulk_attrs, import_directory, process_info, view};
 
 #[derive(Debug, Clone, Copy, Default, IsVariant, PartialEq, Eq, Hash)]
 pub enum Safety {
@@ -36,13 +36,21 @@ pub enum QuickMessage {
     ToggleNetwork,
     ToggleProcessInfo,
     ToggleSettings,
+    ToggleThumbnails,
     Debug,
     ConvertAll,
+    ImportDirectory,
+    ImportCoverUrls,
+    ExportBatch,
     OpenDatabase,
     CopyFilter,
     PasteFilter,
     ReloadGames,
     OpenInstaller,
+    Screenshot,
+    OpenQuickLaunch,
+    CloseQuickLaunch,
+    ToggleAbout,
 }
 
 #[derive(Debug, IsVariant, From, Clone)]
@@ -50,6 +58,7 @@ pub enum Message {
     #[from]
     Status(String),
     Filter(String),
+    CommitFilter,
     #[from]
     Settings(::spel_katalog_settings_view::Message),
     #[from]
@@ -61,12 +70,23 @@ pub enum Message {
     #[from]
     Quick(QuickMessage),
     ProcessInfo(Vec<process_info::ProcessInfo>),
+    BulkAttrs(bulk_attrs::Message),
     Kill {
         pid: i64,
         terminate: bool,
+        tree: bool,
     },
+    ConfirmKill,
+    CancelKill,
+    StageImport(import_directory::PendingImport),
+    ToggleImport(usize),
+    ConfirmImport,
+    CancelImport,
+    QuickLaunch(String),
+    LaunchQuick,
     OpenWindow(window::Id, WindowType),
     CloseWindow(window::Id),
+    Exit,
     Installer(
         window::Id,
         OrRequest<::spel_katalog_installer::Message, ::spel_katalog_installer::Request>,
@@ -79,6 +99,8 @@ pub enum Message {
     Ipc(::spel_katalog_ipc::Message),
     RunGameNative(Box<NativeGame>),
     RunShellNative(Box<NativeGame>),
+    Screenshot(window::Screenshot),
+    BusyTick,
 }
 
 impl<T, E> From<Result<T, E>> for Message
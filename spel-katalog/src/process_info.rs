@@ -21,11 +21,48 @@ pub struct ProcessInfo {
     pub(crate) cmdline: String,
 }
 
+/// A KILL awaiting confirmation.
+#[derive(Debug, Clone, Copy)]
+pub struct PendingKill {
+    pub(crate) pid: i64,
+    pub(crate) tree: bool,
+}
+
+impl PendingKill {
+    pub fn view<'e>(self) -> Element<'e, Message> {
+        let Self { pid, tree } = self;
+        w::row()
+            .spacing(6)
+            .push(text(if tree {
+                format!("Send KILL to process tree of {pid}?")
+            } else {
+                format!("Send KILL to process {pid}?")
+            }))
+            .push(
+                button("Kill")
+                    .padding(3)
+                    .style(button::danger)
+                    .on_press(Message::ConfirmKill),
+            )
+            .push(
+                button("Cancel")
+                    .padding(3)
+                    .style(button::secondary)
+                    .on_press(Message::CancelKill),
+            )
+            .into()
+    }
+}
+
 impl ProcessInfo {
-    pub fn view_list<'e>(list: &'e [ProcessInfo]) -> Element<'e, Message> {
+    pub fn view_list<'e>(
+        list: &'e [ProcessInfo],
+        pending_kill: Option<PendingKill>,
+    ) -> Element<'e, Message> {
         container(
             w::col()
                 .push("Process Tree")
+                .push_maybe(pending_kill.map(PendingKill::view))
                 .extend(list.iter().map(|info| info.view()))
                 .align_x(Left)
                 .pipe(spel_katalog_widget::scrollable)
@@ -52,12 +89,21 @@ impl ProcessInfo {
         struct Kill {
             pid: i64,
             terminate: bool,
+            tree: bool,
         }
 
         impl From<Kill> for Message {
             fn from(value: Kill) -> Self {
-                let Kill { pid, terminate } = value;
-                Message::Kill { pid, terminate }
+                let Kill {
+                    pid,
+                    terminate,
+                    tree,
+                } = value;
+                Message::Kill {
+                    pid,
+                    terminate,
+                    tree,
+                }
             }
         }
 
@@ -67,6 +113,7 @@ impl ProcessInfo {
             .push(button("X").padding(3).style(button::danger).on_press(Kill {
                 pid,
                 terminate: true,
+                tree: false,
             }))
             .push(
                 button("K")
@@ -75,6 +122,24 @@ impl ProcessInfo {
                     .on_press(Kill {
                         pid,
                         terminate: false,
+                        tree: false,
+                    }),
+            )
+            .push(
+                button("T")
+                    .padding(3)
+                    .style(button::danger)
+                    .on_press(Kill {
+                        pid,
+                        terminate: true,
+                        tree: true,
+                    })
+                    .pipe(|b| {
+                        widget::tooltip(
+                            b,
+                            "Terminate process tree",
+                            widget::tooltip::Position::Bottom,
+                        )
                     }),
             )
             .push(value(pid))
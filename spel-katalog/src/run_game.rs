@@ -1,6 +1,6 @@
 use ::std::{
     ffi::{OsStr, OsString},
-    path::Path,
+    process::Stdio,
 };
 
 use ::bytes::Bytes;
@@ -13,13 +13,13 @@ use ::spel_katalog_formats::{
 };
 use ::spel_katalog_ipc::http::ResponseCode;
 use ::spel_katalog_run::{
-    Callback, dll_overrides,
+    Callback, dll_overrides, game_command,
     run_umu::{CommonUmuCtx, LutrisCtx, LutrisUmuCtx},
     sandbox_ro_dirs,
 };
 use ::spel_katalog_settings::{
-    BubblewrapExe, FirejailExe, GamescopeExe, LutrisExe, Network, OnRun, SandboxMode, ShellExe,
-    TermCommand, UmuRunExe, UseGamescope, YmlDir,
+    BubblewrapExe, FirejailExe, GamescopeExe, LaunchMode, LutrisExe, Network, OnRun, SandboxMode,
+    ShellExe, TermCommand, UmuRunExe, UseGamescope, YmlDir,
 };
 use ::spel_katalog_sink::SinkIdentity;
 use ::tap::{Pipe, TapOptional};
@@ -34,21 +34,6 @@ enum ConfigError {
     Scan(#[from] ::yaml_rust2::ScanError),
 }
 
-async fn parse_extra_config(extra_config_path: &Path) -> Result<AdditionalConfig, String> {
-    ::toml::from_str::<AdditionalConfig>(
-        &::smol::fs::read_to_string(&extra_config_path)
-            .await
-            .map_err(|err| {
-                ::log::error!("could not read {extra_config_path:?}\n{err}");
-                format!("could not read {extra_config_path:?}")
-            })?,
-    )
-    .map_err(|err| {
-        ::log::error!("could not parse {extra_config_path:?}\n{err}");
-        format!("could not parse {extra_config_path:?}")
-    })
-}
-
 impl App {
     pub fn game_as_native(
         &self,
@@ -62,7 +47,7 @@ impl App {
 
         match &game.game {
             Game::Lutris(game) => {
-                let GameId::Lutris(lutris_id) = game_id else {
+                let GameId::Lutris(_) = game_id else {
                     ::log::error!(
                         "cannot convert lutris game without lutris id to native game, id: {game_id}"
                     );
@@ -74,11 +59,12 @@ impl App {
                 let installed_at = game.installed_at;
                 let yml_dir = self.settings.get::<YmlDir>();
                 let configpath = format!("{yml_dir}/{}.yml", game.configpath);
-                let extra_config_path = self
+                let config_dir = self
                     .settings
                     .xdg()
-                    .get_config_file(format!("games/{lutris_id}.toml"))
-                    .tap_none(|| ::log::error!("could not get games/{lutris_id} in config dir"))?;
+                    .get_config_home()
+                    .tap_none(|| ::log::error!("could not get config dir"))?;
+                let extra_config_path = AdditionalConfig::path(&config_dir, game_id);
 
                 Some(async move {
                     let config = ::smol::fs::read_to_string(&configpath)
@@ -88,14 +74,11 @@ impl App {
                     let config = lutris_config::Config::parse(&config)
                         .map_err(|err| ::log::error!("could not parse {configpath:?}\n{err}"))
                         .ok()?;
-                    let extra_config = if extra_config_path.exists() {
-                        parse_extra_config(&extra_config_path)
-                            .await
-                            .map_err(|err| ::log::error!("could not parse extra config\n{err}"))
-                            .ok()
-                    } else {
-                        None
-                    };
+                    let extra_config = AdditionalConfig::read(&extra_config_path)
+                        .await
+                        .map_err(|err| ::log::error!("{err}"))
+                        .ok()
+                        .flatten();
                     let game = LutrisCtx {
                         config: &config,
                         exe: &config.game.exe,
@@ -286,16 +269,14 @@ impl App {
         let installed_at = game.installed_at;
         let net_disabled = self.settings.get::<Network>().is_disabled();
         let sink_builder = self.sink_builder.clone();
+        let launch_mode = *self.settings.get::<LaunchMode>();
         let yml_dir = self.settings.get::<YmlDir>();
         let configpath = format!("{yml_dir}/{}.yml", game.configpath);
-        let Some(extra_config_path) = self
-            .settings
-            .xdg()
-            .get_config_file(format!("games/{lutris_id}.toml"))
-        else {
-            ::log::error!("could not get games/{lutris_id}.toml in config dir");
+        let Some(config_dir) = self.settings.xdg().get_config_home() else {
+            ::log::error!("could not get config dir");
             return Task::none();
         };
+        let extra_config_path = AdditionalConfig::path(&config_dir, id);
 
         let (send_open, recv_open) = oneshot_broadcast();
 
@@ -314,13 +295,14 @@ impl App {
                 }
             };
 
-            let extra_config = if extra_config_path.exists() {
-                match parse_extra_config(&extra_config_path).await {
-                    Err(err) => return err.into(),
-                    Ok(additional) => Some(additional),
+            let extra_config = match AdditionalConfig::read(&extra_config_path).await {
+                Ok(extra_config) => extra_config,
+                Err(err) => {
+                    ::log::error!("while loading additional config\n{err}");
+                    return "could not load additional config for game"
+                        .to_owned()
+                        .into();
                 }
-            } else {
-                None
             };
 
             let rungame = if no_game {
@@ -329,6 +311,24 @@ impl App {
                 Some(format!("lutris:rungameid/{lutris_id}"))
             };
 
+            let launch_override = extra_config
+                .as_ref()
+                .filter(|_| !no_game)
+                .map(|extra| extra.launch_command(&config.game.exe, config.game.prefix.as_deref()))
+                .transpose();
+            let command_line = match launch_override {
+                Ok(Some(launch_override)) => launch_override,
+                Ok(None) => {
+                    let mut command_line = vec![OsString::from(lutris.as_os_str())];
+                    command_line.extend(rungame.map(OsString::from));
+                    command_line
+                }
+                Err(err) => {
+                    ::log::error!("could not split launch override of {slug}\n{err}");
+                    return "could not split launch override".to_owned().into();
+                }
+            };
+
             fn wl(p: impl AsRef<OsStr>) -> OsString {
                 let mut s = OsString::new();
                 s.push("--whitelist=");
@@ -336,22 +336,26 @@ impl App {
                 s
             }
 
-            let (stdout, stderr) = match sink_builder.build(|| SinkIdentity::GameId(lutris_id)) {
-                Ok([stdout, stderr]) => (stdout, stderr),
-                Err(err) => {
-                    ::log::error!("could not create process output sinks\n{err}");
-                    return "could not create output sinks".to_owned().into();
+            let (stdout, stderr) = if launch_mode.routes_output() {
+                match sink_builder.build(|| SinkIdentity::GameId(lutris_id)) {
+                    Ok([stdout, stderr]) => (stdout, stderr),
+                    Err(err) => {
+                        ::log::error!("could not create process output sinks\n{err}");
+                        return "could not create output sinks".to_owned().into();
+                    }
                 }
+            } else {
+                (Stdio::null(), Stdio::null())
             };
 
             let cmd = match (safety, sandbox_mode) {
                 (Safety::None, _) => {
-                    ::log::info!("executing {lutris:?} with arguments\n{:#?}", [&rungame]);
-                    ::smol::process::Command::new(lutris)
-                        .args(rungame)
-                        .kill_on_drop(true)
-                        .stdout(stdout)
-                        .stderr(stderr)
+                    let Some((program, args)) = command_line.split_first() else {
+                        return "launch command is empty".to_owned().into();
+                    };
+                    ::log::info!("executing {program:?} with arguments\n{args:#?}");
+                    game_command(program, launch_mode, stdout, stderr)
+                        .args(args)
                         .status()
                 }
                 (Safety::Sandbox, SandboxMode::Firejail) => {
@@ -372,19 +376,12 @@ impl App {
                         args.push("--net=none".into());
                     }
 
-                    args.push(lutris.as_os_str().into());
-
-                    if let Some(rungame) = rungame {
-                        args.push(rungame.into());
-                    };
+                    args.extend(command_line);
 
                     ::log::info!("executing {firejail:?} with arguments\n{args:#?}");
 
-                    ::smol::process::Command::new(firejail)
+                    game_command(firejail, launch_mode, stdout, stderr)
                         .args(args)
-                        .kill_on_drop(true)
-                        .stdout(stdout)
-                        .stderr(stderr)
                         .status()
                 }
 
@@ -406,6 +403,7 @@ impl App {
                             gamescope: gamescope.as_path(),
                             use_gamescope,
                             sink_builder,
+                            launch_mode,
                         },
                         lutris: LutrisCtx {
                             config: &config,
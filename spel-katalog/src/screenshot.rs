@@ -0,0 +1,65 @@
+//! Saving of screenshots.
+
+use ::std::path::{Path, PathBuf};
+
+use ::chrono::NaiveDateTime;
+use ::iced_core::window;
+use ::iced_runtime::Task;
+use ::spel_katalog_settings::ScreenshotDir;
+
+use crate::{App, Message};
+
+/// Get a path in dir to save a screenshot taken at given time to,
+/// that is not already in use.
+///
+/// # Panics
+/// Should every numbered path be in use.
+pub fn screenshot_path(dir: &Path, time: NaiveDateTime) -> PathBuf {
+    let stem = time.format("spel-katalog_%Y-%m-%d_%H-%M-%S").to_string();
+    let path = dir.join(&stem).with_extension("png");
+
+    if !path.exists() {
+        return path;
+    }
+
+    (1..)
+        .map(|n| dir.join(format!("{stem}_{n}")).with_extension("png"))
+        .find(|path| !path.exists())
+        .expect("some screenshot path should be free")
+}
+
+impl App {
+    pub(crate) fn save_screenshot(&mut self, screenshot: window::Screenshot) -> Task<Message> {
+        let dir = self.settings.get::<ScreenshotDir>().to_path_buf();
+        let image = ::spel_katalog_formats::Image {
+            width: screenshot.size.width,
+            height: screenshot.size.height,
+            bytes: screenshot.rgba,
+        };
+
+        Task::future(::smol::unblock(move || {
+            if let Err(err) = ::std::fs::create_dir_all(&dir) {
+                ::log::error!("could not create screenshot dir {dir:?}\n{err}");
+                return Message::Status(format!("could not create {dir:?}"));
+            }
+
+            let path = screenshot_path(&dir, ::chrono::Local::now().naive_local());
+
+            let png = match image.to_png() {
+                Ok(png) => png,
+                Err(err) => {
+                    ::log::error!("could not encode screenshot\n{err}");
+                    return Message::Status("could not encode screenshot".to_owned());
+                }
+            };
+
+            match ::std::fs::write(&path, png) {
+                Ok(_) => Message::Status(format!("saved screenshot to {path:?}")),
+                Err(err) => {
+                    ::log::error!("could not write screenshot to {path:?}\n{err}");
+                    Message::Status(format!("could not write {path:?}"))
+                }
+            }
+        }))
+    }
+}
@@ -1,6 +1,9 @@
 use ::std::time::Duration;
 
-use ::iced_core::keyboard::{self, Modifiers, key::Named};
+use ::iced_core::{
+    Event,
+    keyboard::{self, Modifiers, key::Named},
+};
 use ::iced_futures::Subscription;
 use ::spel_katalog_common::OrRequest;
 use ::spel_katalog_games::SelDir;
@@ -18,6 +21,17 @@ impl App {
                 .pipe(Some)
         }
 
+        // While quick launch is open its input captures escape, as such escape is listened
+        // for whether captured or not, and other quick keys are ignored.
+        let quick_launch =
+            ::iced_futures::event::listen_with(|event, _status, _window| match event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(Named::Escape),
+                    ..
+                }) => Some(Message::Quick(QuickMessage::CloseQuickLaunch)),
+                _ => None,
+            });
+
         let key_event = ::iced::keyboard::listen().filter_map(|event| match event {
             keyboard::Event::KeyPressed {
                 key,
@@ -34,6 +48,8 @@ impl App {
                         "h" => QuickMessage::CycleHidden,
                         "f" => QuickMessage::CycleFilter,
                         "n" => QuickMessage::ToggleNetwork,
+                        "t" => QuickMessage::ToggleThumbnails,
+                        "l" => QuickMessage::OpenQuickLaunch,
                         _ => return None,
                     },
                     keyboard::Key::Named(named) => match named {
@@ -44,10 +60,12 @@ impl App {
 
                         Named::Tab => QuickMessage::Next,
                         Named::Enter | Named::Space => QuickMessage::RunSelected,
+                        Named::F1 => QuickMessage::ToggleAbout,
                         Named::F2 => QuickMessage::ToggleSettings,
                         Named::F3 => QuickMessage::ToggleMain,
                         Named::F5 => QuickMessage::ToggleGameInfo,
                         Named::F7 => QuickMessage::ToggleProcessInfo,
+                        Named::F12 => QuickMessage::Screenshot,
                         _ => return None,
                     },
                     _ => return None,
@@ -70,18 +88,24 @@ impl App {
                 };
                 QuickMessage::Prev
             } else if modifiers == Modifiers::CTRL {
-                if let keyboard::Key::Character(chr) = key.as_ref()
-                    && chr == "q"
-                {
-                    QuickMessage::CloseAll
-                } else {
+                let keyboard::Key::Character(chr) = key.as_ref() else {
                     return None;
+                };
+                match chr {
+                    "q" => QuickMessage::CloseAll,
+                    "r" => QuickMessage::ReloadGames,
+                    _ => return None,
                 }
             } else {
                 return None;
             })),
             _ => None,
         });
+        let key_event = if self.quick_launch.is_some() {
+            quick_launch
+        } else {
+            key_event
+        };
 
         let refresh = if self.view.displayed.is_processes() {
             ::iced_futures::backend::default::time::every(Duration::from_millis(500))
@@ -90,6 +114,13 @@ impl App {
             Subscription::none()
         };
 
+        let busy_tick = if self.busy.is_busy() {
+            ::iced_futures::backend::default::time::every(Duration::from_millis(120))
+                .map(|_| Message::BusyTick)
+        } else {
+            Subscription::none()
+        };
+
         let window_close = ::iced_runtime::window::close_events().map(Message::CloseWindow);
         let games = self
             .games
@@ -97,6 +128,6 @@ impl App {
             .map(OrRequest::Message)
             .map(Message::Games);
 
-        Subscription::batch([key_event, window_close, refresh, games])
+        Subscription::batch([key_event, window_close, refresh, games, busy_tick])
     }
 }
@@ -1,20 +1,28 @@
 use ::core::convert::identity;
-use ::std::path::PathBuf;
+use ::std::{collections::BTreeMap, path::PathBuf};
 
 use ::iced_core::{Size, window};
 use ::iced_runtime::Task;
 use ::image::DynamicImage;
 use ::rustix::process::{Pid, RawPid};
-use ::spel_katalog_common::{IntoOrRequest, OrRequest};
-use ::spel_katalog_formats::{InstallerConfig, InstallerPrepareConfig, NativeGame, RunMode};
-use ::spel_katalog_games::SelDir;
+use ::spel_katalog_common::{IntoOrRequest, OrRequest, async_status};
+use ::spel_katalog_formats::{
+    ExportFormat, GameId, InstallerConfig, InstallerPrepareConfig, NativeGame, RunMode,
+    export_games,
+};
+use ::spel_katalog_games::{QuickLaunch, SelDir};
 use ::spel_katalog_settings::{
-    FilterMode, Load, LutrisDb, Network, Settings, Show, TrustedVariants,
+    ConfirmKill, FilterMode, Load, LogLevel, LutrisDb, Network, Opener, Settings, Show,
+    TerminalLines, TrustedVariants,
 };
 use ::tap::Pipe;
 use ::uuid::Uuid;
 
-use crate::{App, Message, QuickMessage, Safety, app::WindowType};
+use crate::{
+    App, Message, QuickMessage, Safety,
+    app::{QUICK_LAUNCH_INPUT, WindowType},
+    process_info::PendingKill,
+};
 
 #[derive(Default)]
 #[non_exhaustive]
@@ -62,6 +70,128 @@ impl App {
         }
     }
 
+    fn show_output(&mut self, game_id: GameId) -> Task<Message> {
+        let GameId::Lutris(lutris_id) = game_id else {
+            self.set_status("output is only tracked by id for lutris games");
+            return Task::none();
+        };
+        let Some(idx) = self.terminal.game_pipe(lutris_id) else {
+            self.set_status(format!("no output available for {lutris_id}"));
+            return Task::none();
+        };
+
+        let show_term = if let Some(term) = self.find_windows(|t| t.is_term()).next() {
+            ::iced_runtime::window::gain_focus(term)
+        } else {
+            let (_, task) = ::iced_runtime::window::open(Default::default());
+            task.map(|id| Message::OpenWindow(id, WindowType::Term))
+        };
+
+        Task::batch([
+            self.terminal
+                .update(::spel_katalog_terminal::Message::SetCurrent { idx: Some(idx) })
+                .map(From::from),
+            show_term,
+        ])
+    }
+
+    /// Pick a cover url list, a toml table of slugs to urls, and read it.
+    fn pick_cover_urls(&self) -> impl 'static + Future<Output = Option<BTreeMap<String, String>>> {
+        let tx = self.sender.clone();
+
+        async move {
+            let path = ::rfd::AsyncFileDialog::new()
+                .set_title("Import Cover Urls")
+                .add_filter("toml", &["toml"])
+                .pick_file()
+                .await?
+                .path()
+                .to_path_buf();
+
+            let read = ::smol::unblock({
+                let path = path.clone();
+                move || ::spel_katalog_gather::read_cover_urls(&path)
+            })
+            .await;
+            match read {
+                Ok(urls) => Some(urls),
+                Err(err) => {
+                    ::log::error!("could not read cover urls from {path:?}\n{err}");
+                    async_status!(tx, "could not read cover urls from {path:?}").await;
+                    None
+                }
+            }
+        }
+    }
+
+    /// Pick a file to save terminal pipe with given index to.
+    fn pick_pipe_file(&self, idx: usize) -> Task<Message> {
+        let Some(file_name) = self.terminal.pipe_file_name(idx) else {
+            ::log::warn!("cannot save unavailable pipe {idx}");
+            return Task::none();
+        };
+
+        Task::future(async move {
+            let path = ::rfd::AsyncFileDialog::new()
+                .set_title("Save Output")
+                .set_file_name(file_name)
+                .save_file()
+                .await?
+                .path()
+                .to_path_buf();
+            Some(Message::Terminal(
+                ::spel_katalog_terminal::Message::SavePipeTo { idx, path },
+            ))
+        })
+        .and_then(Task::done)
+    }
+
+    fn export_batch(&self) -> impl 'static + Future<Output = ()> {
+        let games = self
+            .games
+            .batch_selected()
+            .map(|game| game.game.clone())
+            .collect::<Vec<_>>();
+        let tx = self.sender.clone();
+
+        async move {
+            if games.is_empty() {
+                async_status!(tx, "no games are batch selected").await;
+                return;
+            }
+
+            let Some(file) = ::rfd::AsyncFileDialog::new()
+                .set_title("Export Batch")
+                .add_filter("json", &["json"])
+                .add_filter("csv", &["csv"])
+                .set_file_name("games.json")
+                .save_file()
+                .await
+            else {
+                return;
+            };
+            let path = file.path().to_path_buf();
+            let format = ExportFormat::from_path(&path).unwrap_or(ExportFormat::Json);
+
+            let content = match export_games(&games, format) {
+                Ok(content) => content,
+                Err(err) => {
+                    ::log::error!("could not export games\n{err}");
+                    async_status!(tx, "could not export games").await;
+                    return;
+                }
+            };
+
+            match ::smol::fs::write(&path, content).await {
+                Ok(()) => async_status!(tx, "exported {} games to {path:?}", games.len()).await,
+                Err(err) => {
+                    ::log::error!("could not write {path:?}\n{err}");
+                    async_status!(tx, "could not write {path:?}").await;
+                }
+            }
+        }
+    }
+
     fn convert_all(&self) -> impl 'static + Future<Output = Vec<(Uuid, NativeGame)>> {
         let game_db = self.games_db.clone();
         let futures = self
@@ -84,7 +214,7 @@ impl App {
         }
     }
 
-    async fn convert_game(
+    pub(crate) async fn convert_game(
         game_db: ::spel_katalog_native::Pool,
         game: NativeGame,
         thumb: Option<DynamicImage>,
@@ -181,6 +311,20 @@ impl App {
             QuickMessage::OpenInstaller => {
                 return self.open_installer(None);
             }
+            QuickMessage::Screenshot => {
+                let Some(id) = self.find_windows(WindowType::is_main).next() else {
+                    self.set_status("no main window to take screenshot of");
+                    return Task::none();
+                };
+                return ::iced_runtime::window::screenshot(id).map(Message::Screenshot);
+            }
+            QuickMessage::OpenQuickLaunch => {
+                self.quick_launch.get_or_insert_default();
+                return ::iced::widget::operation::focus(QUICK_LAUNCH_INPUT);
+            }
+            QuickMessage::CloseQuickLaunch => {
+                self.quick_launch = None;
+            }
             QuickMessage::CopyFilter => {
                 return ::iced_runtime::clipboard::write(self.filter.clone());
             }
@@ -193,8 +337,9 @@ impl App {
                     ::log::error!("could not get config file path for \"games.db\"");
                     return Task::none();
                 };
+                let opener = self.settings.get::<Opener>().clone();
                 return Task::<Option<_>>::future(::smol::unblock(move || {
-                    if let Err(err) = ::open::that_detached(&path) {
+                    if let Err(err) = opener.open(&path) {
                         ::log::error!("failed to open {path:?}\n{err}");
                     }
                     None
@@ -202,8 +347,13 @@ impl App {
                 .and_then(Task::done);
             }
             QuickMessage::ReloadGames => {
-                ::log::info!("reloading games");
-                self.games.clear();
+                if self.games.all().is_empty() {
+                    ::log::info!("loading games");
+                    self.set_status("loading games");
+                } else {
+                    ::log::info!("reloading games");
+                    self.set_status("reloading games");
+                }
                 let load_lutris = || {
                     self.settings
                         .get::<LutrisDb>()
@@ -216,13 +366,15 @@ impl App {
 
                 let load_native = || {
                     let games_db = self.games_db.clone();
+                    let busy = self.busy.start("loading native games");
                     Task::future(::smol::unblock(move || {
+                        let _busy = busy;
                         let mut games = Vec::new();
                         games_db.gather(&mut |uuid, game| {
                             games.push((uuid, game));
                         });
                         Message::Games(OrRequest::Message(
-                            ::spel_katalog_games::Message::AddNativeGames { games },
+                            ::spel_katalog_games::Message::SetNativeGames { games },
                         ))
                     }))
                 };
@@ -236,6 +388,21 @@ impl App {
 
                 return load_db;
             }
+            QuickMessage::ImportDirectory => {
+                return Task::future(self.stage_import())
+                    .and_then(|pending| Task::done(Message::StageImport(pending)));
+            }
+            QuickMessage::ImportCoverUrls => {
+                return Task::future(self.pick_cover_urls()).and_then(|urls| {
+                    ::spel_katalog_games::Message::ImportRemoteCovers { urls }
+                        .into_message()
+                        .pipe(Message::Games)
+                        .pipe(Task::done)
+                });
+            }
+            QuickMessage::ExportBatch => {
+                return Task::future(self.export_batch()).then(|_| Task::none());
+            }
             QuickMessage::ConvertAll => {
                 let future = self.convert_all();
                 return Task::future(async move {
@@ -283,6 +450,14 @@ impl App {
                 self.set_status(format!("cycled filter mode to {next}"));
                 self.sort_games();
             }
+            QuickMessage::ToggleThumbnails => {
+                let hidden = self.games.toggle_thumbnails();
+                self.set_status(if hidden {
+                    "hid thumbnails"
+                } else {
+                    "showing thumbnails"
+                });
+            }
             QuickMessage::ToggleNetwork => {
                 let next = self.settings.get::<Network>().cycle();
                 self.settings.apply_from(next);
@@ -309,14 +484,35 @@ impl App {
                 }
             }
             QuickMessage::ToggleSettings => {
+                let refresh_cache_size = Task::done(Message::Settings(
+                    ::spel_katalog_settings_view::Message::RefreshCacheSize,
+                ));
+                return self
+                    .toggle_window(
+                        |t| t.is_settings(),
+                        || WindowType::Settings,
+                        WindowToggleSettings {
+                            window_settings: Some(&|| window::Settings {
+                                size: Size {
+                                    width: 350.0,
+                                    height: 700.0,
+                                },
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                    )
+                    .chain(refresh_cache_size);
+            }
+            QuickMessage::ToggleAbout => {
                 return self.toggle_window(
-                    |t| t.is_settings(),
-                    || WindowType::Settings,
+                    |t| t.is_about(),
+                    || WindowType::About,
                     WindowToggleSettings {
                         window_settings: Some(&|| window::Settings {
                             size: Size {
-                                width: 350.0,
-                                height: 700.0,
+                                width: 400.0,
+                                height: 200.0,
                             },
                             ..Default::default()
                         }),
@@ -411,6 +607,9 @@ impl App {
             ::spel_katalog_games::Request::InstallGame => {
                 return self.open_installer(None);
             }
+            ::spel_katalog_games::Request::ShowOutput(game_id) => {
+                return self.show_output(game_id);
+            }
         }
         Task::none()
     }
@@ -470,6 +669,32 @@ impl App {
                     &self.games_db,
                 )
                 .map(Message::Games),
+            ::spel_katalog_info::Request::SetFavorite { id, favorite } => self
+                .games
+                .update(
+                    ::spel_katalog_games::Message::SetFavorites {
+                        ids: vec![id],
+                        favorite,
+                    },
+                    &self.sender,
+                    &self.settings,
+                    &self.filter,
+                    &self.games_db,
+                )
+                .map(Message::Games),
+            ::spel_katalog_info::Request::SetPinned { id, pinned } => self
+                .games
+                .update(
+                    ::spel_katalog_games::Message::SetPinned {
+                        ids: vec![id],
+                        pinned,
+                    },
+                    &self.sender,
+                    &self.settings,
+                    &self.filter,
+                    &self.games_db,
+                )
+                .map(Message::Games),
             ::spel_katalog_info::Request::RunGame { id, sandbox } => {
                 self.run_game(id, Safety::from(sandbox), false)
             }
@@ -560,9 +785,18 @@ impl App {
                 return Task::none();
             }
             Message::Filter(filter) => {
+                // Clearing more than a single character at once commits the cleared filter.
+                if filter.is_empty() && self.filter.chars().nth(1).is_some() {
+                    self.filter_history.push(&self.filter);
+                }
                 self.filter = filter;
                 self.games.sort(&self.settings, &self.filter);
             }
+            Message::CommitFilter => {
+                // Filter input captures enter, as such running selected game is done here.
+                self.filter_history.push(&self.filter);
+                return self.quick_update(QuickMessage::RunSelected);
+            }
             Message::Settings(message) => {
                 let should_re_sort = Self::should_re_sort(&message);
                 let task = self
@@ -570,6 +804,10 @@ impl App {
                     .update(message, &self.sender)
                     .map(Message::Settings);
 
+                self.settings.get::<LogLevel>().apply();
+                self.terminal
+                    .set_limit(self.settings.get::<TerminalLines>().limit());
+
                 if should_re_sort {
                     self.sort_games();
                 }
@@ -577,7 +815,14 @@ impl App {
                 return task;
             }
             Message::View(message) => return self.view.update(message),
-            Message::Terminal(message) => return self.terminal.update(message).map(From::from),
+            Message::Terminal(::spel_katalog_terminal::Message::SavePipe { idx }) => {
+                return self.pick_pipe_file(idx);
+            }
+            Message::Terminal(message) => {
+                let task = self.terminal.update(message).map(From::from);
+                self.games.set_with_output(self.terminal.game_ids());
+                return task;
+            }
             Message::Games(message) => match message {
                 OrRequest::Message(message) => {
                     return self
@@ -610,41 +855,58 @@ impl App {
                 OrRequest::Request(request) => return self.info_request(request),
             },
 
+            Message::BulkAttrs(message) => return self.bulk_attrs_update(message),
             Message::ProcessInfo(process_infos) => {
                 self.process_list = process_infos;
             }
-            Message::Kill { pid, terminate } => {
-                let Ok(pid) = RawPid::try_from(pid) else {
-                    return Task::none();
-                };
-                let Some(pid) = Pid::from_raw(pid) else {
-                    return Task::none();
-                };
-
-                return Task::future(async move {
-                    let result = ::smol::unblock(move || {
-                        ::rustix::process::kill_process(
-                            pid,
-                            if terminate {
-                                ::rustix::process::Signal::TERM
-                            } else {
-                                ::rustix::process::Signal::KILL
-                            },
-                        )
-                    })
-                    .await;
-                    match result {
-                        Ok(_) => ::log::info!(
-                            "sent TERM to process {pid}",
-                            pid = pid.as_raw_nonzero().get()
-                        ),
-                        Err(err) => ::log::error!(
-                            "could not kill process {pid}\n{err}",
-                            pid = pid.as_raw_nonzero().get()
-                        ),
-                    };
-                })
-                .then(|_| Task::none());
+            Message::Kill {
+                pid,
+                terminate,
+                tree,
+            } => {
+                if self
+                    .settings
+                    .get::<ConfirmKill>()
+                    .requires_confirmation(terminate)
+                {
+                    self.pending_kill = Some(PendingKill { pid, tree });
+                } else {
+                    return kill_processes(pid, terminate, tree);
+                }
+            }
+            Message::ConfirmKill => {
+                if let Some(PendingKill { pid, tree }) = self.pending_kill.take() {
+                    return kill_processes(pid, false, tree);
+                }
+            }
+            Message::CancelKill => {
+                self.pending_kill = None;
+            }
+            Message::StageImport(pending) => {
+                self.pending_import = Some(pending);
+            }
+            Message::ToggleImport(idx) => {
+                if let Some((selected, _)) = self
+                    .pending_import
+                    .as_mut()
+                    .and_then(|pending| pending.games.get_mut(idx))
+                {
+                    *selected = !*selected;
+                }
+            }
+            Message::ConfirmImport => return self.import_staged(),
+            Message::CancelImport => {
+                self.pending_import = None;
+            }
+            Message::QuickLaunch(query) => {
+                if let Some(quick_launch) = &mut self.quick_launch {
+                    quick_launch.set_query(query, &self.games, &self.settings);
+                }
+            }
+            Message::LaunchQuick => {
+                if let Some(id) = self.quick_launch.take().and_then(QuickLaunch::submit) {
+                    return self.run_game(id, Safety::Sandbox, false);
+                }
             }
             Message::OpenWindow(id, window_type) => {
                 self.windows.insert(id, window_type);
@@ -653,10 +915,10 @@ impl App {
                 let closed = self.windows.remove(&id);
 
                 if self.windows.is_empty() || matches!(closed, Some(WindowType::Term)) {
-                    self.sink_builder = ::spel_katalog_sink::SinkBuilder::Inherit;
-                    return ::iced_runtime::exit();
+                    return self.shutdown();
                 }
             }
+            Message::Exit => return self.shutdown(),
             Message::Installer(id, msg) => {
                 if let Some(WindowType::Installer(installer)) = self.windows.get_mut(&id) {
                     return match msg {
@@ -686,6 +948,8 @@ impl App {
                 self.view.displayed = displayed;
                 self.view.show_info();
             }
+            Message::BusyTick => {}
+            Message::Screenshot(screenshot) => return self.save_screenshot(screenshot),
             Message::RunGameNative(game) => {
                 return self.run_native_game(*game, RunMode::Exe);
             }
@@ -696,3 +960,37 @@ impl App {
         Task::none()
     }
 }
+
+/// Send TERM, or KILL if not `terminate`, to a process and optionally all of its descendants.
+fn kill_processes(pid: i64, terminate: bool, tree: bool) -> Task<Message> {
+    let signal = if terminate {
+        ::rustix::process::Signal::TERM
+    } else {
+        ::rustix::process::Signal::KILL
+    };
+
+    Task::future(::smol::unblock(move || {
+        let pids = if tree {
+            ::spel_katalog_common::process_tree(::std::path::Path::new("/proc"), pid)
+        } else {
+            vec![pid]
+        };
+
+        for pid in pids {
+            let Some(pid) = RawPid::try_from(pid).ok().and_then(Pid::from_raw) else {
+                continue;
+            };
+            match ::rustix::process::kill_process(pid, signal) {
+                Ok(_) => ::log::info!(
+                    "sent {signal:?} to process {pid}",
+                    pid = pid.as_raw_nonzero().get()
+                ),
+                Err(err) => ::log::error!(
+                    "could not kill process {pid}\n{err}",
+                    pid = pid.as_raw_nonzero().get()
+                ),
+            };
+        }
+    }))
+    .then(|_| Task::none())
+}
@@ -12,7 +12,11 @@ use ::spel_katalog_common::styling;
 use ::spel_katalog_settings::Settings;
 use ::tap::Pipe;
 
-use crate::{Element, process_info::ProcessInfo};
+use crate::{
+    Element,
+    bulk_attrs::BulkAttrs,
+    process_info::{PendingKill, ProcessInfo},
+};
 
 #[derive(Debug, Default, Clone, Copy, Display, PartialEq, Eq, IsVariant, Hash)]
 pub enum Pane {
@@ -26,6 +30,7 @@ pub enum Displayed {
     #[default]
     GameInfo,
     Processes,
+    BulkAttrs,
 }
 
 #[derive(Debug)]
@@ -119,7 +124,11 @@ impl State {
             .spacing(3)
             .push(
                 widget::pick_list(
-                    [Displayed::GameInfo, Displayed::Processes],
+                    [
+                        Displayed::GameInfo,
+                        Displayed::Processes,
+                        Displayed::BulkAttrs,
+                    ],
                     Some(self.displayed),
                     Message::SetDisplayed,
                 )
@@ -165,6 +174,7 @@ impl State {
         self.titlebar(match self.displayed {
             Displayed::GameInfo => "No Game Selected",
             Displayed::Processes => "Processes",
+            Displayed::BulkAttrs => "Batch Attributes",
         })
     }
 
@@ -174,6 +184,8 @@ impl State {
         settings: &'app Settings,
         info: &'app spel_katalog_info::State,
         process_info: &'app [ProcessInfo],
+        pending_kill: Option<PendingKill>,
+        bulk_attrs: &'app BulkAttrs,
     ) -> Element<'app, crate::Message> {
         let style = |t: &_| styling::box_border(t).background(Color::WHITE.scale_alpha(0.025));
         match self.displayed {
@@ -213,11 +225,21 @@ impl State {
             Displayed::Processes => widget::Column::new()
                 .push(self.auto_titlebar())
                 .push(spel_katalog_widget::rule::horizontal())
-                .push(ProcessInfo::view_list(process_info))
+                .push(ProcessInfo::view_list(process_info, pending_kill))
+                .padding(5)
+                .spacing(3)
+                .pipe(widget::container)
+                .style(style)
+                .into(),
+            Displayed::BulkAttrs => widget::Column::new()
+                .push(self.auto_titlebar())
+                .push(spel_katalog_widget::rule::horizontal())
+                .push(bulk_attrs.view(games))
                 .padding(5)
                 .spacing(3)
                 .pipe(widget::container)
                 .style(style)
+                .height(Fill)
                 .into(),
         }
     }
@@ -227,6 +249,8 @@ impl State {
         games: &'app ::spel_katalog_games::State,
         info: &'app spel_katalog_info::State,
         process_info: &'app [ProcessInfo],
+        pending_kill: Option<PendingKill>,
+        bulk_attrs: &'app BulkAttrs,
         settings: &'app Settings,
     ) -> Element<'app, crate::Message> {
         widget::responsive(move |size| {
@@ -236,7 +260,14 @@ impl State {
                 pane_grid::Content::new(
                     match state {
                         Pane::Games => games.view(settings).map(crate::Message::from),
-                        Pane::GameInfo => self.view_info(games, settings, info, process_info),
+                        Pane::GameInfo => self.view_info(
+                            games,
+                            settings,
+                            info,
+                            process_info,
+                            pending_kill,
+                            bulk_attrs,
+                        ),
                     }
                     .pipe(widget::container),
                 )
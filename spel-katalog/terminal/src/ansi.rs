@@ -0,0 +1,155 @@
+//! Handling of ansi escape codes.
+
+use ::core::fmt::Display;
+
+use ::iced_core::Color;
+
+/// How ansi escape codes in output are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum AnsiMode {
+    /// Remove escape codes.
+    #[default]
+    Strip,
+    /// Render colors and attributes set by escape codes.
+    Render,
+}
+
+impl Display for AnsiMode {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            AnsiMode::Strip => f.write_str("Strip"),
+            AnsiMode::Render => f.write_str("Render"),
+        }
+    }
+}
+
+/// Style of a segment of output.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AnsiStyle {
+    /// Foreground color, [None] for default.
+    pub foreground: Option<Color>,
+    /// Background color, [None] for default.
+    pub background: Option<Color>,
+    /// Text is bold.
+    pub bold: bool,
+    /// Text is underlined.
+    pub underline: bool,
+}
+
+/// A line split into styled segments.
+pub type Segments = Vec<(AnsiStyle, String)>;
+
+/// Standard and bright colors of the 256 color palette.
+const BASE_COLORS: [Color; 16] = [
+    Color::from_rgb8(0x00, 0x00, 0x00),
+    Color::from_rgb8(0xCD, 0x31, 0x31),
+    Color::from_rgb8(0x0D, 0xBC, 0x79),
+    Color::from_rgb8(0xE5, 0xE5, 0x10),
+    Color::from_rgb8(0x24, 0x72, 0xC8),
+    Color::from_rgb8(0xBC, 0x3F, 0xBC),
+    Color::from_rgb8(0x11, 0xA8, 0xCD),
+    Color::from_rgb8(0xE5, 0xE5, 0xE5),
+    Color::from_rgb8(0x66, 0x66, 0x66),
+    Color::from_rgb8(0xF1, 0x4C, 0x4C),
+    Color::from_rgb8(0x23, 0xD1, 0x8B),
+    Color::from_rgb8(0xF5, 0xF5, 0x43),
+    Color::from_rgb8(0x3B, 0x8E, 0xEA),
+    Color::from_rgb8(0xD6, 0x70, 0xD6),
+    Color::from_rgb8(0x29, 0xB8, 0xDB),
+    Color::from_rgb8(0xFF, 0xFF, 0xFF),
+];
+
+/// Get a color of the 256 color palette.
+fn palette_color(idx: u16) -> Option<Color> {
+    let idx = u8::try_from(idx).ok()?;
+    Some(match idx {
+        0..16 => BASE_COLORS[usize::from(idx)],
+        16..232 => {
+            let level = |value: u8| if value == 0 { 0 } else { value * 40 + 55 };
+            let idx = idx - 16;
+            Color::from_rgb8(level(idx / 36), level(idx / 6 % 6), level(idx % 6))
+        }
+        232.. => {
+            let gray = (idx - 232) * 10 + 8;
+            Color::from_rgb8(gray, gray, gray)
+        }
+    })
+}
+
+/// Parse an extended color, `5;n` or `2;r;g;b`, from the values following 38 or 48.
+fn extended_color(values: &mut impl Iterator<Item = u16>) -> Option<Color> {
+    match values.next()? {
+        5 => palette_color(values.next()?),
+        2 => {
+            let mut channel = || u8::try_from(values.next()?).ok();
+            let (r, g, b) = (channel()?, channel()?, channel()?);
+            Some(Color::from_rgb8(r, g, b))
+        }
+        _ => None,
+    }
+}
+
+impl AnsiStyle {
+    /// Apply sgr parameters to style.
+    fn apply_sgr(&mut self, params: &::vte::Params) {
+        if params.is_empty() {
+            *self = Self::default();
+            return;
+        }
+
+        let mut values = params.iter().flatten().copied();
+
+        while let Some(value) = values.next() {
+            match value {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                22 => self.bold = false,
+                4 => self.underline = true,
+                24 => self.underline = false,
+                30..=37 => self.foreground = palette_color(value - 30),
+                90..=97 => self.foreground = palette_color(value - 90 + 8),
+                38 => self.foreground = extended_color(&mut values),
+                39 => self.foreground = None,
+                40..=47 => self.background = palette_color(value - 40),
+                100..=107 => self.background = palette_color(value - 100 + 8),
+                48 => self.background = extended_color(&mut values),
+                49 => self.background = None,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Split a line containing ansi escape codes into styled segments.
+pub fn styled_segments(line: &str) -> Segments {
+    #[derive(Debug, Default)]
+    struct Styler {
+        style: AnsiStyle,
+        segments: Segments,
+    }
+
+    impl ::vte::Perform for Styler {
+        fn print(&mut self, c: char) {
+            match self.segments.last_mut() {
+                Some((style, text)) if *style == self.style => text.push(c),
+                _ => self.segments.push((self.style, String::from(c))),
+            }
+        }
+
+        fn csi_dispatch(
+            &mut self,
+            params: &::vte::Params,
+            _intermediates: &[u8],
+            _ignore: bool,
+            action: char,
+        ) {
+            if action == 'm' {
+                self.style.apply_sgr(params);
+            }
+        }
+    }
+
+    let mut styler = Styler::default();
+    ::vte::Parser::new().advance(&mut styler, line.as_bytes());
+    styler.segments
+}
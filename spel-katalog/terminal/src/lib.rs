@@ -6,24 +6,61 @@ use ::core::{
     fmt::{Debug, Display},
     mem,
     num::NonZero,
+    ops::Range,
 };
 use ::std::{
     borrow::Cow,
     collections::VecDeque,
     io::{ErrorKind, PipeReader, Read},
+    path::PathBuf,
     rc::Rc,
     sync::{Arc, Mutex},
+    time::SystemTime,
 };
 
-use ::iced_core::{Alignment::Center, Length::Fill};
+use ::iced_core::{Alignment::Center, Color, Length::Fill, font};
 use ::iced_runtime::Task;
 use ::iced_widget as widget;
 use ::spel_katalog_common::in_place::PushMaybe as _;
 use ::spel_katalog_sink::SinkIdentity;
 
+use crate::ansi::styled_segments;
+
+pub use crate::ansi::{AnsiMode, AnsiStyle, Segments};
+
+mod ansi;
+
 /// Element alias.
 type Element<'a, M> = ::iced_core::Element<'a, M, ::iced_core::Theme, ::iced_renderer::Renderer>;
 
+/// A displayed line, with how many times it was repeated, when it was last completed and
+/// its styled segments if rendering ansi escape codes.
+type Line = (NonZero<usize>, Option<SystemTime>, String, Option<Segments>);
+
+/// Id of scrollable showing lines.
+const LINES_ID: &str = "terminal-lines";
+
+/// Find byte ranges of non-overlapping matches of `query` in `line`.
+///
+/// Case insensitive matching only folds ascii characters.
+fn find_matches(line: &str, query: &str, case_sensitive: bool) -> Vec<Range<usize>> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let ranges = |line: &str, query: &str| {
+        line.match_indices(query)
+            .map(|(idx, found)| idx..idx + found.len())
+            .collect()
+    };
+
+    if case_sensitive {
+        ranges(line, query)
+    } else {
+        ranges(&line.to_ascii_lowercase(), &query.to_ascii_lowercase())
+    }
+}
+
 /// Remove ansi escape codes from input.
 fn without_ansi_escapes(bytes: Cow<'_, str>) -> Cow<'_, str> {
     #[derive(Debug)]
@@ -53,16 +90,26 @@ fn without_ansi_escapes(bytes: Cow<'_, str>) -> Cow<'_, str> {
     Cow::Owned(output)
 }
 
-/// Extend a string lossily from bytes.
-fn extend_lossy(buf: &mut String, bytes: &[u8]) {
-    for chunk in bytes.utf8_chunks() {
-        buf.push_str(chunk.valid());
-        let count = chunk.invalid().len();
-        buf.reserve(count);
-        for _ in 0..count {
-            buf.push('\u{FFFD}');
-        }
+/// Trim content from the front once it exceeds `byte_limit`, down to 3/4 of it such that
+/// content is not trimmed again for every addition.
+///
+/// Whole lines are dropped where possible, otherwise content is cut at the
+/// first character boundary within limit. Returns amount of bytes removed.
+fn trim_front(content: &mut Vec<u8>, byte_limit: usize) -> usize {
+    if content.len() <= byte_limit {
+        return 0;
     }
+    let excess = content.len() - (byte_limit - byte_limit / 4);
+
+    let cut = match ::memchr::memchr(b'\n', &content[excess - 1..]) {
+        Some(idx) => excess + idx,
+        None => (excess..content.len())
+            .find(|&idx| content[idx] & 0b1100_0000 != 0b1000_0000)
+            .unwrap_or(content.len()),
+    };
+
+    content.drain(..cut);
+    cut
 }
 
 /// Messages used by terminal.
@@ -78,26 +125,64 @@ pub enum Message {
     /// Close a pipe.
     ClosePipe {
         #[doc(hidden)]
-        idx: Private<usize>,
+        key: Private<usize>,
     },
     /// Add content.
     AddContent {
         #[doc(hidden)]
-        idx: Private<usize>,
+        key: Private<usize>,
         #[doc(hidden)]
         content: Private<Vec<u8>>,
     },
-    /// Set current viewed output.
+    /// Set current viewed output, pinning it if not [None].
     SetCurrent {
         /// Index of output.
         idx: Option<usize>,
     },
+    /// Switch to a new pipe unless another pipe is pinned.
+    AutoSwitch {
+        #[doc(hidden)]
+        key: Private<usize>,
+    },
+    /// Set whether to switch to new pipes automatically.
+    SetAutoSwitch(bool),
+    /// Set whether to keep view scrolled to newest output.
+    SetFollow(bool),
+    /// Set whether to show when lines were received.
+    SetTimestamps(bool),
     /// Set wrapping used.
     SetWrap(Wrap),
     /// Attempt to set line count.
     SetLineCount(String),
     /// Set text size.
     SetTextSize(u16),
+    /// Set search query, only lines matching it are shown if not empty.
+    SetSearch(String),
+    /// Set whether search is case sensitive.
+    SetCaseSensitive(bool),
+    /// Set how ansi escape codes are handled.
+    SetAnsiMode(AnsiMode),
+    /// Save complete content of pipe to a file chosen by user, picking the file is left to
+    /// the application which should answer with [Message::SavePipeTo].
+    SavePipe {
+        /// Index of pipe.
+        idx: usize,
+    },
+    /// Save complete content of pipe to given path.
+    SavePipeTo {
+        /// Index of pipe.
+        idx: usize,
+        /// Path to save content to.
+        path: PathBuf,
+    },
+    /// Result of saving a pipe, the path saved to or an error.
+    PipeSaved(Result<PathBuf, String>),
+    /// Discard received content of current pipe, the pipe keeps receiving content.
+    ClearCurrent,
+    /// Scroll to next match.
+    NextMatch,
+    /// Scroll to previous match.
+    PrevMatch,
 }
 
 impl Message {
@@ -197,14 +282,52 @@ impl<T> Clone for ClonePkgLock<T> {
 /// Storage for data received from pipe.
 #[derive(Debug)]
 struct Pipe {
+    /// Stable key of pipe, unaffected by eviction of other pipes.
+    key: usize,
     /// Id to display for this data.
     identity: String,
+    /// Identity of sink pipe was created for.
+    source: SinkIdentity,
     /// All received content.
     content: Vec<u8>,
+    /// Offsets of line endings in content and when they were received, kept when timestamping.
+    line_times: VecDeque<(usize, SystemTime)>,
     /// If the pipe is still open.
     open: bool,
 }
 
+impl Pipe {
+    /// Trim content such that it fits within `byte_limit`, returns true if content was trimmed.
+    fn trim(&mut self, byte_limit: usize) -> bool {
+        let removed = trim_front(&mut self.content, byte_limit);
+        if removed == 0 {
+            return false;
+        }
+
+        while self
+            .line_times
+            .front()
+            .is_some_and(|(end, _)| *end < removed)
+        {
+            self.line_times.pop_front();
+        }
+        for (end, _) in &mut self.line_times {
+            *end -= removed;
+        }
+
+        true
+    }
+
+    /// Get time line ending at given offset was received.
+    fn line_time(&self, end: usize) -> Option<SystemTime> {
+        let idx = self
+            .line_times
+            .binary_search_by_key(&end, |(end, _)| *end)
+            .ok()?;
+        self.line_times.get(idx).map(|(_, time)| *time)
+    }
+}
+
 /// Id of data pipe.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct PipeId<'s> {
@@ -276,8 +399,14 @@ impl From<Wrap> for widget::text::Wrapping {
 pub struct Terminal {
     /// Received data/pipes.
     pipes: Vec<Pipe>,
+    /// Key to give next added pipe.
+    next_key: usize,
+    /// How many pipes to keep at most.
+    max_pipes: usize,
+    /// How many bytes of content to keep at most per pipe.
+    byte_limit: Option<usize>,
     /// Currently displayed lines.
-    lines: VecDeque<(NonZero<usize>, String)>,
+    lines: VecDeque<Line>,
     /// Current pipe.
     current: Option<usize>,
     /// How to wrap content.
@@ -292,12 +421,35 @@ pub struct Terminal {
     limit_text: String,
     /// Text size to use.
     text_size: u16,
+    /// Switch to new pipes when they are added.
+    auto_switch: bool,
+    /// Current pipe was selected by user.
+    pinned: bool,
+    /// Keep view scrolled to newest output.
+    follow: bool,
+    /// Show when lines were received.
+    timestamps: bool,
+    /// Current search query.
+    search: String,
+    /// Search is case sensitive.
+    case_sensitive: bool,
+    /// Index of line with current match.
+    current_match: Option<usize>,
+    /// Last displayed line is the incomplete last line of current pipe.
+    partial: bool,
+    /// How ansi escape codes are handled.
+    ansi_mode: AnsiMode,
+    /// Outcome of last save of a pipe.
+    save_status: Option<String>,
 }
 
 impl Default for Terminal {
     fn default() -> Self {
         Self {
             pipes: Default::default(),
+            next_key: 0,
+            max_pipes: usize::MAX,
+            byte_limit: None,
             lines: Default::default(),
             current: Default::default(),
             wrap: Default::default(),
@@ -306,6 +458,16 @@ impl Default for Terminal {
             current_limit: Default::default(),
             limit_text: Default::default(),
             text_size: 14,
+            auto_switch: true,
+            pinned: false,
+            follow: true,
+            timestamps: false,
+            search: String::new(),
+            case_sensitive: false,
+            current_match: None,
+            partial: false,
+            ansi_mode: AnsiMode::Strip,
+            save_status: None,
         }
     }
 }
@@ -320,6 +482,234 @@ impl Terminal {
         }
     }
 
+    /// Set default value for how many lines to display at most.
+    ///
+    /// Lines are refreshed if the limit is in use and changed.
+    pub fn set_limit(&mut self, limit: u16) {
+        if self.limit == limit {
+            return;
+        }
+
+        self.limit = limit;
+        self.limit_placeholder = limit.to_string();
+        if self.current_limit.is_none() {
+            self.refresh();
+        }
+    }
+
+    /// Get a terminal keeping at most the given amount of bytes of content per pipe.
+    ///
+    /// When exceeded content is trimmed from the front down to 3/4 of the limit, dropping
+    /// whole lines where possible.
+    pub fn with_byte_limit(self, bytes: usize) -> Self {
+        Self {
+            byte_limit: Some(bytes),
+            ..self
+        }
+    }
+
+    /// Get a terminal keeping at most the given amount of pipes.
+    ///
+    /// When exceeded the oldest closed pipe is evicted, or the oldest pipe if
+    /// none are closed.
+    pub fn with_max_pipes(self, max_pipes: usize) -> Self {
+        Self {
+            max_pipes: max_pipes.max(1),
+            ..self
+        }
+    }
+
+    /// Amount of pipes currently kept.
+    pub const fn pipe_count(&self) -> usize {
+        self.pipes.len()
+    }
+
+    /// Index of currently viewed pipe.
+    pub const fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Would a newly added pipe be switched to.
+    pub const fn switches_to_new_pipe(&self) -> bool {
+        self.auto_switch && !(self.pinned && self.current.is_some())
+    }
+
+    /// Is view kept scrolled to newest output.
+    pub const fn follows(&self) -> bool {
+        self.follow
+    }
+
+    /// Is it shown when lines were received.
+    pub const fn timestamps(&self) -> bool {
+        self.timestamps
+    }
+
+    /// Find index of pipe created for given sink identity.
+    /// If multiple exist the latest open one is preferred, then the latest closed one.
+    pub fn find_pipe(&self, identity: &SinkIdentity) -> Option<usize> {
+        let mut matching = self
+            .pipes
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, pipe)| pipe.source == *identity);
+        let latest = matching.clone().next().map(|(idx, _)| idx);
+
+        matching
+            .find(|(_, pipe)| pipe.open)
+            .map(|(idx, _)| idx)
+            .or(latest)
+    }
+
+    /// Find index of pipe with output of game with given lutris id.
+    pub fn game_pipe(&self, game_id: i64) -> Option<usize> {
+        self.find_pipe(&SinkIdentity::GameId(game_id))
+    }
+
+    /// Lutris ids of games with output available.
+    pub fn game_ids(&self) -> impl Iterator<Item = i64> {
+        self.pipes.iter().filter_map(|pipe| match pipe.source {
+            SinkIdentity::GameId(game_id) => Some(game_id),
+            _ => None,
+        })
+    }
+
+    /// Current search query.
+    pub fn search(&self) -> &str {
+        &self.search
+    }
+
+    /// Currently displayed lines.
+    pub fn lines(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        self.lines.iter().map(|(_, _, line, _)| line.as_str())
+    }
+
+    /// When currently displayed lines were received, [None] for lines received before
+    /// timestamps were enabled.
+    pub fn line_times(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = Option<SystemTime>> + ExactSizeIterator {
+        self.lines.iter().map(|(_, time, ..)| *time)
+    }
+
+    /// Styled segments of currently displayed lines, empty unless rendering ansi escape codes.
+    pub fn segments(&self) -> impl DoubleEndedIterator<Item = &[(AnsiStyle, String)]> {
+        self.lines
+            .iter()
+            .filter_map(|(.., segments)| segments.as_deref())
+    }
+
+    /// Complete content received by pipe with given index.
+    pub fn pipe_content(&self, idx: usize) -> Option<&[u8]> {
+        self.pipes.get(idx).map(|pipe| pipe.content.as_slice())
+    }
+
+    /// Outcome of last save of a pipe.
+    pub fn save_status(&self) -> Option<&str> {
+        self.save_status.as_deref()
+    }
+
+    /// Suggested file name when saving pipe with given index.
+    pub fn pipe_file_name(&self, idx: usize) -> Option<String> {
+        let pipe = self.pipes.get(idx)?;
+        let file_name = pipe
+            .identity
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .chain(".log".chars())
+            .collect();
+        Some(file_name)
+    }
+
+    /// Save content of pipe with given index to given path.
+    fn save_pipe(&self, idx: usize, path: PathBuf) -> Task<Message> {
+        let Some(pipe) = self.pipes.get(idx) else {
+            ::log::warn!("cannot save unavailable pipe {idx}");
+            return Task::none();
+        };
+        let content = pipe.content.clone();
+
+        Task::future(async move {
+            let result = ::smol::fs::write(&path, content)
+                .await
+                .map(|()| path.clone())
+                .map_err(|err| {
+                    ::log::error!("could not write {path:?}\n{err}");
+                    format!("could not write {path:?}")
+                });
+            Message::PipeSaved(result)
+        })
+    }
+
+    /// Amount of lines matching search, 0 if not searching.
+    pub fn match_count(&self) -> usize {
+        if self.search.is_empty() {
+            0
+        } else {
+            self.lines.len()
+        }
+    }
+
+    /// Index of line with current match.
+    pub const fn current_match(&self) -> Option<usize> {
+        self.current_match
+    }
+
+    /// Move current match by given step, wrapping around, and scroll to it.
+    fn step_match(&mut self, forward: bool) -> Task<Message> {
+        let count = self.match_count();
+        if count == 0 {
+            return Task::none();
+        }
+
+        let idx = match (self.current_match, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(idx), true) => (idx + 1) % count,
+            (Some(idx), false) => idx.checked_sub(1).unwrap_or(count - 1),
+        };
+        self.current_match = Some(idx);
+
+        let ratio = if count > 1 {
+            idx as f32 / (count - 1) as f32
+        } else {
+            0.0
+        };
+        // Offsets of the bottom anchored scrollable are relative to the bottom.
+        ::iced_runtime::widget::operation::snap_to(
+            LINES_ID,
+            widget::scrollable::RelativeOffset {
+                x: 0.0,
+                y: 1.0 - ratio,
+            },
+        )
+    }
+
+    /// Get index of pipe with given key.
+    fn pipe_idx(&self, key: usize) -> Option<usize> {
+        self.pipes.iter().position(|pipe| pipe.key == key)
+    }
+
+    /// Evict the oldest closed pipe, or the oldest pipe if none are closed.
+    fn evict(&mut self) {
+        let idx = self.pipes.iter().position(|pipe| !pipe.open).unwrap_or(0);
+        if idx >= self.pipes.len() {
+            return;
+        }
+
+        let pipe = self.pipes.remove(idx);
+        ::log::info!("evicted terminal pipe {}", pipe.identity);
+
+        match self.current {
+            Some(current) if current == idx => {
+                self.pinned = false;
+                self.set_current(None);
+            }
+            Some(current) if current > idx => self.current = Some(current - 1),
+            _ => {}
+        }
+    }
+
     /// Update state of terminal.
     pub fn update(&mut self, msg: Message) -> Task<Message> {
         match msg {
@@ -329,13 +719,22 @@ impl Terminal {
                 };
                 let (tx, rx) = ::flume::bounded(64);
 
+                while self.pipes.len() >= self.max_pipes {
+                    self.evict();
+                }
+
+                let key = self.next_key;
+                self.next_key = key.wrapping_add(1);
+
                 let pipe = Pipe {
+                    key,
                     identity: identity.to_string(),
+                    source: identity.clone(),
                     content: Vec::new(),
+                    line_times: VecDeque::new(),
                     open: true,
                 };
 
-                let idx = self.pipes.len();
                 self.pipes.push(pipe);
 
                 let close_task = Task::future(::smol::unblock(move || {
@@ -358,41 +757,72 @@ impl Terminal {
                     if let Err(err) = result {
                         ::log::error!("task reading pipe {identity} failed\n{err}");
                     }
-                    Task::done(Message::ClosePipe { idx: Private(idx) })
+                    Task::done(Message::ClosePipe { key: Private(key) })
                 });
 
                 let content_task =
                     Task::stream(rx.into_stream()).map(move |content| Message::AddContent {
-                        idx: Private(idx),
+                        key: Private(key),
                         content: Private(content),
                     });
 
-                let set_current = Task::done(Message::SetCurrent { idx: Some(idx) });
+                let auto_switch = Task::done(Message::AutoSwitch { key: Private(key) });
 
-                Task::batch([close_task, content_task, set_current])
+                Task::batch([close_task, content_task, auto_switch])
             }
-            Message::ClosePipe { idx: Private(idx) } => {
-                if let Some(pipe) = self.pipes.get_mut(idx) {
+            Message::ClosePipe { key: Private(key) } => {
+                if let Some(pipe) = self.pipes.iter_mut().find(|pipe| pipe.key == key) {
                     pipe.open = false;
                 }
                 Task::none()
             }
             Message::AddContent {
-                idx: Private(idx),
+                key: Private(key),
                 content: Private(new_content),
             } => {
+                let Some(idx) = self.pipe_idx(key) else {
+                    return Task::none();
+                };
                 self.add_content(idx, new_content);
-                Task::none()
+
+                // Stepping through matches suppresses following.
+                if self.follow && self.current == Some(idx) && self.current_match.is_none() {
+                    ::iced_runtime::widget::operation::snap_to_end(LINES_ID)
+                } else {
+                    Task::none()
+                }
             }
             Message::SetWrap(wrap) => {
                 self.wrap = wrap;
                 Task::none()
             }
             Message::SetCurrent { idx } => {
-                if self.current != idx {
-                    self.current = idx;
-                    self.refresh();
+                self.pinned = idx.is_some();
+                self.set_current(idx);
+                Task::none()
+            }
+            Message::AutoSwitch { key: Private(key) } => {
+                if self.switches_to_new_pipe()
+                    && let Some(idx) = self.pipe_idx(key)
+                {
+                    self.set_current(Some(idx));
+                }
+                Task::none()
+            }
+            Message::SetAutoSwitch(auto_switch) => {
+                self.auto_switch = auto_switch;
+                Task::none()
+            }
+            Message::SetFollow(follow) => {
+                self.follow = follow;
+                if follow {
+                    ::iced_runtime::widget::operation::snap_to_end(LINES_ID)
+                } else {
+                    Task::none()
                 }
+            }
+            Message::SetTimestamps(timestamps) => {
+                self.timestamps = timestamps;
                 Task::none()
             }
             Message::SetLineCount(count) => {
@@ -403,32 +833,126 @@ impl Terminal {
                 self.text_size = size.clamp(7, 36);
                 Task::none()
             }
+            Message::SetSearch(search) => {
+                if self.search != search {
+                    self.search = search;
+                    self.current_match = None;
+                    self.refresh();
+                }
+                Task::none()
+            }
+            Message::SetCaseSensitive(case_sensitive) => {
+                if self.case_sensitive != case_sensitive {
+                    self.case_sensitive = case_sensitive;
+                    self.current_match = None;
+                    self.refresh();
+                }
+                Task::none()
+            }
+            Message::SetAnsiMode(ansi_mode) => {
+                if self.ansi_mode != ansi_mode {
+                    self.ansi_mode = ansi_mode;
+                    self.refresh();
+                }
+                Task::none()
+            }
+            // Picking a file is handled by application.
+            Message::SavePipe { .. } => Task::none(),
+            Message::SavePipeTo { idx, path } => self.save_pipe(idx, path),
+            Message::PipeSaved(result) => {
+                self.save_status = Some(match result {
+                    Ok(path) => format!("saved to {path:?}"),
+                    Err(err) => err,
+                });
+                Task::none()
+            }
+            Message::ClearCurrent => {
+                if let Some(idx) = self.current {
+                    self.clear_pipe(idx);
+                }
+                Task::none()
+            }
+            Message::NextMatch => self.step_match(true),
+            Message::PrevMatch => self.step_match(false),
+        }
+    }
+
+    /// Set current pipe, refreshing lines if changed.
+    fn set_current(&mut self, idx: Option<usize>) {
+        if self.current != idx {
+            self.current = idx;
+            self.refresh();
+        }
+    }
+
+    /// Discard received content of pipe with given index.
+    fn clear_pipe(&mut self, idx: usize) {
+        let Some(pipe) = self.pipes.get_mut(idx) else {
+            return;
+        };
+
+        pipe.content.clear();
+        pipe.line_times.clear();
+
+        if self.current == Some(idx) {
+            self.refresh();
         }
     }
 
-    /// Add line to back of deque,
-    fn add_line_back(lines: &mut VecDeque<(NonZero<usize>, String)>, line: Cow<str>) {
-        if let Some((count, last)) = lines.back_mut()
+    /// Add line to back of deque, a merged line keeps the latest time.
+    ///
+    /// If styled is given the added line is split into segments styled by it.
+    fn add_line_back(
+        lines: &mut VecDeque<Line>,
+        line: Cow<str>,
+        styled: Option<&str>,
+        time: Option<SystemTime>,
+    ) {
+        if let Some((count, last_time, last, _)) = lines.back_mut()
             && last.as_str() == line
         {
             *count = count.saturating_add(1);
+            *last_time = time.or(*last_time);
         } else {
-            lines.push_back((const { NonZero::new(1).unwrap() }, line.into_owned()));
+            lines.push_back((
+                const { NonZero::new(1).unwrap() },
+                time,
+                line.into_owned(),
+                styled.map(styled_segments),
+            ));
         }
     }
 
-    /// Add line to front of deque.
-    fn add_line_front(lines: &mut VecDeque<(NonZero<usize>, String)>, line: Cow<str>) {
-        if let Some((count, first)) = lines.front_mut()
+    /// Add line to front of deque, returns true if it was not merged with the first line.
+    ///
+    /// A merged line keeps the latest time. If styled is given the added line is split into
+    /// segments styled by it.
+    fn add_line_front(
+        lines: &mut VecDeque<Line>,
+        line: Cow<str>,
+        styled: Option<&str>,
+        time: Option<SystemTime>,
+    ) -> bool {
+        if let Some((count, first_time, first, _)) = lines.front_mut()
             && first.as_str() == line
         {
             *count = count.saturating_add(1);
+            *first_time = first_time.or(time);
+            false
         } else {
-            lines.push_front((const { NonZero::new(1).unwrap() }, line.into_owned()));
+            lines.push_front((
+                const { NonZero::new(1).unwrap() },
+                time,
+                line.into_owned(),
+                styled.map(styled_segments),
+            ));
+            true
         }
     }
 
     /// Refresh lines from byte content.
+    ///
+    /// If searching only lines matching the search are kept.
     fn refresh(&mut self) {
         let Self {
             pipes,
@@ -436,10 +960,17 @@ impl Terminal {
             current,
             limit,
             current_limit,
+            search,
+            case_sensitive,
+            current_match,
+            partial,
+            ansi_mode,
             ..
         } = self;
 
         lines.clear();
+        *partial = false;
+        let previous_match = current_match.take();
 
         let Some(current) = *current else {
             return;
@@ -451,78 +982,138 @@ impl Terminal {
 
         let mut content = pipe.content.as_slice();
         let limit = usize::from(current_limit.unwrap_or(*limit));
+        let mut splits = ::memchr::memrchr_iter(b'\n', &pipe.content);
+        // Offset of line ending of the next line to add.
+        let mut end = None;
+
+        while lines.len() < limit {
+            let (bytes, line_end) = match splits.next() {
+                Some(split_at) => (
+                    &content
+                        .split_off(split_at..)
+                        .expect("range should be in bounds")[1..],
+                    end.replace(split_at),
+                ),
+                None if !content.is_empty() => (mem::take(&mut content), end.take()),
+                None => break,
+            };
+            // Nothing follows the last line ending.
+            if line_end.is_none() && bytes.is_empty() {
+                continue;
+            }
+            let time = line_end.and_then(|line_end| pipe.line_time(line_end));
+            let raw = String::from_utf8_lossy(bytes);
+            let line = without_ansi_escapes(raw.clone());
 
-        for split_at in ::memchr::memrchr_iter(b'\n', &pipe.content) {
-            let bytes = &content
-                .split_off(split_at..)
-                .expect("range should be in bounds")[1..];
-            Self::add_line_front(lines, without_ansi_escapes(String::from_utf8_lossy(bytes)));
+            if !search.is_empty() && find_matches(&line, search, *case_sensitive).is_empty() {
+                continue;
+            }
+
+            if lines.is_empty() {
+                *partial = line_end.is_none();
+            }
+            let styled = (*ansi_mode == AnsiMode::Render).then_some(raw.as_ref());
+            Self::add_line_front(lines, line, styled, time);
+        }
+
+        *current_match = previous_match.filter(|idx| *idx < lines.len());
+    }
+
+    /// Add line to back of deque unless it does not match search, returns true if it was added.
+    fn add_matching_back(
+        lines: &mut VecDeque<Line>,
+        bytes: &[u8],
+        time: Option<SystemTime>,
+        search: &str,
+        case_sensitive: bool,
+        ansi_mode: AnsiMode,
+    ) -> bool {
+        let raw = String::from_utf8_lossy(bytes);
+        let line = without_ansi_escapes(raw.clone());
+        if !search.is_empty() && find_matches(&line, search, case_sensitive).is_empty() {
+            return false;
+        }
+        let styled = (ansi_mode == AnsiMode::Render).then_some(raw.as_ref());
+        Self::add_line_back(lines, line, styled, time);
+        true
+    }
 
-            if lines.len() >= limit {
-                break;
+    /// Remove one occurrence of the last line of deque.
+    fn remove_line_back(lines: &mut VecDeque<Line>) {
+        match lines.back_mut() {
+            Some((count, ..)) if count.get() > 1 => {
+                *count = NonZero::new(count.get() - 1).expect("> 1 value - 1 should be > 0");
+            }
+            _ => {
+                lines.pop_back();
             }
         }
     }
 
     /// Add more content to pipe with given index.
+    ///
+    /// If the pipe is current the lines completed by content are displayed, if
+    /// searching only those matching the search.
     fn add_content(&mut self, idx: usize, new_content: Vec<u8>) {
         let Some(pipe) = self.pipes.get_mut(idx) else {
             ::log::warn!("received content for unavailable task {idx}");
             return;
         };
 
-        // name of content that we manipulate.
-        let mut content = new_content.as_slice();
-        let mut content = ::core::iter::from_fn(move || {
-            if content.is_empty() {
-                return None;
-            }
-
-            let Some(idx) = ::memchr::memchr(b'\n', content) else {
-                let slice = content;
-                content = &[];
-                return Some(slice);
-            };
+        let now = self.timestamps.then(SystemTime::now);
+        if let Some(now) = now {
+            let offset = pipe.content.len();
+            pipe.line_times
+                .extend(::memchr::memchr_iter(b'\n', &new_content).map(|end| (offset + end, now)));
+        }
 
-            let slice = content.split_off(..idx).expect("idx should be in bounds");
-            assert_eq!(content.split_off(..1), Some(b"\n".as_slice()));
+        // Start of last line, all line endings after it are in new content.
+        let start = ::memchr::memrchr(b'\n', &pipe.content).map_or(0, |end| end + 1);
+        pipe.content.extend_from_slice(&new_content);
+        let trimmed = self
+            .byte_limit
+            .is_some_and(|byte_limit| pipe.trim(byte_limit));
 
-            Some(slice)
-        });
+        if self.current != Some(idx) {
+            return;
+        }
 
-        if !self.lines.is_empty()
-            && !pipe.content.ends_with(b"\n")
-            && let Some(slice) = content.next()
-        {
-            let count = self.lines.back().map_or(1, |(count, _)| count.get());
-
-            if count == 1 {
-                let (_, last) = self.lines.back_mut().expect("length should be > 0");
-                extend_lossy(last, slice);
-                *last = without_ansi_escapes(mem::take(last).into()).into_owned();
-            } else {
-                let mut line = {
-                    let (c, last) = self.lines.back_mut().expect("length should be > 0");
-                    *c = NonZero::new(count - 1).expect("> 1 value - 1 should be > 0");
-                    last.clone()
-                };
-                extend_lossy(&mut line, slice);
-                self.lines
-                    .push_back((const { NonZero::new(1).unwrap() }, line));
-            }
+        if trimmed {
+            self.refresh();
+            return;
         }
-        pipe.content.extend_from_slice(&new_content);
 
-        for slice in content {
-            Self::add_line_back(
-                &mut self.lines,
-                without_ansi_escapes(String::from_utf8_lossy(slice)),
-            );
+        let Self {
+            pipes,
+            lines,
+            limit,
+            current_limit,
+            search,
+            case_sensitive,
+            current_match,
+            partial,
+            ansi_mode,
+            ..
+        } = self;
+        let pipe = &pipes[idx];
+
+        if mem::take(partial) {
+            Self::remove_line_back(lines);
         }
 
-        while self.lines.len() > usize::from(self.limit) {
-            self.lines.pop_front();
+        let mut completed = pipe.content[start..].split(|byte| *byte == b'\n');
+        let last = completed.next_back().filter(|last| !last.is_empty());
+        for bytes in completed {
+            Self::add_matching_back(lines, bytes, now, search, *case_sensitive, *ansi_mode);
         }
+        *partial = last.is_some_and(|bytes| {
+            Self::add_matching_back(lines, bytes, None, search, *case_sensitive, *ansi_mode)
+        });
+
+        let limit = usize::from(current_limit.unwrap_or(*limit));
+        let excess = lines.len().saturating_sub(limit);
+        lines.drain(..excess);
+        *current_match = current_match.and_then(|idx| idx.checked_sub(excess));
     }
 
     /// Set amount of visible lines.
@@ -547,33 +1138,124 @@ impl Terminal {
         }
     }
 
+    /// View a line as styled segments.
+    fn view_segments<'a>(&self, segments: &'a [(AnsiStyle, String)]) -> Element<'a, Message> {
+        let spans = segments
+            .iter()
+            .map(|(style, text)| {
+                let font = if style.bold {
+                    font::Font {
+                        weight: font::Weight::Bold,
+                        ..font::Font::MONOSPACE
+                    }
+                } else {
+                    font::Font::MONOSPACE
+                };
+                widget::span::<(), ::iced_core::Font>(text.as_str())
+                    .font(font)
+                    .color_maybe(style.foreground)
+                    .background_maybe(style.background)
+                    .underline(style.underline)
+            })
+            .collect::<Vec<_>>();
+
+        widget::rich_text(spans)
+            .size(u32::from(self.text_size))
+            .font(font::Font::MONOSPACE)
+            .wrapping(self.wrap.into())
+            .into()
+    }
+
+    /// View line, highlighting matches of search, using segments if given and not matching.
+    fn view_line<'a>(
+        &'a self,
+        line: &'a str,
+        segments: Option<&'a [(AnsiStyle, String)]>,
+    ) -> Element<'a, Message> {
+        let matches = find_matches(line, &self.search, self.case_sensitive);
+        if matches.is_empty() {
+            if let Some(segments) = segments {
+                return self.view_segments(segments);
+            }
+
+            return widget::Text::new(line)
+                .size(u32::from(self.text_size))
+                .font(::iced_core::font::Font::MONOSPACE)
+                .wrapping(self.wrap.into())
+                .into();
+        }
+
+        let mut spans = Vec::with_capacity(matches.len() * 2 + 1);
+        let mut end = 0;
+        for range in matches {
+            spans.push(widget::span::<(), ::iced_core::Font>(
+                &line[end..range.start],
+            ));
+            spans.push(
+                widget::span(&line[range.clone()])
+                    .color(Color::BLACK)
+                    .background(Color::from_rgb8(0xE5, 0xC0, 0x7B)),
+            );
+            end = range.end;
+        }
+        spans.push(widget::span(&line[end..]));
+
+        widget::rich_text(spans)
+            .size(u32::from(self.text_size))
+            .font(::iced_core::font::Font::MONOSPACE)
+            .wrapping(self.wrap.into())
+            .into()
+    }
+
+    /// View line, prefixed by when it was received if showing timestamps.
+    fn view_timed_line<'a>(&'a self, (_, time, line, segments): &'a Line) -> Element<'a, Message> {
+        let line = self.view_line(line, segments.as_deref());
+        if !self.timestamps {
+            return line;
+        }
+
+        let stamp = time
+            .map(|time| {
+                ::chrono::DateTime::<::chrono::Local>::from(time)
+                    .format("%H:%M:%S%.3f")
+                    .to_string()
+            })
+            .unwrap_or_default();
+
+        widget::Row::new()
+            .spacing(6)
+            .push(
+                widget::Text::new(format!("{stamp:12}"))
+                    .size(u32::from(self.text_size))
+                    .font(::iced_core::font::Font::MONOSPACE)
+                    .color(Color::from_rgb8(0x80, 0x80, 0x80)),
+            )
+            .push(line)
+            .into()
+    }
+
     /// View terminal.
     pub fn view(&self) -> Element<'_, Message> {
+        let lines = spel_katalog_widget::scrollable(
+            widget::container(
+                self.lines
+                    .iter()
+                    .fold(widget::Column::new(), |column, line| {
+                        column.push(self.view_timed_line(line))
+                    })
+                    .spacing(3),
+            )
+            .style(widget::container::dark)
+            .width(Fill),
+        )
+        .id(LINES_ID)
+        .height(Fill)
+        .anchor_bottom();
+
         widget::Column::new()
             .padding(3)
             .spacing(3)
-            .push(widget::themer(
-                Some(::iced_core::Theme::Dark),
-                spel_katalog_widget::scrollable(
-                    widget::container(
-                        self.lines
-                            .iter()
-                            .fold(widget::Column::new(), |column, line| {
-                                column.push(
-                                    widget::Text::new(line.1.as_str())
-                                        .size(u32::from(self.text_size))
-                                        .font(::iced_core::font::Font::MONOSPACE)
-                                        .wrapping(self.wrap.into()),
-                                )
-                            })
-                            .spacing(3),
-                    )
-                    .style(widget::container::dark)
-                    .width(Fill),
-                )
-                .anchor_bottom()
-                .height(Fill),
-            ))
+            .push(widget::themer(Some(::iced_core::Theme::Dark), lines))
             .push(spel_katalog_widget::rule::horizontal())
             .push(
                 widget::Row::new()
@@ -601,7 +1283,66 @@ impl Terminal {
                         )
                         .map(|idx| Message::SetCurrent { idx })
                     }))
+                    .push(
+                        widget::button("Save")
+                            .padding(3)
+                            .on_press_maybe(self.current.map(|idx| Message::SavePipe { idx })),
+                    )
+                    .push(
+                        widget::button("Clear")
+                            .padding(3)
+                            .on_press_maybe(self.current.map(|_| Message::ClearCurrent)),
+                    )
+                    .push_maybe(
+                        self.save_status
+                            .as_deref()
+                            .map(|status| widget::text(status).style(widget::text::secondary)),
+                    )
+                    .push(
+                        widget::toggler(self.auto_switch)
+                            .label("Auto switch")
+                            .on_toggle(Message::SetAutoSwitch),
+                    )
+                    .push(
+                        widget::checkbox(self.follow)
+                            .label("Follow")
+                            .on_toggle(Message::SetFollow),
+                    )
+                    .push(
+                        widget::checkbox(self.timestamps)
+                            .label("Timestamps")
+                            .on_toggle(Message::SetTimestamps),
+                    )
                     .push(widget::space::horizontal())
+                    .push(
+                        widget::text_input("search...", &self.search)
+                            .on_input(Message::SetSearch)
+                            .on_submit(Message::NextMatch)
+                            .width(150)
+                            .padding(3),
+                    )
+                    .push_maybe((!self.search.is_empty()).then(|| {
+                        widget::text(format!(
+                            "{}/{}",
+                            self.current_match.map_or(0, |idx| idx + 1),
+                            self.match_count()
+                        ))
+                    }))
+                    .push(
+                        widget::button("▲")
+                            .padding(3)
+                            .on_press_maybe((self.match_count() > 0).then_some(Message::PrevMatch)),
+                    )
+                    .push(
+                        widget::button("▼")
+                            .padding(3)
+                            .on_press_maybe((self.match_count() > 0).then_some(Message::NextMatch)),
+                    )
+                    .push(
+                        widget::toggler(self.case_sensitive)
+                            .label("Case")
+                            .on_toggle(Message::SetCaseSensitive),
+                    )
                     .push("Size")
                     .push(
                         Element::from(
@@ -626,6 +1367,18 @@ impl Terminal {
                         )
                         .map(Message::SetWrap),
                     )
+                    .push("Ansi:")
+                    .push(
+                        Element::from(
+                            widget::pick_list(
+                                [AnsiMode::Strip, AnsiMode::Render],
+                                Some(self.ansi_mode),
+                                convert::identity,
+                            )
+                            .padding(3),
+                        )
+                        .map(Message::SetAnsiMode),
+                    )
                     .push("Lines:")
                     .push(
                         Element::from(
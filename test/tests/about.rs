@@ -0,0 +1,25 @@
+//! Test version and credits shown in about window.
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog::about;
+
+#[test]
+fn version_matches_package() {
+    let manifest = ::toml::from_str::<::toml::Table>(include_str!("../../spel-katalog/Cargo.toml"))
+        .expect("manifest should parse");
+    let version = manifest
+        .get("package")
+        .and_then(|package| package.get("version"))
+        .and_then(|version| version.as_str());
+
+    assert_eq!(version, Some(about::VERSION));
+    assert_eq!(
+        about::version_label(),
+        format!("Spel Katalog {}", about::VERSION)
+    );
+}
+
+#[test]
+fn license_from_package() {
+    assert_eq!(about::LICENSE, "MIT OR Apache-2.0");
+}
@@ -0,0 +1,55 @@
+//! Test reading and writing additional config of games.
+
+pub mod common;
+
+use ::std::path::PathBuf;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_formats::{AdditionalConfig, AdditionalConfigError, GameId};
+
+use crate::common::temp_dir;
+
+#[test]
+fn path_in_games_dir() {
+    assert_eq!(
+        AdditionalConfig::path("/config/spel-katalog".as_ref(), GameId::Lutris(5)),
+        PathBuf::from("/config/spel-katalog/games/5.toml")
+    );
+}
+
+#[test]
+fn missing_read_as_none() {
+    let dir = temp_dir("additional-missing");
+    let path = AdditionalConfig::path(&dir, GameId::Lutris(5));
+
+    let read = ::smol::block_on(AdditionalConfig::read(&path)).expect("read should succeed");
+    assert_eq!(read, None);
+}
+
+#[test]
+fn written_config_read_back() {
+    let dir = temp_dir("additional-round-trip");
+    let path = AdditionalConfig::path(&dir, GameId::Lutris(5));
+    let config = AdditionalConfig {
+        favorite: true,
+        attrs: [("genre".to_owned(), "puzzle".to_owned())]
+            .into_iter()
+            .collect(),
+        ..Default::default()
+    };
+
+    // Games directory is created when writing.
+    ::smol::block_on(config.write(&path)).expect("write should succeed");
+    let read = ::smol::block_on(AdditionalConfig::read(&path)).expect("read should succeed");
+    assert_eq!(read, Some(config));
+}
+
+#[test]
+fn unparsable_is_error() {
+    let dir = temp_dir("additional-unparsable");
+    let path = dir.join("5.toml");
+    ::std::fs::write(&path, "favorite = \"yes").expect("file should be writable");
+
+    let read = ::smol::block_on(AdditionalConfig::read(&path));
+    assert!(matches!(read, Err(AdditionalConfigError::Parse { .. })));
+}
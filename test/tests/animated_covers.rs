@@ -0,0 +1,77 @@
+//! Test decoding of animated covers.
+
+use ::std::io::Cursor;
+
+use ::image::{
+    Delay, DynamicImage, Frame, ImageFormat, Rgba, RgbaImage,
+    codecs::gif::{GifEncoder, Repeat},
+};
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_gather::{load_cover, open_cover, thumbnail};
+
+/// Color of first frame.
+const FIRST: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+/// Color of second frame.
+const SECOND: Rgba<u8> = Rgba([0, 0, 255, 255]);
+
+/// Encode an animated gif with a red then a blue frame.
+fn animated_gif() -> Vec<u8> {
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = GifEncoder::new(&mut bytes);
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .expect("repeat should be settable");
+        encoder
+            .encode_frames([FIRST, SECOND].map(|color| {
+                Frame::from_parts(
+                    RgbaImage::from_pixel(8, 6, color),
+                    0,
+                    0,
+                    Delay::from_numer_denom_ms(100, 1),
+                )
+            }))
+            .expect("frames should be encodable");
+    }
+    bytes
+}
+
+#[test]
+fn first_frame_of_gif() {
+    let image = load_cover(&animated_gif()).expect("gif should decode");
+    let thumb = thumbnail(image, 0);
+
+    assert_eq!((thumb.width, thumb.height), (8, 6));
+    assert_eq!(thumb.bytes.len(), 8 * 6 * 4);
+    assert!(thumb.bytes.chunks_exact(4).all(|pixel| pixel == FIRST.0));
+}
+
+#[test]
+fn first_frame_of_gif_file() {
+    let path = ::std::env::temp_dir().join(format!(
+        "spel-katalog-{}-animated.gif",
+        ::std::process::id()
+    ));
+    ::std::fs::write(&path, animated_gif()).expect("gif should be writable");
+    let image = open_cover(&path);
+    _ = ::std::fs::remove_file(&path);
+
+    let image = image.expect("gif should decode").into_rgba8();
+    assert_eq!(image.dimensions(), (8, 6));
+    assert_eq!(*image.get_pixel(3, 3), FIRST);
+}
+
+#[test]
+fn still_image_unaffected() {
+    let image = DynamicImage::ImageRgba8(RgbaImage::from_fn(4, 3, |x, y| {
+        Rgba([x as u8 * 60, y as u8 * 80, 10, 255])
+    }));
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .expect("png should be encodable");
+
+    let loaded = load_cover(&png).expect("png should decode");
+    assert_eq!(loaded.into_rgba8(), image.into_rgba8());
+}
@@ -0,0 +1,104 @@
+//! Test atomic writes of settings and other config files.
+
+pub mod common;
+
+use ::std::path::PathBuf;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_settings::{
+    Delta, Settings, SettingsArgs, SettingsStore, Theme, load, save, temp_path, write_atomic,
+};
+
+use crate::common::temp_dir;
+
+/// Create settings using given theme.
+fn with_theme(theme: Theme) -> Settings {
+    let mut settings = Settings::from(SettingsStore::default());
+    Delta::Theme(theme).apply(&mut settings);
+    settings
+}
+
+#[test]
+fn temp_file_next_to_target() {
+    let path = PathBuf::from("/config/spel-katalog/games/5.toml");
+    let temp = temp_path(&path);
+
+    assert_eq!(temp, PathBuf::from("/config/spel-katalog/games/5.toml.tmp"));
+    assert_eq!(temp.parent(), path.parent());
+}
+
+#[test]
+fn write_replaces_content() {
+    let dir = temp_dir("atomic-write");
+    let path = dir.join("5.toml");
+
+    ::smol::block_on(write_atomic(&path, b"favorite = true\n")).expect("write should succeed");
+    ::smol::block_on(write_atomic(&path, b"pinned = true\n")).expect("write should succeed");
+
+    assert_eq!(
+        ::std::fs::read_to_string(&path).expect("file should be readable"),
+        "pinned = true\n"
+    );
+    assert!(!temp_path(&path).exists());
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
+
+#[test]
+fn failed_write_keeps_original() {
+    let dir = temp_dir("atomic-write-failed");
+    let path = dir.join("5.toml");
+    ::std::fs::write(&path, "favorite = true\n").expect("file should be writable");
+
+    // Temporary file cannot be created, interrupting the write before the rename.
+    ::std::fs::create_dir(temp_path(&path)).expect("dir should be creatable");
+    assert!(::smol::block_on(write_atomic(&path, b"pinned = true\n")).is_err());
+
+    assert_eq!(
+        ::std::fs::read_to_string(&path).expect("file should be readable"),
+        "favorite = true\n"
+    );
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
+
+#[test]
+fn interrupted_save_keeps_settings() {
+    let dir = temp_dir("atomic-save");
+    let path = dir.join("config.toml");
+
+    ::smol::block_on(save(with_theme(Theme::Nord), path.clone())).expect("save should succeed");
+
+    ::std::fs::create_dir(temp_path(&path)).expect("dir should be creatable");
+    assert!(::smol::block_on(save(with_theme(Theme::Dracula), path.clone())).is_err());
+    assert_eq!(
+        *load(&path, SettingsArgs::default()).get::<Theme>(),
+        Theme::Nord
+    );
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
+
+#[test]
+fn partial_temp_file_ignored() {
+    let dir = temp_dir("atomic-partial");
+    let path = dir.join("config.toml");
+
+    ::smol::block_on(save(with_theme(Theme::Nord), path.clone())).expect("save should succeed");
+
+    // A crash mid write leaves a partial temporary file behind.
+    ::std::fs::write(temp_path(&path), "theme = \"Dra").expect("file should be writable");
+    assert_eq!(
+        *load(&path, SettingsArgs::default()).get::<Theme>(),
+        Theme::Nord
+    );
+
+    ::smol::block_on(save(with_theme(Theme::Dracula), path.clone())).expect("save should succeed");
+    assert_eq!(
+        *load(&path, SettingsArgs::default()).get::<Theme>(),
+        Theme::Dracula
+    );
+    assert!(!temp_path(&path).exists());
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
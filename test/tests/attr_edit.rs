@@ -0,0 +1,95 @@
+//! Test applying attribute edits to a selection of games.
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_formats::{AdditionalConfig, AttrEdit, GameId};
+
+/// Create a config with given attributes.
+fn config(attrs: &[(&str, &str)]) -> AdditionalConfig {
+    AdditionalConfig {
+        attrs: attrs
+            .iter()
+            .map(|(key, value)| ((*key).to_owned(), (*value).to_owned()))
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// Configs of a selection of three games.
+fn selection() -> Vec<(GameId, AdditionalConfig)> {
+    vec![
+        (GameId::Lutris(1), config(&[])),
+        (GameId::Lutris(2), config(&[("genre", "rpg")])),
+        (
+            GameId::Lutris(3),
+            config(&[("genre", "puzzle"), ("done", "yes")]),
+        ),
+    ]
+}
+
+#[test]
+fn set_writes_changed_games() {
+    let edit = AttrEdit::Set {
+        key: "genre".to_owned(),
+        value: "rpg".to_owned(),
+    };
+
+    assert_eq!(
+        edit.apply_to(selection()),
+        [
+            (GameId::Lutris(1), config(&[("genre", "rpg")])),
+            (
+                GameId::Lutris(3),
+                config(&[("genre", "rpg"), ("done", "yes")])
+            ),
+        ]
+    );
+}
+
+#[test]
+fn remove_writes_games_with_key() {
+    let edit = AttrEdit::Remove {
+        key: "genre".to_owned(),
+    };
+
+    assert_eq!(
+        edit.apply_to(selection()),
+        [
+            (GameId::Lutris(2), config(&[])),
+            (GameId::Lutris(3), config(&[("done", "yes")])),
+        ]
+    );
+}
+
+#[test]
+fn other_fields_kept() {
+    let mut additional = AdditionalConfig {
+        favorite: true,
+        sandbox_root: vec!["/games".to_owned()],
+        ..config(&[("done", "no")])
+    };
+
+    let edit = AttrEdit::Set {
+        key: "done".to_owned(),
+        value: "yes".to_owned(),
+    };
+    assert!(edit.apply(&mut additional));
+    assert!(!edit.apply(&mut additional));
+
+    assert_eq!(edit.key(), "done");
+    assert_eq!(
+        additional,
+        AdditionalConfig {
+            favorite: true,
+            sandbox_root: vec!["/games".to_owned()],
+            ..config(&[("done", "yes")])
+        }
+    );
+}
+
+#[test]
+fn empty_selection() {
+    let edit = AttrEdit::Remove {
+        key: "genre".to_owned(),
+    };
+    assert_eq!(edit.apply_to(Vec::<(GameId, AdditionalConfig)>::new()), []);
+}
@@ -0,0 +1,66 @@
+//! Test editor autosave debounce and restore logic.
+
+use ::core::time::Duration;
+use ::std::path::Path;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_common::autosave::{Debounce, restorable, sidecar_path};
+use ::spel_katalog_settings::AutosaveDelay;
+
+#[test]
+fn only_latest_edit_triggers() {
+    let mut debounce = Debounce::default();
+
+    let first = debounce.edit();
+    let second = debounce.edit();
+    assert!(!debounce.should_save(first));
+    assert!(debounce.should_save(second));
+
+    let third = debounce.edit();
+    assert!(!debounce.should_save(second));
+    assert!(debounce.should_save(third));
+}
+
+#[test]
+fn cancel_prevents_trigger() {
+    let mut debounce = Debounce::default();
+
+    let generation = debounce.edit();
+    debounce.cancel();
+    assert!(!debounce.should_save(generation));
+
+    let generation = debounce.edit();
+    assert!(debounce.should_save(generation));
+}
+
+#[test]
+fn delay_duration() {
+    assert_eq!(
+        AutosaveDelay::default().duration(),
+        Some(Duration::from_secs(10))
+    );
+    assert_eq!(
+        AutosaveDelay::new(3).duration(),
+        Some(Duration::from_secs(3))
+    );
+    assert_eq!(AutosaveDelay::new(0).duration(), None);
+    assert_eq!(AutosaveDelay::new(-5).duration(), None);
+}
+
+#[test]
+fn restore_prompt() {
+    assert_eq!(restorable("game: {}", None), None);
+    assert_eq!(restorable("game: {}", Some("game: {}")), None);
+    assert_eq!(
+        restorable("game: {}", Some("game: {exe: a}")),
+        Some("game: {exe: a}".to_owned())
+    );
+}
+
+#[test]
+fn sidecar() {
+    assert_eq!(
+        sidecar_path(Path::new("/games/portal-2.yml")),
+        Path::new("/games/portal-2.yml.autosave")
+    );
+}
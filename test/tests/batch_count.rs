@@ -0,0 +1,59 @@
+//! Test counting batch selected games.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_formats::Game;
+use ::spel_katalog_games::{GameWithThumb, State};
+use ::spel_katalog_settings::{Settings, SettingsStore};
+
+/// Create a lutris game, batch selected if requested.
+fn lutris(id: i64, name: &str, batch_selected: bool) -> GameWithThumb {
+    GameWithThumb {
+        batch_selected,
+        ..common::lutris(id, name)
+    }
+}
+
+#[test]
+fn count_selected() {
+    let settings = Settings::from(SettingsStore::default());
+    let mut state = State::default();
+    assert_eq!(state.batch_selected_count(), 0);
+
+    state.merge_games(
+        vec![
+            lutris(1, "Celeste", true),
+            lutris(2, "Hades", false),
+            lutris(3, "Portal", true),
+            lutris(4, "Outer Wilds", false),
+        ],
+        |_| true,
+        &settings,
+        "",
+    );
+    assert_eq!(state.batch_selected_count(), 2);
+
+    state.deselect_batch();
+    assert_eq!(state.batch_selected_count(), 0);
+}
+
+#[test]
+fn count_includes_hidden() {
+    let settings = Settings::from(SettingsStore::default());
+    let mut state = State::default();
+
+    let mut hades = lutris(2, "Hades", true);
+    if let Game::Lutris(game) = &mut hades.game {
+        game.hidden = true;
+    }
+    state.merge_games(
+        vec![lutris(1, "Celeste", true), hades],
+        |_| true,
+        &settings,
+        "",
+    );
+
+    assert_eq!(state.displayed_count(), 1);
+    assert_eq!(state.batch_selected_count(), 2);
+}
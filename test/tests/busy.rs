@@ -0,0 +1,67 @@
+//! Test busy tracking of long running operations.
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_common::Busy;
+
+#[test]
+fn overlapping_operations() {
+    let busy = Busy::default();
+    assert!(!busy.is_busy());
+
+    let games = busy.start("loading games");
+    let covers = busy.clone().start("gathering covers");
+    assert_eq!(busy.count(), 2);
+    assert_eq!(busy.label(), Some("gathering covers"));
+
+    drop(games);
+    assert_eq!(busy.count(), 1);
+    assert_eq!(busy.label(), Some("gathering covers"));
+
+    drop(covers);
+    assert!(!busy.is_busy());
+    assert_eq!(busy.label(), None);
+}
+
+#[test]
+fn repeated_label() {
+    let busy = Busy::default();
+
+    let first = busy.start("loading games");
+    let second = busy.start("loading games");
+    drop(first);
+    assert_eq!(busy.label(), Some("loading games"));
+
+    drop(second);
+    assert!(!busy.is_busy());
+}
+
+#[test]
+fn cleared_on_error() {
+    let busy = Busy::default();
+
+    let result = (|| -> Result<(), &str> {
+        let _busy = busy.start("failing");
+        Err("operation failed")
+    })();
+
+    assert!(result.is_err());
+    assert!(!busy.is_busy());
+}
+
+#[test]
+fn progress_of_latest_operation() {
+    let busy = Busy::default();
+
+    let games = busy.start("loading games");
+    assert_eq!(busy.progress(), None);
+
+    let covers = busy.start("gathering covers");
+    covers.set_progress(3, 10);
+    assert_eq!(busy.progress(), Some((3, 10)));
+
+    games.set_progress(1, 2);
+    assert_eq!(busy.progress(), Some((3, 10)));
+
+    drop(covers);
+    assert_eq!(busy.progress(), Some((1, 2)));
+}
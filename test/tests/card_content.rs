@@ -0,0 +1,75 @@
+//! Test toggling thumbnails of game cards.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_games::{CardContent, GameWithThumb, State};
+use ::spel_katalog_settings::{Delta, Settings, SettingsStore, ThumbnailPlaceholder};
+
+/// Create a lutris game, with a thumbnail if requested.
+fn lutris(id: i64, name: &str, thumb: bool) -> GameWithThumb {
+    GameWithThumb {
+        thumb: thumb.then(|| ::iced::widget::image::Handle::from_rgba(1, 1, vec![0; 4])),
+        ..common::lutris(id, name)
+    }
+}
+
+#[test]
+fn toggle() {
+    let mut state = State::default();
+    assert!(!state.thumbnails_hidden());
+
+    assert!(state.toggle_thumbnails());
+    assert!(state.thumbnails_hidden());
+
+    assert!(!state.toggle_thumbnails());
+    assert!(!state.thumbnails_hidden());
+}
+
+#[test]
+fn hidden_thumbnails_render_text() {
+    let settings = Settings::from(SettingsStore::default());
+    let mut state = State::default();
+    let with_thumb = lutris(1, "Celeste", true);
+    let without_thumb = lutris(2, "Hades", false);
+
+    assert_eq!(
+        state.card_content(&with_thumb, &settings),
+        CardContent::Thumbnail
+    );
+    assert_eq!(
+        state.card_content(&without_thumb, &settings),
+        CardContent::Text
+    );
+
+    state.toggle_thumbnails();
+
+    assert_eq!(
+        state.card_content(&with_thumb, &settings),
+        CardContent::Text
+    );
+    assert_eq!(
+        state.card_content(&without_thumb, &settings),
+        CardContent::Text
+    );
+}
+
+#[test]
+fn hidden_thumbnails_skip_placeholder() {
+    let mut settings = Settings::from(SettingsStore::default());
+    Delta::ThumbnailPlaceholder(ThumbnailPlaceholder::Color).apply(&mut settings);
+    let mut state = State::default();
+    let without_thumb = lutris(2, "Hades", false);
+
+    assert_eq!(
+        state.card_content(&without_thumb, &settings),
+        CardContent::Placeholder
+    );
+
+    state.toggle_thumbnails();
+
+    assert_eq!(
+        state.card_content(&without_thumb, &settings),
+        CardContent::Text
+    );
+}
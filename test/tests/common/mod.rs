@@ -0,0 +1,114 @@
+//! Helpers shared between tests.
+
+use ::std::{
+    io::{PipeWriter, Write},
+    path::PathBuf,
+    pin::Pin,
+};
+
+use ::iced_runtime::Action;
+use ::smol::stream::{Stream, StreamExt as _};
+use ::spel_katalog_formats::{Game, LutrisGame, LutrisRunner};
+use ::spel_katalog_games::GameWithThumb;
+use ::spel_katalog_sink::SinkIdentity;
+use ::spel_katalog_terminal::{ClonePkgLock, Message, Terminal};
+
+/// Stream of actions produced by adding a pipe to a terminal.
+pub type Actions = Pin<Box<dyn Stream<Item = Action<Message>> + Send>>;
+
+/// Create an empty temporary directory.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = ::std::env::temp_dir().join(format!("spel-katalog-{}-{name}", ::std::process::id()));
+    if dir.exists() {
+        ::std::fs::remove_dir_all(&dir).expect("old temp dir should be removable");
+    }
+    ::std::fs::create_dir_all(&dir).expect("temp dir should be creatable");
+    dir
+}
+
+/// Create a linux lutris game installed at its id, as it would be read from the lutris database.
+pub fn lutris_game(id: i64, name: &str) -> LutrisGame {
+    LutrisGame {
+        slug: name.to_lowercase().replace(' ', "-"),
+        id,
+        name: name.to_owned(),
+        runner: LutrisRunner::Linux,
+        configpath: String::new(),
+        hidden: false,
+        installed_at: id,
+    }
+}
+
+/// Wrap a game without any thumbnail or state.
+pub fn with_thumb(game: Game) -> GameWithThumb {
+    GameWithThumb {
+        game,
+        thumb: None,
+        thumb_thumb: None,
+        batch_selected: false,
+        shadows: None,
+        ghost: false,
+        favorite: false,
+        pinned: false,
+    }
+}
+
+/// Create a lutris game without any thumbnail or state.
+pub fn lutris(id: i64, name: &str) -> GameWithThumb {
+    with_thumb(Game::Lutris(lutris_game(id, name)))
+}
+
+/// Add a pipe to terminal, returning writer and stream of actions.
+pub fn add_pipe(terminal: &mut Terminal) -> (PipeWriter, Actions) {
+    let (reader, writer) = ::std::io::pipe().expect("pipe should be creatable");
+    let task = terminal.update(Message::AddPipe {
+        identity: SinkIdentity::GameId(1),
+        reader: ClonePkgLock::new(reader),
+    });
+    let stream = ::iced_runtime::task::into_stream(task).expect("task should have a stream");
+
+    (writer, stream)
+}
+
+/// Write output to pipe and update terminal until it has been added.
+pub fn write_output(
+    terminal: &mut Terminal,
+    writer: &mut PipeWriter,
+    stream: &mut Actions,
+    output: &[u8],
+) {
+    writer.write_all(output).expect("output should be writable");
+
+    while let Some(action) = ::smol::block_on(stream.next()) {
+        if let Action::Output(message) = action {
+            let is_content = matches!(message, Message::AddContent { .. });
+            _ = terminal.update(message);
+            if is_content {
+                return;
+            }
+        }
+    }
+}
+
+/// Add a closed pipe containing given output to terminal and make it current.
+pub fn with_output(mut terminal: Terminal, output: impl AsRef<[u8]>) -> Terminal {
+    let (mut writer, stream) = add_pipe(&mut terminal);
+    writer
+        .write_all(output.as_ref())
+        .expect("output should be writable");
+    drop(writer);
+
+    run_actions(&mut terminal, stream);
+    _ = terminal.update(Message::SetCurrent { idx: Some(0) });
+
+    terminal
+}
+
+/// Update terminal with all messages of stream, until it ends.
+pub fn run_actions(terminal: &mut Terminal, stream: Actions) {
+    for action in ::smol::block_on(stream.collect::<Vec<_>>()) {
+        if let Action::Output(message) = action {
+            _ = terminal.update(message);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Test gating of process signals behind confirmation.
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_settings::{ConfirmKill, Delta, Settings, SettingsStore};
+
+#[test]
+fn kill_requires_confirmation() {
+    assert!(ConfirmKill::Yes.requires_confirmation(false));
+    assert!(!ConfirmKill::No.requires_confirmation(false));
+}
+
+#[test]
+fn terminate_is_immediate() {
+    assert!(!ConfirmKill::Yes.requires_confirmation(true));
+    assert!(!ConfirmKill::No.requires_confirmation(true));
+}
+
+#[test]
+fn confirmation_follows_settings() {
+    let mut settings = Settings::from(SettingsStore::default());
+    assert_eq!(*settings.get::<ConfirmKill>(), ConfirmKill::Yes);
+    assert!(settings.get::<ConfirmKill>().requires_confirmation(false));
+
+    Delta::ConfirmKill(ConfirmKill::No).apply(&mut settings);
+    assert!(!settings.get::<ConfirmKill>().requires_confirmation(false));
+}
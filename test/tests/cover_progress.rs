@@ -0,0 +1,97 @@
+//! Test progress of gathering covers.
+
+pub mod common;
+
+use ::std::path::{Path, PathBuf};
+
+use ::pretty_assertions::assert_eq;
+use ::smol::stream::StreamExt;
+use ::spel_katalog_gather::{CoverGatherer, CoverGathererOptions, GatherProgress};
+
+use crate::common::temp_dir;
+
+/// Write a png cover.
+fn write_png(dir: &Path, name: &str) {
+    ::image::RgbaImage::from_pixel(4, 4, ::image::Rgba([0, 0, 255, 255]))
+        .save(dir.join(name))
+        .expect("png should be writable");
+}
+
+/// Directory with three images, one broken cover and a subdirectory.
+fn cover_dir(name: &str) -> PathBuf {
+    let dir = temp_dir(name);
+    write_png(&dir, "a.png");
+    write_png(&dir, "b.png");
+    write_png(&dir, "c.png");
+    ::std::fs::write(dir.join("broken.png"), "not an image").expect("file should be writable");
+    ::std::fs::create_dir(dir.join("subdir.png")).expect("subdir should be creatable");
+    dir
+}
+
+#[test]
+fn total_all_candidates() {
+    let dir = cover_dir("cover-progress-all");
+
+    let gatherer = CoverGatherer::with_options(&dir, CoverGathererOptions::default())
+        .expect("gatherer should be created");
+    assert_eq!(gatherer.total(), 4);
+
+    let mut slugs = (&gatherer)
+        .into_iter()
+        .map(|(slug, _)| slug)
+        .collect::<Vec<_>>();
+    slugs.sort();
+    assert_eq!(slugs, ["a", "b", "c"]);
+    assert_eq!(
+        gatherer.progress(),
+        GatherProgress {
+            processed: 4,
+            total: 4
+        }
+    );
+    assert!(gatherer.progress().is_done());
+    assert_eq!(gatherer.progress().to_string(), "4/4");
+}
+
+#[test]
+fn total_requested_slugs() {
+    let dir = cover_dir("cover-progress-slugs");
+
+    let gatherer = CoverGatherer::new(&dir, vec!["a".to_owned(), "missing".to_owned()])
+        .expect("gatherer should be created");
+    assert_eq!(gatherer.total(), 1);
+
+    let items = ::smol::block_on(gatherer.into_progress_stream().collect::<Vec<_>>());
+    assert_eq!(items.len(), 1);
+    let (progress, (slug, _)) = &items[0];
+    assert_eq!(slug, "a");
+    assert_eq!(progress.total, 1);
+    assert!(progress.is_done());
+}
+
+#[test]
+fn missing_dir() {
+    let dir = temp_dir("cover-progress-missing").join("missing");
+
+    assert!(CoverGatherer::new(&dir, Vec::new()).is_err());
+}
+
+#[test]
+fn spawned_scan() {
+    let dir = cover_dir("cover-progress-spawn");
+
+    let gatherer = ::smol::block_on(CoverGatherer::spawn(
+        dir.clone(),
+        CoverGathererOptions::default(),
+    ))
+    .expect("gatherer should be created");
+    assert_eq!(gatherer.total(), 4);
+
+    assert!(
+        ::smol::block_on(CoverGatherer::spawn(
+            dir.join("missing"),
+            Default::default()
+        ))
+        .is_err()
+    );
+}
@@ -0,0 +1,86 @@
+//! Test detection of duplicate thumbnails.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_formats::Image;
+use ::spel_katalog_gather::{
+    find_cached_duplicate, find_duplicate_thumbnail, import_lutris_covers, thumbnail,
+};
+
+use crate::common::temp_dir;
+
+/// Create a single colored image.
+fn image(width: u32, height: u32, pixel: [u8; 4]) -> Image {
+    ::image::RgbaImage::from_pixel(width, height, ::image::Rgba(pixel)).into()
+}
+
+#[test]
+fn exact_duplicate() {
+    let cached = vec![
+        ("portal".to_owned(), image(4, 4, [0, 0, 255, 255])),
+        ("portal-2".to_owned(), image(4, 4, [255, 0, 0, 255])),
+    ];
+
+    let added = image(4, 4, [255, 0, 0, 255]);
+    assert_eq!(
+        find_duplicate_thumbnail(&added, "half-life", &cached),
+        Some("portal-2")
+    );
+}
+
+#[test]
+fn unique_image() {
+    let cached = vec![
+        ("portal".to_owned(), image(4, 4, [0, 0, 255, 255])),
+        ("portal-2".to_owned(), image(4, 4, [255, 0, 0, 255])),
+    ];
+
+    for added in [
+        image(4, 4, [0, 255, 0, 255]),
+        image(2, 8, [255, 0, 0, 255]),
+        image(4, 4, [255, 0, 0, 254]),
+    ] {
+        assert_eq!(find_duplicate_thumbnail(&added, "half-life", &cached), None);
+    }
+}
+
+#[test]
+fn own_slug_ignored() {
+    let cached = vec![("portal-2".to_owned(), image(4, 4, [255, 0, 0, 255]))];
+
+    assert_eq!(
+        find_duplicate_thumbnail(&image(4, 4, [255, 0, 0, 255]), "portal-2", &cached),
+        None
+    );
+}
+
+#[test]
+fn duplicate_in_cache() {
+    let dir = temp_dir("duplicate-thumbnails");
+
+    let cover = ::image::RgbaImage::from_pixel(30, 40, ::image::Rgba([10, 200, 30, 255]));
+    cover
+        .save(dir.join("portal-2.png"))
+        .expect("image should be writable");
+
+    let db_path = dir.join("thumbnails.db");
+    import_lutris_covers(&dir, vec!["portal-2".to_owned()], &db_path, 200)
+        .expect("import should succeed");
+
+    let added = thumbnail(cover.into(), 200);
+    assert_eq!(
+        find_cached_duplicate(&db_path, &added, "half-life").as_deref(),
+        Some("portal-2")
+    );
+    assert_eq!(
+        find_cached_duplicate(&db_path, &image(30, 40, [0, 0, 0, 255]), "half-life"),
+        None
+    );
+    assert_eq!(
+        find_cached_duplicate(&dir.join("missing.db"), &added, "half-life"),
+        None
+    );
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
@@ -0,0 +1,118 @@
+//! Test exporting batch selected games.
+
+pub mod common;
+
+use ::std::path::Path;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_formats::{
+    ExportFormat, ExportedGame, Game, GameId, LutrisGame, export_csv, export_json,
+};
+use ::spel_katalog_games::{GameWithThumb, State};
+use ::spel_katalog_settings::{Settings, SettingsStore};
+
+use crate::common::lutris_game;
+
+/// Create a lutris game, batch selected if requested.
+fn lutris(id: i64, name: &str, batch_selected: bool) -> GameWithThumb {
+    GameWithThumb {
+        batch_selected,
+        ..common::lutris(id, name)
+    }
+}
+
+#[test]
+fn export_includes_only_selected() {
+    let settings = Settings::from(SettingsStore::default());
+    let mut state = State::default();
+    state.merge_games(
+        vec![
+            lutris(1, "Celeste", true),
+            lutris(2, "Hades", false),
+            lutris(3, "Outer Wilds", true),
+        ],
+        |_| true,
+        &settings,
+        "",
+    );
+
+    let json =
+        export_json(state.batch_selected().map(|game| &game.game)).expect("games should export");
+    let mut exported =
+        ::serde_json::from_str::<Vec<ExportedGame>>(&json).expect("export should deserialize");
+    exported.sort_by_key(|game| game.id);
+
+    assert_eq!(
+        exported.iter().map(|game| game.id).collect::<Vec<_>>(),
+        [GameId::Lutris(1), GameId::Lutris(3)]
+    );
+
+    let csv = export_csv(state.batch_selected().map(|game| &game.game));
+    let mut rows = csv.lines().skip(1).collect::<Vec<_>>();
+    rows.sort();
+    assert_eq!(
+        rows,
+        [
+            "1,Celeste,linux,celeste,false,1",
+            "3,Outer Wilds,linux,outer-wilds,false,3"
+        ]
+    );
+}
+
+#[test]
+fn csv_quotes_fields() {
+    let game = Game::Lutris(LutrisGame {
+        slug: "quoted".to_owned(),
+        hidden: true,
+        installed_at: 0,
+        ..lutris_game(7, "Hello, \"World\"")
+    });
+
+    assert_eq!(
+        export_csv([&game]),
+        "id,name,runner,slug,hidden,installed_at\n7,\"Hello, \"\"World\"\"\",linux,quoted,true,0\n"
+    );
+    assert_eq!(export_csv([]), "id,name,runner,slug,hidden,installed_at\n");
+}
+
+#[test]
+fn json_of_native_game() {
+    let uuid = ::uuid::Uuid::nil();
+    let game = Game::Native {
+        name: "Native".to_owned(),
+        installed_at: 5,
+        uuid,
+        hidden: false,
+    };
+
+    let exported = ::serde_json::from_str::<Vec<ExportedGame>>(
+        &export_json([&game]).expect("game should export"),
+    )
+    .expect("export should deserialize");
+
+    assert_eq!(
+        exported,
+        [ExportedGame {
+            id: GameId::Native(uuid),
+            name: "Native".to_owned(),
+            runner: "native".to_owned(),
+            slug: None,
+            hidden: false,
+            installed_at: 5,
+        }]
+    );
+}
+
+#[test]
+fn format_from_extension() {
+    assert_eq!(
+        ExportFormat::from_path(Path::new("games.json")),
+        Some(ExportFormat::Json)
+    );
+    assert_eq!(
+        ExportFormat::from_path(Path::new("games.CSV")),
+        Some(ExportFormat::Csv)
+    );
+    assert_eq!(ExportFormat::from_path(Path::new("games.txt")), None);
+    assert_eq!(ExportFormat::from_path(Path::new("games")), None);
+}
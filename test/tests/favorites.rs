@@ -0,0 +1,73 @@
+//! Test favorite games.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_formats::AdditionalConfig;
+use ::spel_katalog_settings::Show;
+
+use crate::common::temp_dir;
+
+#[test]
+fn old_config_deserializes() {
+    let config = ::toml::from_str::<AdditionalConfig>(
+        r#"
+        sandbox_root = ["/games"]
+
+        [attrs]
+        key = "value"
+        "#,
+    )
+    .expect("config without favorite should deserialize");
+
+    assert!(!config.favorite);
+    assert_eq!(config.sandbox_root, ["/games"]);
+}
+
+#[test]
+fn favorite_serialization() {
+    let mut config = AdditionalConfig::default();
+    assert_eq!(
+        ::toml::to_string(&config).expect("config should serialize"),
+        ""
+    );
+
+    config.favorite = true;
+    let content = ::toml::to_string(&config).expect("config should serialize");
+    assert_eq!(content.trim(), "favorite = true");
+    assert_eq!(
+        ::toml::from_str::<AdditionalConfig>(&content).expect("config should deserialize"),
+        config,
+    );
+}
+
+#[test]
+fn favorites_filter() {
+    let cases = [(false, false), (false, true), (true, false), (true, true)];
+
+    let shown = |show: Show| {
+        cases
+            .into_iter()
+            .filter(|(hidden, favorite)| show.includes(*hidden, *favorite))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(shown(Show::Favorites), [(false, true), (true, true)]);
+    assert_eq!(shown(Show::Apparent), [(false, false), (false, true)]);
+    assert_eq!(shown(Show::Hidden), [(true, false), (true, true)]);
+    assert_eq!(shown(Show::All), cases);
+}
+
+#[test]
+fn load_favorites() {
+    let dir = temp_dir("favorites");
+    ::std::fs::write(dir.join("1.toml"), "favorite = true").expect("config should be writable");
+    ::std::fs::write(dir.join("2.toml"), "favorite = false").expect("config should be writable");
+    ::std::fs::write(dir.join("3.toml"), "").expect("config should be writable");
+    ::std::fs::write(dir.join("4.txt"), "favorite = true").expect("file should be writable");
+
+    let favorites = ::spel_katalog_gather::load_favorites(&dir);
+    _ = ::std::fs::remove_dir_all(&dir);
+
+    assert_eq!(favorites, [1]);
+}
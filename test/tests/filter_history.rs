@@ -0,0 +1,77 @@
+//! Test history of recent filters.
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog::FilterHistory;
+
+/// Get a path in the temporary directory unique to this process.
+fn temp_path(name: &str) -> ::std::path::PathBuf {
+    ::std::env::temp_dir().join(format!("spel-katalog-{}-{name}", ::std::process::id()))
+}
+
+/// Filters of history as a vec.
+fn filters(history: &FilterHistory) -> Vec<&str> {
+    history.iter().collect()
+}
+
+#[test]
+fn most_recent_first() {
+    let mut history = FilterHistory::default();
+
+    assert!(history.push("portal"));
+    assert!(history.push("half life"));
+    assert_eq!(filters(&history), ["half life", "portal"]);
+}
+
+#[test]
+fn blank_ignored() {
+    let mut history = FilterHistory::default();
+
+    assert!(!history.push(""));
+    assert!(!history.push("   "));
+    assert!(history.is_empty());
+}
+
+#[test]
+fn duplicates_moved_to_front() {
+    let mut history = FilterHistory::default();
+    history.push("portal");
+    history.push("half life");
+    history.push("doom");
+
+    assert!(history.push("portal"));
+    assert_eq!(filters(&history), ["portal", "doom", "half life"]);
+
+    assert!(!history.push("portal"));
+    assert_eq!(history.len(), 3);
+}
+
+#[test]
+fn capped() {
+    let mut history = FilterHistory::default();
+    for i in 0..FilterHistory::CAP + 3 {
+        history.push(&format!("filter {i}"));
+    }
+
+    assert_eq!(history.len(), FilterHistory::CAP);
+    assert_eq!(
+        history.iter().next(),
+        Some(format!("filter {}", FilterHistory::CAP + 2).as_str())
+    );
+    assert_eq!(history.iter().last(), Some("filter 3"));
+}
+
+#[test]
+fn round_trip() {
+    let mut history = FilterHistory::default();
+    history.push("portal");
+    history.push("half life");
+    let path = temp_path("filter-history.toml");
+
+    history
+        .write(&path)
+        .expect("filter history should be writable");
+    let read = FilterHistory::read(&path).expect("filter history should be readable");
+    _ = ::std::fs::remove_file(&path);
+
+    assert_eq!(history, read);
+}
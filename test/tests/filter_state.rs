@@ -0,0 +1,52 @@
+//! Test filter state persistence.
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_settings::{FilterMode, FilterState, Settings, SettingsStore, Show};
+
+/// Get a path in the temporary directory unique to this process.
+fn temp_path(name: &str) -> ::std::path::PathBuf {
+    ::std::env::temp_dir().join(format!("spel-katalog-{}-{name}", ::std::process::id()))
+}
+
+#[test]
+fn round_trip() {
+    let state = FilterState {
+        filter: "half life".to_owned(),
+        show: Some(Show::All),
+        filter_mode: Some(FilterMode::Regex),
+    };
+    let path = temp_path("round-trip.toml");
+
+    state.write(&path).expect("filter state should be writable");
+    let read = FilterState::read(&path).expect("filter state should be readable");
+    _ = ::std::fs::remove_file(&path);
+
+    assert_eq!(state, read);
+}
+
+#[test]
+fn empty_file_is_default() {
+    let path = temp_path("empty.toml");
+
+    ::std::fs::write(&path, "").expect("empty file should be writable");
+    let read = FilterState::read(&path).expect("empty file should be readable");
+    _ = ::std::fs::remove_file(&path);
+
+    assert_eq!(read, FilterState::default());
+}
+
+#[test]
+fn restore_settings() {
+    let mut settings = Settings::from(SettingsStore::default());
+    let state = FilterState {
+        filter: String::new(),
+        show: Some(Show::Hidden),
+        filter_mode: Some(FilterMode::Filter),
+    };
+
+    for delta in state.deltas() {
+        delta.apply(&mut settings);
+    }
+
+    assert_eq!(FilterState::capture("", &settings), state);
+}
@@ -0,0 +1,46 @@
+//! Test summaries shown when hovering games.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_formats::{Game, LutrisGame, LutrisRunner};
+
+use crate::common::lutris_game;
+
+#[test]
+fn lutris_summary() {
+    let game = Game::Lutris(LutrisGame {
+        runner: LutrisRunner::Wine,
+        // 2024-03-09 12:00:00 UTC
+        installed_at: 1_709_985_600,
+        ..lutris_game(1, "Portal 2")
+    });
+
+    assert_eq!(
+        game.summary(),
+        [
+            ("Runner", "wine".to_owned()),
+            ("Installed", "2024-03-09".to_owned()),
+            ("Slug", "portal-2".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn native_summary() {
+    let game = Game::Native {
+        name: "Celeste".to_owned(),
+        installed_at: 0,
+        uuid: ::uuid::Uuid::nil(),
+        hidden: true,
+    };
+
+    assert_eq!(
+        game.summary(),
+        [
+            ("Runner", "native".to_owned()),
+            ("Installed", "1970-01-01".to_owned()),
+            ("Hidden", "yes".to_owned()),
+        ]
+    );
+}
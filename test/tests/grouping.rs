@@ -0,0 +1,113 @@
+//! Test grouping of games.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_common::group_by_key;
+use ::spel_katalog_formats::{Game, LutrisGame, LutrisRunner};
+
+use crate::common::lutris_game;
+
+/// Create a lutris game using given runner.
+fn lutris(id: i64, name: &str, runner: &str) -> Game {
+    Game::Lutris(LutrisGame {
+        runner: LutrisRunner::from(runner),
+        ..lutris_game(id, name)
+    })
+}
+
+/// Games sorted by when they were added.
+fn games() -> Vec<Game> {
+    vec![
+        lutris(1, "Portal 2", "wine"),
+        lutris(2, "Celeste", "linux"),
+        lutris(3, "portal", "linux"),
+        lutris(4, "Hades", "wine"),
+        lutris(5, "7 Days to Die", "linux"),
+        lutris(6, "Pac-Man", "mame"),
+        lutris(7, "Ärtan", "wine"),
+    ]
+}
+
+/// Convert groups to keys and ids.
+fn ids<K>(groups: Vec<(K, Vec<&Game>)>) -> Vec<(K, Vec<i64>)> {
+    groups
+        .into_iter()
+        .map(|(key, games)| {
+            let ids = games
+                .into_iter()
+                .map(|game| match game {
+                    Game::Lutris(game) => game.id,
+                    Game::Native { .. } => unreachable!(),
+                })
+                .collect();
+            (key, ids)
+        })
+        .collect()
+}
+
+#[test]
+fn group_by_runner() {
+    let games = games();
+    let groups = group_by_key(&games, |game| game.runner_name(), false);
+
+    assert_eq!(
+        ids(groups),
+        [
+            ("linux".to_owned(), vec![2, 3, 5]),
+            ("mame".to_owned(), vec![6]),
+            ("wine".to_owned(), vec![1, 4, 7]),
+        ]
+    );
+}
+
+#[test]
+fn group_by_letter() {
+    let games = games();
+    let groups = group_by_key(&games, |game| game.initial(), false);
+
+    assert_eq!(
+        ids(groups),
+        [
+            ('#', vec![5]),
+            ('C', vec![2]),
+            ('H', vec![4]),
+            ('P', vec![1, 3, 6]),
+            ('Ä', vec![7]),
+        ]
+    );
+}
+
+#[test]
+fn sort_kept_within_groups() {
+    let mut games = games();
+    games.reverse();
+    let groups = group_by_key(&games, |game| game.initial(), false);
+
+    assert_eq!(
+        ids(groups).into_iter().find(|(initial, _)| *initial == 'P'),
+        Some(('P', vec![6, 3, 1]))
+    );
+}
+
+#[test]
+fn reverse_group_order() {
+    let mut games = games();
+    games.reverse();
+    let groups = group_by_key(&games, |game| game.runner_name(), true);
+
+    assert_eq!(
+        ids(groups),
+        [
+            ("wine".to_owned(), vec![7, 4, 1]),
+            ("mame".to_owned(), vec![6]),
+            ("linux".to_owned(), vec![5, 3, 2]),
+        ]
+    );
+}
+
+#[test]
+fn empty() {
+    let groups = group_by_key(Vec::<i32>::new(), |value| *value, false);
+    assert_eq!(groups, []);
+}
@@ -0,0 +1,51 @@
+//! Test choice of syntax highlighter theme.
+
+use ::iced_highlighter::Theme as Highlight;
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_settings::Theme;
+use ::spel_katalog_settings_view::highlighter_theme;
+
+#[test]
+fn light_themes() {
+    for theme in [
+        Theme::Light,
+        Theme::SolarizedLight,
+        Theme::GruvboxLight,
+        Theme::CatppuccinLatte,
+        Theme::TokyoNightLight,
+        Theme::KanagawaLotus,
+    ] {
+        assert_eq!(
+            highlighter_theme(theme),
+            Highlight::InspiredGitHub,
+            "{theme:?}"
+        );
+    }
+}
+
+#[test]
+fn dark_themes() {
+    for theme in [
+        Theme::Dark,
+        Theme::GruvboxDark,
+        Theme::Dracula,
+        Theme::Nord,
+        Theme::CatppuccinMocha,
+        Theme::TokyoNight,
+        Theme::Oxocarbon,
+    ] {
+        assert_eq!(
+            highlighter_theme(theme),
+            Highlight::Base16Mocha,
+            "{theme:?}"
+        );
+    }
+}
+
+#[test]
+fn solarized_dark_kept() {
+    assert_eq!(
+        highlighter_theme(Theme::SolarizedDark),
+        Highlight::SolarizedDark
+    );
+}
@@ -0,0 +1,65 @@
+//! Test integer settings.
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_settings::{
+    Delta, Settings, SettingsIndex, SettingsStore, TerminalLines, index::Int,
+};
+
+#[test]
+fn default_value() {
+    let settings = Settings::from(SettingsStore::default());
+
+    assert_eq!(settings.get::<TerminalLines>().get(), 256);
+    assert_eq!(settings.get::<TerminalLines>().limit(), 256);
+    assert_eq!(*Int::TerminalLines.get(&SettingsStore::default()), 256);
+}
+
+#[test]
+fn clamped_to_range() {
+    assert_eq!(TerminalLines::new(0).get(), TerminalLines::MIN);
+    assert_eq!(TerminalLines::new(-40).get(), 1);
+    assert_eq!(TerminalLines::new(1 << 20).get(), TerminalLines::MAX);
+    assert_eq!(TerminalLines::new(1 << 20).limit(), u16::MAX);
+
+    let mut lines = TerminalLines::new(64);
+    lines.set(70_000);
+    assert_eq!(lines.get(), 65535);
+    lines.set(512);
+    assert_eq!(i64::from(lines), 512);
+}
+
+#[test]
+fn parsed_from_str() {
+    assert_eq!(
+        " 512 ".parse::<TerminalLines>().ok(),
+        Some(TerminalLines::new(512))
+    );
+    assert_eq!(
+        "0".parse::<TerminalLines>().ok(),
+        Some(TerminalLines::new(1))
+    );
+    assert!("many".parse::<TerminalLines>().is_err());
+    assert_eq!(TerminalLines::new(128).to_string(), "128");
+}
+
+#[test]
+fn deserialized_clamped() {
+    let store = ::toml::from_str::<SettingsStore>("terminal_lines = 100000")
+        .expect("settings should deserialize");
+    assert_eq!(*Int::TerminalLines.get(&store), 65535);
+
+    let serialized = ::toml::to_string(&store).expect("settings should serialize");
+    assert_eq!(serialized.trim(), "terminal_lines = 65535");
+}
+
+#[test]
+fn applied_as_delta() {
+    let mut settings = Settings::from(SettingsStore::default());
+    Delta::TerminalLines(TerminalLines::new(1024)).apply(&mut settings);
+
+    assert_eq!(settings.get::<TerminalLines>().get(), 1024);
+    assert_eq!(
+        settings.generic().get("TerminalLines").map(String::as_str),
+        Some("1024")
+    );
+}
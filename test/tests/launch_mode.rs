@@ -0,0 +1,81 @@
+//! Test configuration of detached game launches.
+
+use ::std::process::Stdio;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_run::game_command;
+use ::spel_katalog_settings::{Delta, LaunchMode, Settings, SettingsStore};
+
+/// Get process and session id from the content of a `/proc/<pid>/stat` file.
+fn session_id(stat: &str) -> (u32, u32) {
+    // Session is the fourth field after the parenthesized command name.
+    let (pid, fields) = stat
+        .split_once(" (")
+        .expect("stat should contain a command name");
+    let (_, fields) = fields
+        .rsplit_once(')')
+        .expect("stat should contain a command name");
+    let sid = fields
+        .split_whitespace()
+        .nth(3)
+        .expect("stat should contain a session id");
+    (
+        pid.parse().expect("pid should be a number"),
+        sid.parse().expect("session id should be a number"),
+    )
+}
+
+/// Run a shell through [game_command] printing its stat, returning its process and session id.
+fn command_session_id(launch_mode: LaunchMode) -> (u32, u32) {
+    let output = ::smol::block_on(
+        game_command("sh", launch_mode, Stdio::piped(), Stdio::null())
+            .args(["-c", "cat /proc/$$/stat"])
+            .output(),
+    )
+    .expect("sh should run");
+    assert!(output.status.success());
+    session_id(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Get process and session id of current process.
+fn own_session_id() -> (u32, u32) {
+    session_id(&::std::fs::read_to_string("/proc/self/stat").expect("stat should be readable"))
+}
+
+#[test]
+fn attached_by_default() {
+    let settings = Settings::from(SettingsStore::default());
+    assert_eq!(*settings.get::<LaunchMode>(), LaunchMode::Attached);
+}
+
+#[test]
+fn attached_configuration() {
+    let mode = LaunchMode::Attached;
+    assert!(mode.kill_on_drop());
+    assert!(!mode.new_session());
+    assert!(mode.routes_output());
+}
+
+#[test]
+fn detached_configuration() {
+    let mut settings = Settings::from(SettingsStore::default());
+    Delta::LaunchMode(LaunchMode::Detached).apply(&mut settings);
+
+    let mode = *settings.get::<LaunchMode>();
+    assert!(!mode.kill_on_drop());
+    assert!(mode.new_session());
+    assert!(!mode.routes_output());
+}
+
+#[test]
+fn attached_command_shares_session() {
+    let (_, sid) = command_session_id(LaunchMode::Attached);
+    assert_eq!(sid, own_session_id().1);
+}
+
+#[test]
+fn detached_command_leads_new_session() {
+    let (pid, sid) = command_session_id(LaunchMode::Detached);
+    assert_eq!(sid, pid);
+    assert_ne!(sid, own_session_id().1);
+}
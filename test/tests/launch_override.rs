@@ -0,0 +1,85 @@
+//! Test per-game launch command overrides.
+
+use ::std::{ffi::OsString, path::Path};
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_formats::AdditionalConfig;
+
+/// Create additional config with given launch override.
+fn with_override(launch_override: &str) -> AdditionalConfig {
+    AdditionalConfig {
+        launch_override: Some(launch_override.to_owned()),
+        ..Default::default()
+    }
+}
+
+/// Convert strings to an expected command line.
+fn expected(args: &[&str]) -> Option<Vec<OsString>> {
+    Some(args.iter().map(OsString::from).collect())
+}
+
+#[test]
+fn default_without_override() {
+    let config = AdditionalConfig::default();
+    assert_eq!(
+        config
+            .launch_command(Path::new("/games/portal/hl2.exe"), None)
+            .expect("config should be valid"),
+        None
+    );
+    assert_eq!(
+        with_override("  ")
+            .launch_command(Path::new("/games/portal/hl2.exe"), None)
+            .expect("config should be valid"),
+        None
+    );
+}
+
+#[test]
+fn override_takes_precedence() {
+    let config = ::toml::from_str::<AdditionalConfig>(
+        r#"launch_override = "/games/portal/start.sh --fullscreen""#,
+    )
+    .expect("config should parse");
+
+    assert_eq!(
+        config
+            .launch_command(Path::new("/games/portal/hl2.exe"), None)
+            .expect("config should be valid"),
+        expected(&["/games/portal/start.sh", "--fullscreen"])
+    );
+}
+
+#[test]
+fn placeholders_interpolated() {
+    let config = with_override("wine {exe} '--prefix={prefix}'");
+
+    assert_eq!(
+        config
+            .launch_command(
+                Path::new("/games/my game/game.exe"),
+                Some(Path::new("/games/my game/pfx")),
+            )
+            .expect("config should be valid"),
+        expected(&[
+            "wine",
+            "/games/my game/game.exe",
+            "--prefix=/games/my game/pfx"
+        ])
+    );
+    assert_eq!(
+        config
+            .launch_command(Path::new("/games/game.exe"), None)
+            .expect("config should be valid"),
+        expected(&["wine", "/games/game.exe", "--prefix="])
+    );
+}
+
+#[test]
+fn unbalanced_quotes() {
+    assert!(
+        with_override("wine '{exe}")
+            .launch_command(Path::new("/games/game.exe"), None)
+            .is_err()
+    );
+}
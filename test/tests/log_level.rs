@@ -0,0 +1,56 @@
+//! Test changing log level at runtime.
+
+use ::core::sync::atomic::{AtomicUsize, Ordering};
+
+use ::log::LevelFilter;
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_settings::LogLevel;
+
+/// Logger counting emitted records.
+struct CountingLogger(AtomicUsize);
+
+impl ::log::Log for CountingLogger {
+    fn enabled(&self, _metadata: &::log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, _record: &::log::Record) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn flush(&self) {}
+}
+
+/// Logger used by test.
+static LOGGER: CountingLogger = CountingLogger(AtomicUsize::new(0));
+
+#[test]
+fn level_filter_mapping() {
+    assert_eq!(LogLevel::Error.level_filter(), LevelFilter::Error);
+    assert_eq!(LogLevel::Warn.level_filter(), LevelFilter::Warn);
+    assert_eq!(LogLevel::Info.level_filter(), LevelFilter::Info);
+    assert_eq!(LogLevel::Debug.level_filter(), LevelFilter::Debug);
+    assert_eq!(LogLevel::Trace.level_filter(), LevelFilter::Trace);
+    assert_eq!(LevelFilter::from(LogLevel::default()), LevelFilter::Info);
+}
+
+#[test]
+fn applied_level_filters_records() {
+    ::log::set_logger(&LOGGER).expect("logger should only be set once");
+    let count = || LOGGER.0.load(Ordering::SeqCst);
+
+    LogLevel::Warn.apply();
+    ::log::info!("filtered");
+    ::log::warn!("emitted");
+    assert_eq!(count(), 1);
+
+    LogLevel::Debug.apply();
+    ::log::info!("emitted");
+    ::log::debug!("emitted");
+    ::log::trace!("filtered");
+    assert_eq!(count(), 3);
+
+    LogLevel::Error.apply();
+    ::log::warn!("filtered");
+    assert_eq!(count(), 3);
+}
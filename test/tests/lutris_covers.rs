@@ -0,0 +1,99 @@
+//! Test importing thumbnails from lutris coverart.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_gather::{
+    find_lutris_cover, import_lutris_covers, load_thumbnail_database, lutris_coverart_dir,
+};
+
+use crate::common::temp_dir;
+
+#[test]
+fn slug_to_file() {
+    let dir = temp_dir("lutris-slugs");
+    for file in [
+        "portal-2.jpg",
+        "celeste.jpg",
+        "celeste.png",
+        "hades.jpeg",
+        "half-life.txt",
+        "half-life-2.jpg.bak",
+    ] {
+        ::std::fs::write(dir.join(file), "").expect("file should be writable");
+    }
+    ::std::fs::create_dir(dir.join("outer-wilds.png")).expect("dir should be creatable");
+
+    assert_eq!(
+        find_lutris_cover(&dir, "portal-2"),
+        Some(dir.join("portal-2.jpg"))
+    );
+    assert_eq!(
+        find_lutris_cover(&dir, "celeste"),
+        Some(dir.join("celeste.png"))
+    );
+    assert_eq!(
+        find_lutris_cover(&dir, "hades"),
+        Some(dir.join("hades.jpeg"))
+    );
+    assert_eq!(find_lutris_cover(&dir, "portal"), None);
+    assert_eq!(find_lutris_cover(&dir, "half-life"), None);
+    assert_eq!(find_lutris_cover(&dir, "half-life-2"), None);
+    assert_eq!(find_lutris_cover(&dir, "outer-wilds"), None);
+    assert_eq!(find_lutris_cover(&dir, ""), None);
+    assert_eq!(find_lutris_cover(&dir, "../portal-2"), None);
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
+
+#[test]
+fn coverart_dir_resolution() {
+    let dir = temp_dir("lutris-resolve");
+    let (data_home, home) = (dir.join("data"), dir.join("home"));
+
+    assert_eq!(lutris_coverart_dir(&data_home, &home), None);
+
+    let flatpak = home.join(".var/app/net.lutris.Lutris/data/lutris/coverart");
+    ::std::fs::create_dir_all(&flatpak).expect("dir should be creatable");
+    assert_eq!(lutris_coverart_dir(&data_home, &home), Some(flatpak));
+
+    let native = data_home.join("lutris/coverart");
+    ::std::fs::create_dir_all(&native).expect("dir should be creatable");
+    assert_eq!(lutris_coverart_dir(&data_home, &home), Some(native));
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
+
+#[test]
+fn import_into_cache() {
+    let dir = temp_dir("lutris-import");
+    let coverart = dir.join("coverart");
+    ::std::fs::create_dir(&coverart).expect("dir should be creatable");
+
+    ::image::RgbaImage::from_pixel(40, 20, ::image::Rgba([255, 0, 0, 255]))
+        .save(coverart.join("portal-2.png"))
+        .expect("image should be writable");
+    ::std::fs::write(coverart.join("broken.jpg"), "not an image").expect("file should be writable");
+
+    let db_path = dir.join("thumbnails.db");
+    let imported = import_lutris_covers(
+        &coverart,
+        vec![
+            "portal-2".to_owned(),
+            "broken".to_owned(),
+            "missing".to_owned(),
+        ],
+        &db_path,
+        10,
+    )
+    .expect("import should succeed");
+    assert_eq!(imported, ["portal-2"]);
+
+    let loaded = load_thumbnail_database(&db_path).expect("database should be loadable");
+    assert_eq!(loaded.len(), 1);
+    let (slug, image) = &loaded[0];
+    assert_eq!(slug, "portal-2");
+    assert_eq!((image.width, image.height), (10, 5));
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
@@ -0,0 +1,78 @@
+//! Test assembly of opener command lines.
+
+use ::std::{ffi::OsString, path::Path};
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_settings::{Delta, Opener, Settings, SettingsStore};
+
+/// Command line used by opener for given path.
+fn command_line(opener: &str, path: &str) -> Option<Vec<OsString>> {
+    Opener::new(opener.to_owned())
+        .command_line(Path::new(path))
+        .expect("opener should be valid")
+}
+
+/// Convert strings to an expected command line.
+fn expected(args: &[&str]) -> Option<Vec<OsString>> {
+    Some(args.iter().map(OsString::from).collect())
+}
+
+#[test]
+fn system_default_when_empty() {
+    let settings = Settings::from(SettingsStore::default());
+    assert_eq!(
+        settings
+            .get::<Opener>()
+            .command_line(Path::new("/games"))
+            .expect("opener should be valid"),
+        None
+    );
+    assert_eq!(command_line("  ", "/games"), None);
+}
+
+#[test]
+fn path_appended_without_placeholder() {
+    assert_eq!(
+        command_line("thunar", "/games/portal"),
+        expected(&["thunar", "/games/portal"])
+    );
+    assert_eq!(
+        command_line("kitty -e ranger", "/games/my game"),
+        expected(&["kitty", "-e", "ranger", "/games/my game"])
+    );
+}
+
+#[test]
+fn placeholder_interpolated() {
+    assert_eq!(
+        command_line("dolphin --select {path} --new-window", "/games/portal"),
+        expected(&["dolphin", "--select", "/games/portal", "--new-window"])
+    );
+    assert_eq!(
+        command_line("nnn '--dir={path}'", "/games/my game"),
+        expected(&["nnn", "--dir=/games/my game"])
+    );
+}
+
+#[test]
+fn set_from_settings() {
+    let mut settings = Settings::from(SettingsStore::default());
+    Delta::Opener(Opener::new("pcmanfm".to_owned())).apply(&mut settings);
+
+    assert_eq!(
+        settings
+            .get::<Opener>()
+            .command_line(Path::new("/games"))
+            .expect("opener should be valid"),
+        expected(&["pcmanfm", "/games"])
+    );
+}
+
+#[test]
+fn unbalanced_quotes() {
+    assert!(
+        Opener::new("thunar 'oops".to_owned())
+            .command_line(Path::new("/games"))
+            .is_err()
+    );
+}
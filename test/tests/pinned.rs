@@ -0,0 +1,89 @@
+//! Test sorting of pinned games.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_formats::GameId;
+use ::spel_katalog_games::Games;
+use ::spel_katalog_settings::{Delta, Settings, SettingsStore, SortBy, SortDir};
+
+use crate::common::{lutris, temp_dir};
+
+/// Names of displayed games in order.
+fn displayed(games: &Games) -> Vec<&str> {
+    games.displayed().map(|game| game.name()).collect()
+}
+
+/// Games with a some pinned.
+fn games(settings: &Settings) -> Games {
+    let mut games = Games::default();
+    games.set(
+        vec![
+            lutris(1, "Celeste"),
+            lutris(2, "Hades"),
+            lutris(3, "Portal"),
+            lutris(4, "Braid"),
+        ],
+        settings,
+        "",
+    );
+    assert!(games.set_pinned([GameId::Lutris(1), GameId::Lutris(3)], true));
+    games.sort(settings, "");
+    games
+}
+
+#[test]
+fn pinned_first_by_added() {
+    let settings = Settings::from(SettingsStore::default());
+    let games = games(&settings);
+
+    assert_eq!(displayed(&games), ["Portal", "Celeste", "Braid", "Hades"]);
+}
+
+#[test]
+fn pinned_first_by_name() {
+    let mut settings = Settings::from(SettingsStore::default());
+    Delta::SortBy(SortBy::Name).apply(&mut settings);
+    let games = games(&settings);
+
+    assert_eq!(displayed(&games), ["Celeste", "Portal", "Braid", "Hades"]);
+}
+
+#[test]
+fn pinned_first_when_reversed() {
+    let mut settings = Settings::from(SettingsStore::default());
+    Delta::SortBy(SortBy::Name).apply(&mut settings);
+    Delta::SortDir(SortDir::Reverse).apply(&mut settings);
+    let games = games(&settings);
+
+    assert_eq!(displayed(&games), ["Portal", "Celeste", "Hades", "Braid"]);
+}
+
+#[test]
+fn unpinned_returns_to_order() {
+    let settings = Settings::from(SettingsStore::default());
+    let mut games = games(&settings);
+
+    assert!(games.set_pinned([GameId::Lutris(3)], false));
+    assert!(!games.set_pinned([GameId::Lutris(3)], false));
+    games.sort(&settings, "");
+
+    assert_eq!(displayed(&games), ["Celeste", "Braid", "Portal", "Hades"]);
+}
+
+#[test]
+fn load_pinned() {
+    let dir = temp_dir("pinned");
+    ::std::fs::write(dir.join("1.toml"), "pinned = true").expect("config should be writable");
+    ::std::fs::write(dir.join("2.toml"), "favorite = true").expect("config should be writable");
+    ::std::fs::write(dir.join("3.toml"), "favorite = true\npinned = true")
+        .expect("config should be writable");
+
+    let mut pinned = ::spel_katalog_gather::load_pinned(&dir);
+    pinned.sort();
+    let favorites = ::spel_katalog_gather::load_favorites(&dir);
+    _ = ::std::fs::remove_dir_all(&dir);
+
+    assert_eq!(pinned, [1, 3]);
+    assert_eq!(favorites.len(), 2);
+}
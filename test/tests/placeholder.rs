@@ -0,0 +1,38 @@
+//! Test colors of thumbnail placeholders.
+
+use ::pretty_assertions::{assert_eq, assert_ne};
+use ::spel_katalog_common::styling::name_color;
+
+#[test]
+fn stable_per_name() {
+    for name in ["Portal 2", "Half-Life", "", "ÅÄÖ"] {
+        assert_eq!(name_color(name), name_color(name));
+        assert_eq!(name_color(name), name_color(&name.to_owned()));
+    }
+}
+
+#[test]
+fn known_color() {
+    // Colors must not change between runs or versions.
+    let color = name_color("Portal 2");
+    for (channel, expected) in [(color.r, 0.6525), (color.g, 0.41625), (color.b, 0.2475)] {
+        assert!((channel - expected).abs() < 1e-4, "{color:?}");
+    }
+    assert_eq!(color.a, 1.0);
+}
+
+#[test]
+fn differs_between_names() {
+    assert_ne!(name_color("Portal"), name_color("Portal 2"));
+    assert_ne!(name_color("Half-Life"), name_color("Half-Life 2"));
+}
+
+#[test]
+fn in_range() {
+    for name in ["a", "b", "c", "Celeste", "Hades", "Outer Wilds"] {
+        let color = name_color(name);
+        for channel in [color.r, color.g, color.b] {
+            assert!((0.0..=1.0).contains(&channel), "{name}: {color:?}");
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! Test process tree traversal using a mocked `/proc` directory.
+
+pub mod common;
+
+use ::std::path::Path;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_common::{process_children, process_tree};
+
+use crate::common::temp_dir;
+
+/// Write the children of a task of a process.
+fn write_children(root: &Path, pid: i64, task: i64, children: &[i64]) {
+    let dir = root
+        .join(pid.to_string())
+        .join("task")
+        .join(task.to_string());
+    ::std::fs::create_dir_all(&dir).unwrap();
+    let content = children
+        .iter()
+        .map(|child| format!("{child} "))
+        .collect::<String>();
+    ::std::fs::write(dir.join("children"), content).unwrap();
+}
+
+#[test]
+fn children_of_all_tasks() {
+    let root = temp_dir("proc-children");
+    write_children(&root, 10, 10, &[12]);
+    write_children(&root, 10, 11, &[14, 13]);
+
+    assert_eq!(process_children(&root, 10), vec![12, 13, 14]);
+    assert_eq!(process_children(&root, 99), Vec::<i64>::new());
+
+    ::std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn whole_tree() {
+    let root = temp_dir("proc-tree");
+    write_children(&root, 1, 1, &[2, 3]);
+    write_children(&root, 2, 2, &[4]);
+    write_children(&root, 3, 3, &[]);
+    write_children(&root, 4, 4, &[5]);
+    write_children(&root, 4, 6, &[7]);
+    write_children(&root, 100, 100, &[101]);
+
+    assert_eq!(process_tree(&root, 1), vec![1, 2, 3, 4, 5, 7]);
+    assert_eq!(process_tree(&root, 4), vec![4, 5, 7]);
+    assert_eq!(process_tree(&root, 5), vec![5]);
+
+    ::std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn cycles_visited_once() {
+    let root = temp_dir("proc-cycle");
+    write_children(&root, 1, 1, &[2]);
+    write_children(&root, 2, 2, &[1, 3]);
+
+    assert_eq!(process_tree(&root, 1), vec![1, 2, 3]);
+
+    ::std::fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,101 @@
+//! Test match selection of quick launch.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_formats::{Game, GameId, LutrisGame};
+use ::spel_katalog_games::{GameWithThumb, Games, QuickLaunch};
+use ::spel_katalog_settings::{Delta, Settings, SettingsStore, Show};
+
+use crate::common::{lutris_game, with_thumb};
+
+/// Create a lutris game, hidden if requested.
+fn lutris(id: i64, name: &str, hidden: bool) -> GameWithThumb {
+    with_thumb(Game::Lutris(LutrisGame {
+        hidden,
+        ..lutris_game(id, name)
+    }))
+}
+
+/// Games to match against.
+fn games(settings: &Settings) -> Games {
+    let mut games = Games::default();
+    games.set(
+        vec![
+            lutris(1, "Portal", false),
+            lutris(2, "Portal 2", false),
+            lutris(3, "Hades", false),
+            lutris(4, "Hollow Knight", false),
+            lutris(5, "Postal", true),
+        ],
+        settings,
+        "",
+    );
+    games
+}
+
+#[test]
+fn contained_ranked_first() {
+    let settings = Settings::from(SettingsStore::default());
+    let games = games(&settings);
+
+    assert_eq!(
+        games.quick_matches("portal", &settings, 8)[..2],
+        [GameId::Lutris(1), GameId::Lutris(2)]
+    );
+    assert_eq!(
+        games.quick_matches("hol", &settings, 1),
+        [GameId::Lutris(4)]
+    );
+    assert_eq!(games.quick_matches("  ", &settings, 8), []);
+}
+
+#[test]
+fn respects_show() {
+    let mut settings = Settings::from(SettingsStore::default());
+    let games = games(&settings);
+
+    assert!(
+        !games
+            .quick_matches("postal", &settings, 8)
+            .contains(&GameId::Lutris(5))
+    );
+
+    Delta::Show(Show::All).apply(&mut settings);
+    assert_eq!(
+        games.quick_matches("postal", &settings, 1),
+        [GameId::Lutris(5)]
+    );
+}
+
+#[test]
+fn submit_runs_top_match() {
+    let settings = Settings::from(SettingsStore::default());
+    let games = games(&settings);
+
+    let mut quick_launch = QuickLaunch::default();
+    assert_eq!(quick_launch.clone().submit(), None);
+
+    quick_launch.set_query("hades".to_owned(), &games, &settings);
+    assert_eq!(quick_launch.query(), "hades");
+    assert_eq!(quick_launch.top(), Some(GameId::Lutris(3)));
+    assert!(quick_launch.matches().len() <= QuickLaunch::MAX_MATCHES);
+    assert_eq!(quick_launch.submit(), Some(GameId::Lutris(3)));
+}
+
+#[test]
+fn dissimilar_not_matched() {
+    let settings = Settings::from(SettingsStore::default());
+    let games = games(&settings);
+
+    assert_eq!(games.quick_matches("xyz", &settings, 8), []);
+    assert_eq!(
+        games.quick_matches("hdaes", &settings, 8),
+        [GameId::Lutris(3)]
+    );
+
+    let mut quick_launch = QuickLaunch::default();
+    quick_launch.set_query("xyz".to_owned(), &games, &settings);
+    assert_eq!(quick_launch.top(), None);
+    assert_eq!(quick_launch.submit(), None);
+}
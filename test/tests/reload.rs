@@ -0,0 +1,95 @@
+//! Test reloading games.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_formats::GameId;
+use ::spel_katalog_games::{GameWithThumb, SelDir, State};
+use ::spel_katalog_settings::{Settings, SettingsStore};
+
+use crate::common::lutris;
+
+/// Is a game a lutris game.
+fn is_lutris(game: &GameWithThumb) -> bool {
+    matches!(game.id(), GameId::Lutris(..))
+}
+
+/// Ids of all games.
+fn ids(state: &State) -> Vec<GameId> {
+    let mut ids = state.all().iter().map(|game| game.id()).collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn selection_kept_when_game_remains() {
+    let settings = Settings::from(SettingsStore::default());
+    let mut state = State::default();
+
+    state.merge_games(
+        vec![lutris(1, "Celeste"), lutris(2, "Hades")],
+        is_lutris,
+        &settings,
+        "",
+    );
+    state.select(SelDir::Right);
+    let selected = state.selected().expect("a game should be selected");
+
+    state.merge_games(
+        vec![
+            lutris(1, "Celeste"),
+            lutris(2, "Hades"),
+            lutris(3, "Portal"),
+        ],
+        is_lutris,
+        &settings,
+        "",
+    );
+
+    assert_eq!(state.selected(), Some(selected));
+    assert_eq!(
+        ids(&state),
+        [GameId::Lutris(1), GameId::Lutris(2), GameId::Lutris(3)]
+    );
+}
+
+#[test]
+fn selection_dropped_when_game_removed() {
+    let settings = Settings::from(SettingsStore::default());
+    let mut state = State::default();
+
+    state.merge_games(vec![lutris(1, "Celeste")], is_lutris, &settings, "");
+    state.select(SelDir::Right);
+    assert_eq!(state.selected(), Some(GameId::Lutris(1)));
+
+    state.merge_games(vec![lutris(2, "Hades")], is_lutris, &settings, "");
+
+    assert_eq!(state.selected(), None);
+    assert_eq!(ids(&state), [GameId::Lutris(2)]);
+}
+
+#[test]
+fn game_state_kept() {
+    let settings = Settings::from(SettingsStore::default());
+    let mut state = State::default();
+
+    let mut celeste = lutris(1, "Celeste");
+    celeste.batch_selected = true;
+    celeste.favorite = true;
+    state.merge_games(vec![celeste, lutris(2, "Hades")], is_lutris, &settings, "");
+
+    state.merge_games(
+        vec![lutris(1, "Celeste 2"), lutris(2, "Hades")],
+        is_lutris,
+        &settings,
+        "",
+    );
+
+    let celeste = state
+        .by_id(GameId::Lutris(1))
+        .expect("game should remain after reload");
+    assert_eq!(celeste.name(), "Celeste 2");
+    assert!(celeste.batch_selected);
+    assert!(celeste.favorite);
+    assert_eq!(state.batch_selected().count(), 1);
+}
@@ -0,0 +1,161 @@
+//! Test fetching covers from a list of urls.
+
+pub mod common;
+
+use ::std::{collections::BTreeMap, path::Path, time::Duration};
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_gather::{
+    FetchCoverError, RemoteCoverOptions, RemoteCoverReport, fetch_cover, fetch_covers,
+    parse_cover_urls,
+};
+
+use crate::common::temp_dir;
+
+/// Write a png of given size, returning a file url to it.
+fn write_png(dir: &Path, name: &str, width: u32, height: u32) -> String {
+    let path = dir.join(name);
+    ::image::RgbaImage::from_pixel(width, height, ::image::Rgba([255, 0, 0, 255]))
+        .save(&path)
+        .expect("png should be writable");
+    format!("file://{}", path.display())
+}
+
+#[test]
+fn parse_url_list() {
+    let urls = parse_cover_urls(
+        r#"
+game-b = "http://example.com/b.png"
+game-a = "file:///covers/a.png"
+"#,
+    )
+    .expect("list should parse");
+
+    assert_eq!(
+        urls.into_iter().collect::<Vec<_>>(),
+        vec![
+            ("game-a".to_owned(), "file:///covers/a.png".to_owned()),
+            ("game-b".to_owned(), "http://example.com/b.png".to_owned()),
+        ]
+    );
+    assert!(parse_cover_urls("game = 5").is_err());
+}
+
+#[test]
+fn file_url_to_thumbnail() {
+    let dir = temp_dir("remote-covers-file");
+    let url = write_png(&dir, "cover.png", 40, 20);
+
+    let image = ::smol::block_on(fetch_cover(&url, 10, Duration::from_secs(10)))
+        .expect("cover should be fetched");
+    assert_eq!((image.width, image.height), (10, 5));
+    assert_eq!(image.bytes.len(), 10 * 5 * 4);
+
+    let image = ::smol::block_on(fetch_cover(&url, 0, Duration::from_secs(10)))
+        .expect("cover should be fetched");
+    assert_eq!((image.width, image.height), (40, 20));
+}
+
+#[test]
+fn percent_encoded_file_url() {
+    let dir = temp_dir("remote-covers-encoded");
+    write_png(&dir, "my cover%.png", 6, 3);
+    let url = format!("file://{}/my%20cover%25.png", dir.display());
+
+    let image = ::smol::block_on(fetch_cover(&url, 0, Duration::from_secs(10)))
+        .expect("percent encoded cover should be fetched");
+    assert_eq!((image.width, image.height), (6, 3));
+}
+
+#[test]
+fn fetch_errors() {
+    let dir = temp_dir("remote-covers-errors");
+    let missing = format!("file://{}", dir.join("missing.png").display());
+    let garbage = dir.join("garbage.png");
+    ::std::fs::write(&garbage, "not an image").expect("file should be writable");
+    let garbage = format!("file://{}", garbage.display());
+
+    let fetch = |url: &str| ::smol::block_on(fetch_cover(url, 10, Duration::from_secs(10)));
+
+    assert!(matches!(fetch(&missing), Err(FetchCoverError::Read { .. })));
+    assert!(matches!(fetch(&garbage), Err(FetchCoverError::Decode(..))));
+    assert!(matches!(
+        fetch("ftp://example.com/a.png"),
+        Err(FetchCoverError::UnsupportedUrl(..))
+    ));
+}
+
+#[test]
+fn fetch_and_aggregate() {
+    let dir = temp_dir("remote-covers-aggregate");
+    let urls = BTreeMap::from([
+        ("c".to_owned(), write_png(&dir, "c.png", 8, 8)),
+        ("a".to_owned(), write_png(&dir, "a.png", 4, 4)),
+        ("b".to_owned(), "ftp://example.com/b.png".to_owned()),
+        (
+            "d".to_owned(),
+            format!("file://{}", dir.join("d.png").display()),
+        ),
+    ]);
+
+    let results = ::smol::block_on(fetch_covers(
+        urls,
+        &RemoteCoverOptions {
+            concurrency: 2,
+            ..Default::default()
+        },
+    ));
+    assert_eq!(
+        results
+            .iter()
+            .map(|(slug, _)| slug.as_str())
+            .collect::<Vec<_>>(),
+        ["a", "b", "c", "d"]
+    );
+
+    let (report, images) = RemoteCoverReport::split(results);
+    assert_eq!(report.succeeded, ["a", "c"]);
+    assert_eq!(
+        report
+            .failed
+            .iter()
+            .map(|(slug, _)| slug.as_str())
+            .collect::<Vec<_>>(),
+        ["b", "d"]
+    );
+    assert_eq!(
+        images
+            .iter()
+            .map(|(slug, image)| (slug.as_str(), image.width))
+            .collect::<Vec<_>>(),
+        [("a", 4), ("c", 8)]
+    );
+    assert_eq!(report.summary(), "fetched 2 covers, 2 failed");
+}
+
+#[test]
+fn split_results() {
+    let results = vec![
+        ("z".to_owned(), Ok(1)),
+        ("x".to_owned(), Err("broken")),
+        ("y".to_owned(), Ok(2)),
+        ("w".to_owned(), Err("gone")),
+    ];
+
+    let (report, values) = RemoteCoverReport::split(results);
+    assert_eq!(
+        report,
+        RemoteCoverReport {
+            succeeded: vec!["y".to_owned(), "z".to_owned()],
+            failed: vec![
+                ("w".to_owned(), "gone".to_owned()),
+                ("x".to_owned(), "broken".to_owned()),
+            ],
+        }
+    );
+    assert_eq!(values, [("y".to_owned(), 2), ("z".to_owned(), 1)]);
+
+    let (report, values) = RemoteCoverReport::split(Vec::<(String, Result<(), &str>)>::new());
+    assert_eq!(report, RemoteCoverReport::default());
+    assert_eq!(values, []);
+}
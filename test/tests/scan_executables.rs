@@ -0,0 +1,118 @@
+//! Test importing games from a directory of executables.
+
+pub mod common;
+
+use ::std::{os::unix::fs::PermissionsExt, path::Path};
+
+use ::pretty_assertions::assert_eq;
+use ::rustc_hash::FxHashSet;
+use ::spel_katalog_formats::{NativeRunner, Timestamp};
+use ::spel_katalog_gather::{executable_runner, remove_known, scan_executables};
+
+use crate::common::temp_dir;
+
+/// Write a file with given permission mode.
+fn write_file(dir: &Path, name: &str, mode: u32) {
+    let path = dir.join(name);
+    ::std::fs::write(&path, "").expect("file should be writable");
+    ::std::fs::set_permissions(&path, ::std::fs::Permissions::from_mode(mode))
+        .expect("permissions should be settable");
+}
+
+#[test]
+fn runner_by_extension_and_mode() {
+    let dir = temp_dir("scan-runner");
+    write_file(&dir, "Setup.EXE", 0o644);
+    write_file(&dir, "launch.bat", 0o644);
+    write_file(&dir, "install.msi", 0o644);
+    write_file(&dir, "game.x86_64", 0o755);
+    write_file(&dir, "readme.txt", 0o644);
+    ::std::fs::create_dir(dir.join("data")).expect("dir should be creatable");
+
+    assert_eq!(
+        executable_runner(&dir.join("Setup.EXE")),
+        Some(NativeRunner::Wine)
+    );
+    assert_eq!(
+        executable_runner(&dir.join("launch.bat")),
+        Some(NativeRunner::Wine)
+    );
+    assert_eq!(
+        executable_runner(&dir.join("game.x86_64")),
+        Some(NativeRunner::Linux)
+    );
+    assert_eq!(executable_runner(&dir.join("install.msi")), None);
+    assert_eq!(executable_runner(&dir.join("readme.txt")), None);
+    assert_eq!(executable_runner(&dir.join("data")), None);
+    assert_eq!(executable_runner(&dir.join("missing")), None);
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
+
+#[test]
+fn directory_to_games() {
+    let dir = temp_dir("scan-games");
+    write_file(&dir, "Portal 2.exe", 0o644);
+    write_file(&dir, "Celeste", 0o755);
+    write_file(&dir, "notes.md", 0o644);
+
+    let games = scan_executables(&dir, Timestamp::now()).expect("dir should be scannable");
+
+    let summary = games
+        .iter()
+        .map(|game| {
+            (
+                game.name.as_str(),
+                game.runner,
+                game.exe.clone(),
+                game.attrs.get("slug").map(String::as_str),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            (
+                "Celeste",
+                NativeRunner::Linux,
+                dir.join("Celeste"),
+                Some("celeste")
+            ),
+            (
+                "Portal 2",
+                NativeRunner::Wine,
+                dir.join("Portal 2.exe"),
+                Some("portal-2")
+            ),
+        ]
+    );
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
+
+#[test]
+fn known_games_removed() {
+    let dir = temp_dir("scan-known");
+    write_file(&dir, "Portal 2.exe", 0o644);
+    write_file(&dir, "Celeste", 0o755);
+    write_file(&dir, "Hades.exe", 0o644);
+
+    let games = scan_executables(&dir, Timestamp::now()).expect("dir should be scannable");
+    let slugs = FxHashSet::from_iter(["portal-2".to_owned()]);
+    let exes = FxHashSet::from_iter([dir.join("Hades.exe")]);
+
+    let names = remove_known(games, &slugs, &exes)
+        .into_iter()
+        .map(|game| game.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Celeste"]);
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
+
+#[test]
+fn missing_directory_errors() {
+    let dir = temp_dir("scan-missing").join("missing");
+
+    assert!(scan_executables(&dir, Timestamp::now()).is_err());
+}
@@ -0,0 +1,41 @@
+//! Test screenshot helpers.
+
+pub mod common;
+
+use ::chrono::NaiveDate;
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog::screenshot::screenshot_path;
+use ::spel_katalog_formats::Image;
+
+use crate::common::temp_dir;
+
+#[test]
+fn screenshot_paths() {
+    let dir = temp_dir("screenshots");
+    let time = NaiveDate::from_ymd_opt(2024, 3, 9)
+        .and_then(|date| date.and_hms_opt(17, 5, 1))
+        .expect("time should be valid");
+
+    let first = screenshot_path(&dir, time);
+    ::std::fs::write(&first, []).expect("file should be writable");
+    let second = screenshot_path(&dir, time);
+    _ = ::std::fs::remove_dir_all(&dir);
+
+    assert_eq!(first, dir.join("spel-katalog_2024-03-09_17-05-01.png"));
+    assert_eq!(second, dir.join("spel-katalog_2024-03-09_17-05-01_1.png"));
+}
+
+#[test]
+fn png_encoding() {
+    let image = Image {
+        width: 2,
+        height: 2,
+        bytes: vec![255; 16].into(),
+    };
+
+    let png = image.to_png().expect("image should be encodable");
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+    let mismatched = Image { width: 3, ..image };
+    assert!(mismatched.to_png().is_err());
+}
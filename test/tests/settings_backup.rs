@@ -0,0 +1,90 @@
+//! Test backup of settings file on save.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_settings::{
+    Delta, Settings, SettingsArgs, SettingsStore, Theme, backup_path, load, restorable_backup,
+    restore_backup, save,
+};
+
+use crate::common::temp_dir;
+
+/// Create settings using given theme.
+fn with_theme(theme: Theme) -> Settings {
+    let mut settings = Settings::from(SettingsStore::default());
+    Delta::Theme(theme).apply(&mut settings);
+    settings
+}
+
+#[test]
+fn save_creates_backup() {
+    let dir = temp_dir("settings-backup");
+    let path = dir.join("config.toml");
+
+    ::smol::block_on(save(with_theme(Theme::Nord), path.clone())).expect("save should succeed");
+    assert!(!backup_path(&path).exists());
+
+    ::smol::block_on(save(with_theme(Theme::Dracula), path.clone())).expect("save should succeed");
+    let backup = load(&backup_path(&path), SettingsArgs::default());
+    assert_eq!(*backup.get::<Theme>(), Theme::Nord);
+    let current = load(&path, SettingsArgs::default());
+    assert_eq!(*current.get::<Theme>(), Theme::Dracula);
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
+
+#[test]
+fn corrupt_file_not_backed_up() {
+    let dir = temp_dir("settings-corrupt-save");
+    let path = dir.join("config.toml");
+
+    ::smol::block_on(save(with_theme(Theme::Nord), path.clone())).expect("save should succeed");
+    ::smol::block_on(save(with_theme(Theme::Dracula), path.clone())).expect("save should succeed");
+    ::std::fs::write(&path, "theme = [").expect("file should be writable");
+    ::smol::block_on(save(with_theme(Theme::Ferra), path.clone())).expect("save should succeed");
+
+    let backup = load(&backup_path(&path), SettingsArgs::default());
+    assert_eq!(*backup.get::<Theme>(), Theme::Nord);
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
+
+#[test]
+fn corrupt_primary_restored_on_request() {
+    let dir = temp_dir("settings-restore");
+    let path = dir.join("config.toml");
+
+    ::smol::block_on(save(with_theme(Theme::Nord), path.clone())).expect("save should succeed");
+    ::smol::block_on(save(with_theme(Theme::Dracula), path.clone())).expect("save should succeed");
+    ::std::fs::write(&path, "theme = [").expect("file should be writable");
+
+    let loaded = load(&path, SettingsArgs::default());
+    assert_eq!(*loaded.get::<Theme>(), Theme::default());
+    assert_eq!(
+        ::std::fs::read_to_string(&path).expect("file should be readable"),
+        "theme = ["
+    );
+    assert_eq!(restorable_backup(&path), Some(backup_path(&path)));
+
+    let restored = ::smol::block_on(restore_backup(path.clone())).expect("restore should succeed");
+    assert_eq!(*Settings::from(restored).get::<Theme>(), Theme::Nord);
+    let loaded = load(&path, SettingsArgs::default());
+    assert_eq!(*loaded.get::<Theme>(), Theme::Nord);
+    assert_eq!(restorable_backup(&path), None);
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
+
+#[test]
+fn missing_primary_uses_defaults() {
+    let dir = temp_dir("settings-missing");
+    let path = dir.join("config.toml");
+    ::std::fs::write(backup_path(&path), "theme = \"Nord\"").expect("file should be writable");
+
+    let loaded = load(&path, SettingsArgs::default());
+    assert_eq!(*loaded.get::<Theme>(), Theme::default());
+    assert_eq!(restorable_backup(&path), None);
+
+    ::std::fs::remove_dir_all(&dir).expect("temp dir should be removable");
+}
@@ -0,0 +1,108 @@
+//! Test migration of settings files to the current schema.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_settings::{
+    MIGRATIONS, Migration, SETTINGS_VERSION, Settings, SettingsArgs, SettingsStore, SortBy, Theme,
+    VERSION_KEY, load, migrate_table, migrate_with, save, table_version,
+};
+
+use crate::common::temp_dir;
+
+/// Migrations renaming keys of an older schema.
+const RENAMES: &[Migration] = &[
+    Migration {
+        version: 1,
+        renames: &[("colors", "theme")],
+        transform: None,
+    },
+    Migration {
+        version: 2,
+        renames: &[("order_by", "sort_by")],
+        transform: Some(|table| {
+            table.remove("obsolete");
+        }),
+    },
+];
+
+#[test]
+fn renamed_keys_migrate() {
+    let store = migrate_with(
+        r#"
+colors = "Nord"
+order_by = "Name"
+obsolete = true
+"#,
+        RENAMES,
+    )
+    .expect("settings should migrate");
+
+    let settings = Settings::from(store);
+    assert_eq!(*settings.get::<Theme>(), Theme::Nord);
+    assert_eq!(*settings.get::<SortBy>(), SortBy::Name);
+}
+
+#[test]
+fn only_newer_migrations_apply() {
+    let mut table = ::toml::from_str::<::toml::Table>(
+        r#"
+version = 1
+colors = "Nord"
+order_by = "Name"
+"#,
+    )
+    .expect("table should parse");
+
+    migrate_table(&mut table, RENAMES);
+
+    assert_eq!(table_version(&table), 2);
+    assert!(table.contains_key("colors"));
+    assert!(!table.contains_key("theme"));
+    assert_eq!(table.get("sort_by").and_then(|v| v.as_str()), Some("Name"));
+}
+
+#[test]
+fn rename_keeps_current_key() {
+    let store = migrate_with(
+        r#"
+colors = "Nord"
+theme = "Dracula"
+"#,
+        RENAMES,
+    )
+    .expect("settings should migrate");
+
+    assert_eq!(store.theme, Some(Theme::Dracula));
+}
+
+#[test]
+fn unversioned_settings_load() {
+    let settings = Settings::migrate(r#"theme = "Nord""#).expect("settings should migrate");
+    assert_eq!(*settings.get::<Theme>(), Theme::Nord);
+    assert_eq!(MIGRATIONS.last().map(|m| m.version), Some(SETTINGS_VERSION));
+    assert!(Settings::migrate("theme = 5").is_err());
+}
+
+#[test]
+fn saved_settings_are_versioned() {
+    let dir = temp_dir("migration");
+    let path = dir.join("config.toml");
+
+    let store = SettingsStore {
+        theme: Some(Theme::Nord),
+        ..Default::default()
+    };
+    ::smol::block_on(save(Settings::from(store), path.clone())).expect("save should succeed");
+
+    let content = ::std::fs::read_to_string(&path).expect("settings should be readable");
+    let table = ::toml::from_str::<::toml::Table>(&content).expect("settings should be toml");
+    let loaded = load(&path, SettingsArgs::default());
+    _ = ::std::fs::remove_dir_all(&dir);
+
+    assert_eq!(
+        table.get(VERSION_KEY).and_then(|v| v.as_integer()),
+        Some(SETTINGS_VERSION)
+    );
+    assert_eq!(*loaded.get::<Theme>(), Theme::Nord);
+}
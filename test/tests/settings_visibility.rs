@@ -0,0 +1,54 @@
+//! Test conditional visibility of settings.
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_settings::{
+    BubblewrapExe, Delta, FirejailExe, GamescopeExe, SandboxMode, SettingsStore, Theme,
+    UseGamescope, Visible,
+};
+use ::spel_katalog_settings_build::condition::{Comparison, Condition};
+
+#[test]
+fn parse_condition() {
+    assert_eq!(
+        "Sandbox != None".parse::<Condition>(),
+        Ok(Condition {
+            setting: "Sandbox".to_owned(),
+            comparison: Comparison::Ne,
+            variant: "None".to_owned(),
+        })
+    );
+    assert_eq!(
+        "UseGamescope==Yes".parse::<Condition>(),
+        Ok(Condition {
+            setting: "UseGamescope".to_owned(),
+            comparison: Comparison::Eq,
+            variant: "Yes".to_owned(),
+        })
+    );
+
+    for invalid in [
+        "Sandbox",
+        "Sandbox == ",
+        "Sandbox <= None",
+        "Sand box == None",
+    ] {
+        assert!(invalid.parse::<Condition>().is_err(), "{invalid:?} parsed");
+    }
+}
+
+#[test]
+fn visibility() {
+    let mut settings = SettingsStore::default();
+
+    assert!(Theme::is_visible(&settings));
+    assert!(!GamescopeExe::is_visible(&settings));
+    assert!(FirejailExe::is_visible(&settings));
+    assert!(!BubblewrapExe::is_visible(&settings));
+
+    Delta::UseGamescope(UseGamescope::Yes).apply(&mut settings);
+    Delta::SandboxMode(SandboxMode::Bubblewrap).apply(&mut settings);
+
+    assert!(GamescopeExe::is_visible(&settings));
+    assert!(!FirejailExe::is_visible(&settings));
+    assert!(BubblewrapExe::is_visible(&settings));
+}
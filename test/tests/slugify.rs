@@ -0,0 +1,46 @@
+//! Test slug generation from game names.
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_formats::slugify;
+
+#[test]
+fn spaces() {
+    assert_eq!(slugify("Portal 2"), "portal-2");
+    assert_eq!(slugify("The  Outer\tWilds"), "the-outer-wilds");
+    assert_eq!(slugify("Half-Life 2"), "half-life-2");
+    assert_eq!(slugify("Half - Life"), "half-life");
+}
+
+#[test]
+fn punctuation() {
+    assert_eq!(slugify("Baldur's Gate 3"), "baldurs-gate-3");
+    assert_eq!(
+        slugify("S.T.A.L.K.E.R.: Shadow of Chernobyl"),
+        "stalker-shadow-of-chernobyl"
+    );
+    assert_eq!(
+        slugify("Hellblade: Senua's Sacrifice"),
+        "hellblade-senuas-sacrifice"
+    );
+    assert_eq!(slugify("snake_case_game"), "snake_case_game");
+}
+
+#[test]
+fn accents() {
+    assert_eq!(slugify("Pokémon"), "pokemon");
+    assert_eq!(slugify("Über Straße"), "uber-strae");
+    assert_eq!(slugify("Æon Flux"), "on-flux");
+    assert_eq!(slugify("Łódź Ñandú"), "odz-nandu");
+    assert_eq!(slugify("Ōkami 大神"), "okami");
+}
+
+#[test]
+fn leading_and_trailing_separators() {
+    assert_eq!(slugify("  Celeste  "), "celeste");
+    assert_eq!(slugify("--Celeste--"), "celeste");
+    assert_eq!(slugify("- Celeste !"), "celeste");
+    assert_eq!(slugify("_Celeste_"), "celeste");
+    assert_eq!(slugify("_ Celeste"), "celeste");
+    assert_eq!(slugify("!!!"), "");
+    assert_eq!(slugify(""), "");
+}
@@ -0,0 +1,172 @@
+//! Test terminal pipe switching, lookup and eviction.
+
+use ::iced_runtime::{Action, Task};
+use ::pretty_assertions::assert_eq;
+use ::smol::stream::StreamExt as _;
+use ::spel_katalog_sink::SinkIdentity;
+use ::spel_katalog_terminal::{ClonePkgLock, Message, Terminal};
+
+#[test]
+fn switches_when_unpinned() {
+    let terminal = Terminal::default();
+
+    assert!(terminal.switches_to_new_pipe());
+}
+
+#[test]
+fn pinned_pipe_is_kept() {
+    let mut terminal = Terminal::default();
+
+    _ = terminal.update(Message::SetCurrent { idx: Some(0) });
+    assert!(!terminal.switches_to_new_pipe());
+
+    _ = terminal.update(Message::SetCurrent { idx: None });
+    assert!(terminal.switches_to_new_pipe());
+}
+
+#[test]
+fn disabled_never_switches() {
+    let mut terminal = Terminal::default();
+
+    _ = terminal.update(Message::SetAutoSwitch(false));
+    assert!(!terminal.switches_to_new_pipe());
+
+    _ = terminal.update(Message::SetAutoSwitch(true));
+    assert!(terminal.switches_to_new_pipe());
+}
+
+/// Add a pipe with given identity to terminal, returning the write end.
+fn add_pipe(terminal: &mut Terminal, identity: SinkIdentity) -> ::std::io::PipeWriter {
+    let (reader, writer) = ::std::io::pipe().expect("pipe should be creatable");
+    _ = terminal.update(Message::AddPipe {
+        identity,
+        reader: ClonePkgLock::new(reader),
+    });
+    writer
+}
+
+#[test]
+fn game_id_to_pipe() {
+    let mut terminal = Terminal::default();
+
+    let _init = add_pipe(&mut terminal, SinkIdentity::StaticName("Init Prefix"));
+    let _portal = add_pipe(&mut terminal, SinkIdentity::GameId(5));
+    let _celeste = add_pipe(&mut terminal, SinkIdentity::GameId(12));
+    let _named = add_pipe(&mut terminal, SinkIdentity::Name("12".to_owned()));
+
+    assert_eq!(terminal.game_pipe(5), Some(1));
+    assert_eq!(terminal.game_pipe(12), Some(2));
+    assert_eq!(terminal.game_pipe(7), None);
+    assert_eq!(
+        terminal.find_pipe(&SinkIdentity::Name("12".to_owned())),
+        Some(3)
+    );
+}
+
+#[test]
+fn latest_open_pipe_preferred() {
+    let mut terminal = Terminal::default();
+
+    let _first = add_pipe(&mut terminal, SinkIdentity::GameId(5));
+    let _other = add_pipe(&mut terminal, SinkIdentity::GameId(6));
+    let _second = add_pipe(&mut terminal, SinkIdentity::GameId(5));
+    assert_eq!(terminal.game_pipe(5), Some(2));
+}
+
+/// Close a pipe by dropping its writer and running its task to completion.
+fn close_pipe(terminal: &mut Terminal, writer: ::std::io::PipeWriter, task: Task<Message>) {
+    drop(writer);
+    let Some(stream) = ::iced_runtime::task::into_stream(task) else {
+        return;
+    };
+    let actions = ::smol::block_on(stream.collect::<Vec<_>>());
+    for action in actions {
+        if let Action::Output(message) = action {
+            _ = terminal.update(message);
+        }
+    }
+}
+
+/// Add a pipe with given identity to terminal, returning the write end and task.
+fn add_pipe_task(
+    terminal: &mut Terminal,
+    identity: SinkIdentity,
+) -> (::std::io::PipeWriter, Task<Message>) {
+    let (reader, writer) = ::std::io::pipe().expect("pipe should be creatable");
+    let task = terminal.update(Message::AddPipe {
+        identity,
+        reader: ClonePkgLock::new(reader),
+    });
+    (writer, task)
+}
+
+#[test]
+fn oldest_closed_pipe_evicted() {
+    let mut terminal = Terminal::default().with_max_pipes(3);
+
+    let _open = add_pipe(&mut terminal, SinkIdentity::GameId(1));
+    let (writer, task) = add_pipe_task(&mut terminal, SinkIdentity::GameId(2));
+    close_pipe(&mut terminal, writer, task);
+    let (writer, task) = add_pipe_task(&mut terminal, SinkIdentity::GameId(3));
+    close_pipe(&mut terminal, writer, task);
+
+    _ = terminal.update(Message::SetCurrent { idx: Some(2) });
+    assert_eq!(terminal.pipe_count(), 3);
+
+    let _new = add_pipe(&mut terminal, SinkIdentity::GameId(4));
+
+    assert_eq!(terminal.pipe_count(), 3);
+    assert_eq!(terminal.game_pipe(1), Some(0));
+    assert_eq!(terminal.game_pipe(2), None);
+    assert_eq!(terminal.game_pipe(3), Some(1));
+    assert_eq!(terminal.game_pipe(4), Some(2));
+    assert_eq!(terminal.current(), Some(1));
+}
+
+#[test]
+fn evicting_current_pipe_clears_it() {
+    let mut terminal = Terminal::default().with_max_pipes(2);
+
+    let (writer, task) = add_pipe_task(&mut terminal, SinkIdentity::GameId(1));
+    close_pipe(&mut terminal, writer, task);
+    let _open = add_pipe(&mut terminal, SinkIdentity::GameId(2));
+
+    _ = terminal.update(Message::SetCurrent { idx: Some(0) });
+    let _new = add_pipe(&mut terminal, SinkIdentity::GameId(3));
+
+    assert_eq!(terminal.current(), None);
+    assert!(terminal.switches_to_new_pipe());
+    assert_eq!(terminal.game_pipe(1), None);
+}
+
+#[test]
+fn oldest_open_pipe_evicted_when_none_closed() {
+    let mut terminal = Terminal::default().with_max_pipes(2);
+
+    let _first = add_pipe(&mut terminal, SinkIdentity::GameId(1));
+    let _second = add_pipe(&mut terminal, SinkIdentity::GameId(2));
+    let _third = add_pipe(&mut terminal, SinkIdentity::GameId(3));
+
+    assert_eq!(terminal.pipe_count(), 2);
+    assert_eq!(terminal.game_pipe(1), None);
+    assert_eq!(terminal.game_pipe(2), Some(0));
+    assert_eq!(terminal.game_pipe(3), Some(1));
+}
+
+#[test]
+fn closing_after_eviction_targets_right_pipe() {
+    let mut terminal = Terminal::default().with_max_pipes(2);
+
+    let (first, first_task) = add_pipe_task(&mut terminal, SinkIdentity::GameId(1));
+    let (second, second_task) = add_pipe_task(&mut terminal, SinkIdentity::GameId(2));
+    let _third = add_pipe(&mut terminal, SinkIdentity::GameId(3));
+    drop(first);
+    drop(first_task);
+
+    close_pipe(&mut terminal, second, second_task);
+    let _fourth = add_pipe(&mut terminal, SinkIdentity::GameId(4));
+
+    assert_eq!(terminal.game_pipe(2), None);
+    assert_eq!(terminal.game_pipe(3), Some(0));
+    assert_eq!(terminal.game_pipe(4), Some(1));
+}
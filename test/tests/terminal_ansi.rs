@@ -0,0 +1,126 @@
+//! Test rendering of ansi escape codes in terminal output.
+
+pub mod common;
+
+use ::std::io::Write;
+
+use ::iced::Color;
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_terminal::{AnsiMode, AnsiStyle, Message, Terminal};
+
+use crate::common::{add_pipe, run_actions, with_output};
+
+/// Create a terminal with a closed pipe containing given output.
+fn terminal_with(output: &str) -> Terminal {
+    with_output(Terminal::default().with_limit(64), output)
+}
+
+/// Segments of terminal as owned values, skipping empty lines.
+fn segments(terminal: &Terminal) -> Vec<Vec<(AnsiStyle, String)>> {
+    terminal
+        .segments()
+        .filter(|segments| !segments.is_empty())
+        .map(<[_]>::to_vec)
+        .collect()
+}
+
+#[test]
+fn stripped_by_default() {
+    let terminal = terminal_with("\x1b[31mred\x1b[0m text\n");
+
+    assert!(terminal.lines().any(|line| line == "red text"));
+    assert_eq!(segments(&terminal), Vec::<Vec<_>>::new());
+}
+
+#[test]
+fn colors_and_attributes() {
+    let mut terminal = terminal_with("\x1b[31merror\x1b[0m: \x1b[1;4mbold\x1b[m done\n");
+    _ = terminal.update(Message::SetAnsiMode(AnsiMode::Render));
+
+    let red = AnsiStyle {
+        foreground: Some(Color::from_rgb8(0xCD, 0x31, 0x31)),
+        ..Default::default()
+    };
+    let bold = AnsiStyle {
+        bold: true,
+        underline: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        segments(&terminal),
+        [vec![
+            (red, "error".to_owned()),
+            (AnsiStyle::default(), ": ".to_owned()),
+            (bold, "bold".to_owned()),
+            (AnsiStyle::default(), " done".to_owned()),
+        ]]
+    );
+    assert!(terminal.lines().any(|line| line == "error: bold done"));
+
+    _ = terminal.update(Message::SetAnsiMode(AnsiMode::Strip));
+    assert_eq!(segments(&terminal), Vec::<Vec<_>>::new());
+}
+
+#[test]
+fn extended_colors() {
+    let mut terminal = terminal_with("\x1b[38;5;196mA\x1b[48;2;1;2;3mB\x1b[39;49mC\n");
+    _ = terminal.update(Message::SetAnsiMode(AnsiMode::Render));
+
+    let fg = Some(Color::from_rgb8(255, 0, 0));
+    assert_eq!(
+        segments(&terminal),
+        [vec![
+            (
+                AnsiStyle {
+                    foreground: fg,
+                    ..Default::default()
+                },
+                "A".to_owned()
+            ),
+            (
+                AnsiStyle {
+                    foreground: fg,
+                    background: Some(Color::from_rgb8(1, 2, 3)),
+                    ..Default::default()
+                },
+                "B".to_owned()
+            ),
+            (AnsiStyle::default(), "C".to_owned()),
+        ]]
+    );
+}
+
+#[test]
+fn rendered_while_receiving() {
+    let mut terminal = Terminal::default().with_limit(64);
+    _ = terminal.update(Message::SetAnsiMode(AnsiMode::Render));
+
+    let (mut writer, stream) = add_pipe(&mut terminal);
+    _ = terminal.update(Message::SetCurrent { idx: Some(0) });
+
+    // Write in chunks splitting lines and escape codes, such that content is added incrementally.
+    let write = ::std::thread::spawn(move || {
+        for chunk in ["\x1b[3", "1mred\x1b[0m\npla", "in\n"] {
+            writer
+                .write_all(chunk.as_bytes())
+                .expect("output should be writable");
+            ::std::thread::sleep(::std::time::Duration::from_millis(20));
+        }
+    });
+
+    run_actions(&mut terminal, stream);
+    write.join().expect("writer should not panic");
+
+    let red = AnsiStyle {
+        foreground: Some(Color::from_rgb8(0xCD, 0x31, 0x31)),
+        ..Default::default()
+    };
+    assert_eq!(
+        segments(&terminal),
+        [
+            vec![(red, "red".to_owned())],
+            vec![(AnsiStyle::default(), "plain".to_owned())],
+        ]
+    );
+    assert_eq!(terminal.lines().collect::<Vec<_>>(), ["red", "plain"]);
+}
@@ -0,0 +1,51 @@
+//! Test limiting terminal pipe content by byte budget.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_terminal::Terminal;
+
+use crate::common::with_output;
+
+/// Create a terminal with a byte limit and a closed pipe containing given output.
+fn terminal_with(byte_limit: usize, output: &[u8]) -> Terminal {
+    with_output(
+        Terminal::default()
+            .with_limit(64)
+            .with_byte_limit(byte_limit),
+        output,
+    )
+}
+
+#[test]
+fn whole_lines_dropped() {
+    let terminal = terminal_with(10, b"aaaa\nbbbb\ncccc\n");
+
+    assert_eq!(terminal.pipe_content(0), Some(&b"cccc\n"[..]));
+    assert!(!terminal.lines().any(|line| line == "aaaa"));
+    assert!(!terminal.lines().any(|line| line == "bbbb"));
+    assert!(terminal.lines().any(|line| line == "cccc"));
+}
+
+#[test]
+fn within_budget_kept() {
+    let terminal = terminal_with(64, b"aaaa\nbbbb\n");
+
+    assert_eq!(terminal.pipe_content(0), Some(&b"aaaa\nbbbb\n"[..]));
+}
+
+#[test]
+fn utf8_not_split() {
+    let terminal = terminal_with(5, "ééééé".as_bytes());
+
+    let content = terminal.pipe_content(0).expect("pipe should exist");
+    assert_eq!(::core::str::from_utf8(content), Ok("éé"));
+}
+
+#[test]
+fn trimmed_below_budget() {
+    let terminal = terminal_with(16, b"aaaa\nbbbb\ncccc\ndddd\n");
+
+    // Trimmed to within 3/4 of the limit.
+    assert_eq!(terminal.pipe_content(0), Some(&b"cccc\ndddd\n"[..]));
+}
@@ -0,0 +1,67 @@
+//! Test clearing terminal pipes.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_terminal::{Message, Terminal};
+
+use crate::common::{add_pipe, write_output};
+
+/// Lines of terminal, skipping empty lines.
+fn lines(terminal: &Terminal) -> Vec<&str> {
+    terminal.lines().filter(|line| !line.is_empty()).collect()
+}
+
+#[test]
+fn cleared_pipe_keeps_receiving() {
+    let mut terminal = Terminal::default().with_limit(64);
+    let (mut writer, mut stream) = add_pipe(&mut terminal);
+
+    write_output(&mut terminal, &mut writer, &mut stream, b"old\nstale");
+    assert_eq!(lines(&terminal), ["old", "stale"]);
+
+    _ = terminal.update(Message::ClearCurrent);
+    assert_eq!(terminal.pipe_content(0), Some(&b""[..]));
+    assert_eq!(lines(&terminal), Vec::<&str>::new());
+
+    write_output(&mut terminal, &mut writer, &mut stream, b"new\nline");
+    assert_eq!(terminal.pipe_content(0), Some(&b"new\nline"[..]));
+    assert_eq!(lines(&terminal), ["new", "line"]);
+
+    write_output(&mut terminal, &mut writer, &mut stream, b" done\n");
+    assert_eq!(lines(&terminal), ["new", "line done"]);
+}
+
+#[test]
+fn nothing_cleared_without_current() {
+    let mut terminal = Terminal::default().with_limit(64);
+    let (mut writer, mut stream) = add_pipe(&mut terminal);
+    write_output(&mut terminal, &mut writer, &mut stream, b"kept\n");
+    _ = terminal.update(Message::SetCurrent { idx: None });
+
+    _ = terminal.update(Message::ClearCurrent);
+    assert_eq!(terminal.pipe_content(0), Some(&b"kept\n"[..]));
+}
+
+#[test]
+fn only_current_cleared() {
+    let mut terminal = Terminal::default().with_limit(64);
+    let (mut first, mut first_stream) = add_pipe(&mut terminal);
+    write_output(&mut terminal, &mut first, &mut first_stream, b"first\n");
+    let (mut second, mut second_stream) = add_pipe(&mut terminal);
+    write_output(&mut terminal, &mut second, &mut second_stream, b"second\n");
+
+    _ = terminal.update(Message::SetCurrent { idx: Some(0) });
+    _ = terminal.update(Message::ClearCurrent);
+    assert_eq!(terminal.pipe_content(0), Some(&b""[..]));
+    assert_eq!(terminal.pipe_content(1), Some(&b"second\n"[..]));
+
+    _ = terminal.update(Message::SetCurrent { idx: Some(1) });
+    assert_eq!(lines(&terminal), ["second"]);
+    _ = terminal.update(Message::ClearCurrent);
+    assert_eq!(lines(&terminal), Vec::<&str>::new());
+    assert_eq!(terminal.pipe_count(), 2);
+
+    write_output(&mut terminal, &mut first, &mut first_stream, b"again\n");
+    assert_eq!(terminal.pipe_content(0), Some(&b"again\n"[..]));
+}
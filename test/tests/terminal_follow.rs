@@ -0,0 +1,124 @@
+//! Test keeping terminal scrolled to newest output.
+
+use ::std::io::Write;
+
+use ::iced_runtime::{Action, Task};
+use ::pretty_assertions::assert_eq;
+use ::smol::stream::StreamExt as _;
+use ::spel_katalog_sink::SinkIdentity;
+use ::spel_katalog_terminal::{ClonePkgLock, Message, Terminal};
+
+/// Check if a task does anything.
+fn is_some(task: Task<Message>) -> bool {
+    ::iced_runtime::task::into_stream(task).is_some()
+}
+
+/// Add a pipe with given output to terminal, returning whether each added content scrolled.
+fn add_output(terminal: &mut Terminal, output: &[u8]) -> Vec<bool> {
+    let (reader, mut writer) = ::std::io::pipe().expect("pipe should be creatable");
+    let task = terminal.update(Message::AddPipe {
+        identity: SinkIdentity::GameId(1),
+        reader: ClonePkgLock::new(reader),
+    });
+
+    writer.write_all(output).expect("output should be writable");
+    drop(writer);
+
+    let stream = ::iced_runtime::task::into_stream(task).expect("task should have a stream");
+    let mut messages = ::smol::block_on(stream.collect::<Vec<_>>())
+        .into_iter()
+        .filter_map(|action| match action {
+            Action::Output(message) => Some(message),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Switch to pipe before content is added.
+    messages.sort_by_key(|message| !matches!(message, Message::AutoSwitch { .. }));
+
+    messages
+        .into_iter()
+        .filter_map(|message| {
+            let is_content = matches!(message, Message::AddContent { .. });
+            let task = terminal.update(message);
+            is_content.then(|| is_some(task))
+        })
+        .collect()
+}
+
+#[test]
+fn follows_by_default() {
+    let mut terminal = Terminal::default();
+    assert!(terminal.follows());
+
+    let scrolled = add_output(&mut terminal, b"installing\n");
+    assert!(!scrolled.is_empty());
+    assert!(scrolled.iter().all(|scrolled| *scrolled));
+}
+
+#[test]
+fn content_does_not_scroll_when_not_following() {
+    let mut terminal = Terminal::default();
+
+    assert!(!is_some(terminal.update(Message::SetFollow(false))));
+    assert!(!terminal.follows());
+
+    let scrolled = add_output(&mut terminal, b"installing\n");
+    assert!(!scrolled.is_empty());
+    assert_eq!(scrolled.iter().filter(|scrolled| **scrolled).count(), 0);
+}
+
+#[test]
+fn scrolls_to_end_when_enabled() {
+    let mut terminal = Terminal::default();
+
+    _ = terminal.update(Message::SetFollow(false));
+    assert!(is_some(terminal.update(Message::SetFollow(true))));
+    assert!(terminal.follows());
+}
+
+#[test]
+fn stepping_matches_suppresses_follow() {
+    let mut terminal = Terminal::default().with_limit(64);
+    _ = terminal.update(Message::SetSearch("error".to_owned()));
+
+    let (reader, mut writer) = ::std::io::pipe().expect("pipe should be creatable");
+    let task = terminal.update(Message::AddPipe {
+        identity: SinkIdentity::GameId(1),
+        reader: ClonePkgLock::new(reader),
+    });
+    _ = terminal.update(Message::SetCurrent { idx: Some(0) });
+
+    // Write in chunks, such that content is added more than once.
+    let write = ::std::thread::spawn(move || {
+        for chunk in ["error: early\n", "installing\n"] {
+            writer
+                .write_all(chunk.as_bytes())
+                .expect("output should be writable");
+            ::std::thread::sleep(::std::time::Duration::from_millis(20));
+        }
+    });
+
+    let stream = ::iced_runtime::task::into_stream(task).expect("task should have a stream");
+    let contents = ::smol::block_on(stream.collect::<Vec<_>>())
+        .into_iter()
+        .filter_map(|action| match action {
+            Action::Output(message @ Message::AddContent { .. }) => Some(message),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    write.join().expect("writer should not panic");
+
+    let mut contents = contents.into_iter();
+    let first = contents.next().expect("content should be added");
+    assert!(is_some(terminal.update(first)));
+
+    assert!(is_some(terminal.update(Message::NextMatch)));
+    assert_eq!(terminal.current_match(), Some(0));
+
+    let scrolled = contents
+        .map(|message| is_some(terminal.update(message)))
+        .collect::<Vec<_>>();
+    assert!(!scrolled.is_empty());
+    assert_eq!(scrolled.iter().filter(|scrolled| **scrolled).count(), 0);
+}
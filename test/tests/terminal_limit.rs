@@ -0,0 +1,34 @@
+//! Test limiting the amount of lines displayed by terminal.
+
+pub mod common;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_terminal::{Message, Terminal};
+
+use crate::common::with_output;
+
+/// Create a terminal with given line limit and a closed pipe containing given output.
+fn terminal_with(limit: u16, output: &str) -> Terminal {
+    with_output(Terminal::default().with_limit(limit), output)
+}
+
+#[test]
+fn changed_limit_applied() {
+    let mut terminal = terminal_with(2, "a\nb\nc\nd\n");
+    assert_eq!(terminal.lines().collect::<Vec<_>>(), ["c", "d"]);
+
+    terminal.set_limit(3);
+    assert_eq!(terminal.lines().collect::<Vec<_>>(), ["b", "c", "d"]);
+
+    terminal.set_limit(1);
+    assert_eq!(terminal.lines().collect::<Vec<_>>(), ["d"]);
+}
+
+#[test]
+fn line_count_overrides_limit() {
+    let mut terminal = terminal_with(2, "a\nb\nc\nd\n");
+    _ = terminal.update(Message::SetLineCount("3".to_owned()));
+
+    terminal.set_limit(1);
+    assert_eq!(terminal.lines().collect::<Vec<_>>(), ["b", "c", "d"]);
+}
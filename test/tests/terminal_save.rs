@@ -0,0 +1,82 @@
+//! Test saving terminal pipe output.
+
+pub mod common;
+
+use ::std::path::PathBuf;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_terminal::{Message, Terminal};
+
+use crate::common::{run_actions, temp_dir, with_output};
+
+/// Create a terminal with a line limit and a closed pipe containing given output.
+fn terminal_with(limit: u16, output: &[u8]) -> Terminal {
+    with_output(Terminal::default().with_limit(limit), output)
+}
+
+#[test]
+fn complete_content_kept() {
+    let output = (0..100)
+        .map(|i| format!("\x1b[32mline {i}\x1b[0m\n"))
+        .collect::<String>();
+    let terminal = terminal_with(8, output.as_bytes());
+
+    assert!(terminal.lines().len() <= 8);
+    assert_eq!(terminal.pipe_content(0), Some(output.as_bytes()));
+    assert_eq!(terminal.pipe_content(1), None);
+}
+
+#[test]
+fn save_status_reported() {
+    let mut terminal = terminal_with(8, b"output\n");
+    assert_eq!(terminal.save_status(), None);
+
+    _ = terminal.update(Message::PipeSaved(Ok(PathBuf::from("/tmp/game.log"))));
+    assert_eq!(terminal.save_status(), Some("saved to \"/tmp/game.log\""));
+
+    _ = terminal.update(Message::PipeSaved(Err("could not write".to_owned())));
+    assert_eq!(terminal.save_status(), Some("could not write"));
+}
+
+#[test]
+fn suggested_file_name() {
+    let terminal = terminal_with(8, b"output\n");
+
+    assert_eq!(terminal.pipe_file_name(0).as_deref(), Some("Game_1_.log"));
+    assert_eq!(terminal.pipe_file_name(1), None);
+}
+
+#[test]
+fn saved_to_path() {
+    let mut terminal = terminal_with(8, b"first\nsecond\n");
+    let path = temp_dir("terminal-save").join("game.log");
+
+    let task = terminal.update(Message::SavePipeTo {
+        idx: 0,
+        path: path.clone(),
+    });
+    run_actions(
+        &mut terminal,
+        ::iced_runtime::task::into_stream(task).expect("task should have a stream"),
+    );
+
+    assert_eq!(
+        ::std::fs::read(&path).expect("saved file should be readable"),
+        b"first\nsecond\n"
+    );
+    assert_eq!(
+        terminal.save_status(),
+        Some(format!("saved to {path:?}").as_str())
+    );
+}
+
+#[test]
+fn unavailable_pipe_not_saved() {
+    let mut terminal = terminal_with(8, b"output\n");
+
+    let task = terminal.update(Message::SavePipeTo {
+        idx: 3,
+        path: PathBuf::from("/tmp/game.log"),
+    });
+    assert!(::iced_runtime::task::into_stream(task).is_none());
+}
@@ -0,0 +1,97 @@
+//! Test searching terminal output.
+
+pub mod common;
+
+use ::std::io::Write;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_terminal::{Message, Terminal};
+
+use crate::common::{add_pipe, run_actions, with_output};
+
+/// Create a terminal with a closed pipe containing given output.
+fn terminal_with(output: &str) -> Terminal {
+    with_output(Terminal::default().with_limit(64), output)
+}
+
+const OUTPUT: &str = "starting game\nerror: missing dll\nloading level\nERROR: crashed\n";
+
+#[test]
+fn search_filters_lines() {
+    let mut terminal = terminal_with(OUTPUT);
+
+    _ = terminal.update(Message::SetSearch("error".to_owned()));
+    assert_eq!(terminal.search(), "error");
+    assert_eq!(
+        terminal.lines().collect::<Vec<_>>(),
+        ["error: missing dll", "ERROR: crashed"]
+    );
+    assert_eq!(terminal.match_count(), 2);
+
+    _ = terminal.update(Message::SetCaseSensitive(true));
+    assert_eq!(terminal.lines().collect::<Vec<_>>(), ["error: missing dll"]);
+
+    _ = terminal.update(Message::SetSearch(String::new()));
+    assert_eq!(terminal.match_count(), 0);
+    let lines = terminal.lines().collect::<Vec<_>>();
+    assert!(lines.contains(&"starting game"));
+    assert!(lines.contains(&"loading level"));
+}
+
+#[test]
+fn matches_wrap_around() {
+    let mut terminal = terminal_with(OUTPUT);
+
+    _ = terminal.update(Message::NextMatch);
+    assert_eq!(terminal.current_match(), None);
+
+    _ = terminal.update(Message::SetSearch("e".to_owned()));
+    let count = terminal.match_count();
+    assert!(count >= 4);
+
+    _ = terminal.update(Message::NextMatch);
+    assert_eq!(terminal.current_match(), Some(0));
+    _ = terminal.update(Message::PrevMatch);
+    assert_eq!(terminal.current_match(), Some(count - 1));
+    _ = terminal.update(Message::NextMatch);
+    assert_eq!(terminal.current_match(), Some(0));
+    _ = terminal.update(Message::NextMatch);
+    assert_eq!(terminal.current_match(), Some(1));
+
+    _ = terminal.update(Message::SetSearch("crash".to_owned()));
+    assert_eq!(terminal.current_match(), None);
+    assert_eq!(terminal.match_count(), 1);
+}
+
+#[test]
+fn content_filtered_while_searching() {
+    let mut terminal = Terminal::default().with_limit(64);
+    _ = terminal.update(Message::SetSearch("error".to_owned()));
+
+    let (mut writer, stream) = add_pipe(&mut terminal);
+    _ = terminal.update(Message::SetCurrent { idx: Some(0) });
+
+    // Write in chunks splitting lines, such that content is added incrementally.
+    let write = ::std::thread::spawn(move || {
+        for chunk in [
+            "starting game\nerr",
+            "or: missing dll\nload",
+            "ing level\nERROR: cra",
+            "shed\n",
+        ] {
+            writer
+                .write_all(chunk.as_bytes())
+                .expect("output should be writable");
+            ::std::thread::sleep(::std::time::Duration::from_millis(20));
+        }
+    });
+
+    run_actions(&mut terminal, stream);
+    write.join().expect("writer should not panic");
+
+    assert_eq!(
+        terminal.lines().collect::<Vec<_>>(),
+        ["error: missing dll", "ERROR: crashed"]
+    );
+    assert_eq!(terminal.match_count(), 2);
+}
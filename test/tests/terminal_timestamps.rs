@@ -0,0 +1,89 @@
+//! Test timestamping of terminal lines.
+
+pub mod common;
+
+use ::std::time::Duration;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_terminal::{Message, Terminal};
+
+use crate::common::{add_pipe, write_output};
+
+/// Lines of terminal with whether they have a time, skipping empty lines.
+fn timed_lines(terminal: &Terminal) -> Vec<(String, bool)> {
+    terminal
+        .lines()
+        .zip(terminal.line_times())
+        .filter(|(line, _)| !line.is_empty())
+        .map(|(line, time)| (line.to_owned(), time.is_some()))
+        .collect()
+}
+
+#[test]
+fn disabled_by_default() {
+    let mut terminal = Terminal::default().with_limit(64);
+    assert!(!terminal.timestamps());
+
+    let (mut writer, mut stream) = add_pipe(&mut terminal);
+    write_output(&mut terminal, &mut writer, &mut stream, b"first\n");
+
+    assert_eq!(timed_lines(&terminal), [("first".to_owned(), false)]);
+}
+
+#[test]
+fn blank_before_enabled() {
+    let mut terminal = Terminal::default().with_limit(64);
+    let (mut writer, mut stream) = add_pipe(&mut terminal);
+
+    write_output(&mut terminal, &mut writer, &mut stream, b"before\n");
+    _ = terminal.update(Message::SetTimestamps(true));
+    write_output(&mut terminal, &mut writer, &mut stream, b"after\n");
+
+    assert!(terminal.timestamps());
+    assert_eq!(
+        timed_lines(&terminal),
+        [("before".to_owned(), false), ("after".to_owned(), true)]
+    );
+}
+
+#[test]
+fn collapsed_lines_keep_latest_time() {
+    let mut terminal = Terminal::default().with_limit(64);
+    _ = terminal.update(Message::SetTimestamps(true));
+    let (mut writer, mut stream) = add_pipe(&mut terminal);
+
+    write_output(&mut terminal, &mut writer, &mut stream, b"same\n");
+    let first = terminal.line_times().flatten().max();
+
+    ::std::thread::sleep(Duration::from_millis(20));
+    write_output(&mut terminal, &mut writer, &mut stream, b"same\n");
+    let latest = terminal.line_times().flatten().max();
+
+    assert_eq!(timed_lines(&terminal), [("same".to_owned(), true)]);
+    assert!(first.is_some());
+    assert!(latest > first);
+
+    // Refreshing lines from content keeps collapsing and the latest time.
+    _ = terminal.update(Message::SetSearch("same".to_owned()));
+    assert_eq!(timed_lines(&terminal), [("same".to_owned(), true)]);
+    assert_eq!(terminal.line_times().flatten().max(), latest);
+}
+
+#[test]
+fn partial_line_timed_when_completed() {
+    let mut terminal = Terminal::default().with_limit(64);
+    _ = terminal.update(Message::SetTimestamps(true));
+    let (mut writer, mut stream) = add_pipe(&mut terminal);
+
+    write_output(&mut terminal, &mut writer, &mut stream, b"done\nhalf");
+    assert_eq!(
+        timed_lines(&terminal),
+        [("done".to_owned(), true), ("half".to_owned(), false)]
+    );
+
+    write_output(&mut terminal, &mut writer, &mut stream, b" way\n");
+    assert_eq!(
+        timed_lines(&terminal),
+        [("done".to_owned(), true), ("half way".to_owned(), true)]
+    );
+}
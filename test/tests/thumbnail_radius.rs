@@ -0,0 +1,39 @@
+//! Test rounding of thumbnail corners.
+
+use ::iced::border::Radius;
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_games::thumbnail_radius;
+use ::spel_katalog_settings::{Delta, Settings, SettingsStore, ThumbnailRadius};
+
+/// Settings with given thumbnail radius.
+fn settings_with(radius: i64) -> Settings {
+    let mut settings = Settings::from(SettingsStore::default());
+    Delta::ThumbnailRadius(ThumbnailRadius::new(radius)).apply(&mut settings);
+    settings
+}
+
+#[test]
+fn square_by_default() {
+    let settings = Settings::from(SettingsStore::default());
+
+    assert_eq!(settings.get::<ThumbnailRadius>().pixels(), 0.0);
+    assert_eq!(thumbnail_radius(&settings), Radius::default());
+}
+
+#[test]
+fn radius_applied_to_all_corners() {
+    let radius = thumbnail_radius(&settings_with(12));
+
+    assert_eq!(radius, Radius::from(12.0));
+    assert_eq!(radius.top_left, 12.0);
+    assert_eq!(radius.bottom_right, 12.0);
+}
+
+#[test]
+fn radius_clamped_to_range() {
+    assert_eq!(thumbnail_radius(&settings_with(-4)), Radius::default());
+    assert_eq!(
+        thumbnail_radius(&settings_with(1 << 20)),
+        Radius::from(ThumbnailRadius::MAX as f32)
+    );
+}
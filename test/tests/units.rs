@@ -0,0 +1,56 @@
+//! Test human readable formatting of sizes and counts.
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_common::units::{
+    UnitBase::{Binary, Decimal},
+    format_count, format_size,
+};
+
+#[test]
+fn binary_sizes() {
+    for (bytes, expected) in [
+        (0, "0 B"),
+        (1, "1 B"),
+        (1023, "1023 B"),
+        (1024, "1.0 KiB"),
+        (1536, "1.5 KiB"),
+        (1_258_291, "1.2 MiB"),
+        (1024 * 1024 - 1, "1.0 MiB"),
+        (1024 * 1024 * 1024, "1.0 GiB"),
+        (u64::MAX, "16.0 EiB"),
+    ] {
+        assert_eq!(format_size(bytes, Binary), expected, "{bytes}");
+    }
+}
+
+#[test]
+fn decimal_sizes() {
+    for (bytes, expected) in [
+        (0, "0 B"),
+        (999, "999 B"),
+        (1000, "1.0 kB"),
+        (1024, "1.0 kB"),
+        (999_949, "999.9 kB"),
+        (1_000_000, "1.0 MB"),
+        (1_200_000, "1.2 MB"),
+        (u64::MAX, "18.4 EB"),
+    ] {
+        assert_eq!(format_size(bytes, Decimal), expected, "{bytes}");
+    }
+}
+
+#[test]
+fn counts() {
+    for (count, expected) in [
+        (0, "0"),
+        (7, "7"),
+        (999, "999"),
+        (1000, "1,000"),
+        (1024, "1,024"),
+        (999_999, "999,999"),
+        (1_000_000, "1,000,000"),
+        (u64::MAX, "18,446,744,073,709,551,615"),
+    ] {
+        assert_eq!(format_count(count), expected, "{count}");
+    }
+}
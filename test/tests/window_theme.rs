@@ -0,0 +1,65 @@
+//! Test resolution of per window theme overrides.
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_settings::{
+    Delta, InstallerTheme, Settings, SettingsStore, SettingsTheme, TerminalTheme, Theme,
+    ThemedWindow,
+};
+
+/// All kinds of themed windows.
+const WINDOWS: [ThemedWindow; 4] = [
+    ThemedWindow::Main,
+    ThemedWindow::Settings,
+    ThemedWindow::Terminal,
+    ThemedWindow::Installer,
+];
+
+/// Resolve theme of every window kind.
+fn resolved(settings: &Settings) -> Vec<Theme> {
+    WINDOWS
+        .iter()
+        .map(|window| settings.window_theme(*window))
+        .collect()
+}
+
+#[test]
+fn global_by_default() {
+    let settings = Settings::from(SettingsStore::default());
+
+    assert!(settings.get::<SettingsTheme>().is_global());
+    assert_eq!(settings.get::<TerminalTheme>().theme(), None);
+    assert_eq!(resolved(&settings), [*settings.get::<Theme>(); 4]);
+}
+
+#[test]
+fn override_takes_precedence() {
+    let mut settings = Settings::from(SettingsStore::default());
+    Delta::Theme(Theme::Light).apply(&mut settings);
+    Delta::SettingsTheme(SettingsTheme::Dark).apply(&mut settings);
+    Delta::InstallerTheme(InstallerTheme::Nord).apply(&mut settings);
+
+    assert_eq!(
+        resolved(&settings),
+        [Theme::Light, Theme::Dark, Theme::Light, Theme::Nord]
+    );
+}
+
+#[test]
+fn unset_follows_global() {
+    let mut settings = Settings::from(SettingsStore::default());
+    Delta::TerminalTheme(TerminalTheme::Dracula).apply(&mut settings);
+
+    Delta::Theme(Theme::GruvboxLight).apply(&mut settings);
+    assert_eq!(
+        resolved(&settings),
+        [
+            Theme::GruvboxLight,
+            Theme::GruvboxLight,
+            Theme::Dracula,
+            Theme::GruvboxLight
+        ]
+    );
+
+    Delta::TerminalTheme(TerminalTheme::Global).apply(&mut settings);
+    assert_eq!(resolved(&settings), [Theme::GruvboxLight; 4]);
+}
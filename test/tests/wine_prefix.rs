@@ -0,0 +1,63 @@
+//! Test extracting the wine prefix from a game config.
+
+use ::std::path::Path;
+
+use ::pretty_assertions::assert_eq;
+use ::spel_katalog_formats::{LutrisRunner, lutris_config::Config};
+
+#[test]
+fn prefix_of_wine_game() {
+    let config = Config::parse(
+        r#"
+game:
+  exe: /games/example/drive_c/example.exe
+  prefix: /games/example
+"#,
+    )
+    .expect("config should parse");
+
+    assert_eq!(
+        config.wine_prefix(&LutrisRunner::Wine),
+        Some(Path::new("/games/example"))
+    );
+}
+
+#[test]
+fn prefix_missing() {
+    let config = Config::parse(
+        r#"
+game:
+  exe: /games/example/drive_c/example.exe
+"#,
+    )
+    .expect("config should parse");
+    assert_eq!(config.wine_prefix(&LutrisRunner::Wine), None);
+
+    let config = Config::parse(
+        r#"
+game:
+  exe: /games/example/drive_c/example.exe
+  prefix: ""
+"#,
+    )
+    .expect("config should parse");
+    assert_eq!(config.wine_prefix(&LutrisRunner::Wine), None);
+}
+
+#[test]
+fn prefix_of_other_runners() {
+    let config = Config::parse(
+        r#"
+game:
+  exe: /games/example/example.sh
+  prefix: /games/example
+"#,
+    )
+    .expect("config should parse");
+
+    assert_eq!(config.wine_prefix(&LutrisRunner::Linux), None);
+    assert_eq!(
+        config.wine_prefix(&LutrisRunner::Other("dosbox".to_owned())),
+        None
+    );
+}